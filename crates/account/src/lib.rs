@@ -36,8 +36,34 @@ use namada_migrations::*;
 use serde::{Deserialize, Serialize};
 pub use storage::*;
 pub use storage_key::*;
+use thiserror::Error;
 pub use types::*;
 
+/// Errors from validating an [`Account`]
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum AccountError {
+    /// The threshold is zero
+    #[error("The account signature threshold must be at least 1")]
+    ZeroThreshold,
+    /// The threshold exceeds the number of public keys
+    #[error(
+        "The account signature threshold {threshold} exceeds the number of \
+         public keys {num_keys}"
+    )]
+    ThresholdExceedsKeys {
+        /// The account signature threshold
+        threshold: u8,
+        /// The number of public keys of the account
+        num_keys: usize,
+    },
+    /// A public key appears more than once
+    #[error("The account public key {0} is duplicated")]
+    DuplicatePublicKey(common::PublicKey),
+    /// A public key does not belong to the account
+    #[error("The public key {0} does not belong to the account")]
+    MissingPublicKey(common::PublicKey),
+}
+
 #[derive(
     Debug,
     Clone,
@@ -78,4 +104,178 @@ impl Account {
     pub fn get_all_public_keys(&self) -> Vec<common::PublicKey> {
         self.public_keys_map.pk_to_idx.keys().cloned().collect()
     }
+
+    /// Check that the signature threshold is at least 1 and at most the
+    /// number of public keys, and that no public key appears more than once
+    pub fn validate_threshold(&self) -> Result<(), AccountError> {
+        if self.threshold == 0 {
+            return Err(AccountError::ZeroThreshold);
+        }
+        // a duplicated key is indexed more than once, but only its last index
+        // is retained in the reverse map
+        let duplicate =
+            self.public_keys_map.idx_to_pk.iter().find(|(ix, pk)| {
+                self.public_keys_map.pk_to_idx.get(*pk) != Some(*ix)
+            });
+        if let Some((_, pk)) = duplicate {
+            return Err(AccountError::DuplicatePublicKey(pk.clone()));
+        }
+        let num_keys = self.public_keys_map.idx_to_pk.len();
+        if usize::from(self.threshold) > num_keys {
+            return Err(AccountError::ThresholdExceedsKeys {
+                threshold: self.threshold,
+                num_keys,
+            });
+        }
+        Ok(())
+    }
+
+    /// Get a copy of this account with the public key `old` replaced by
+    /// `new` at the same index. The threshold is kept intact.
+    pub fn with_rotated_key(
+        &self,
+        old: &common::PublicKey,
+        new: common::PublicKey,
+    ) -> Result<Account, AccountError> {
+        if !self.public_keys_map.pk_to_idx.contains_key(old) {
+            return Err(AccountError::MissingPublicKey(old.clone()));
+        }
+        if self.public_keys_map.pk_to_idx.contains_key(&new) {
+            return Err(AccountError::DuplicatePublicKey(new));
+        }
+        let rotate = |pk: &common::PublicKey| {
+            if pk == old {
+                new.clone()
+            } else {
+                pk.clone()
+            }
+        };
+        let public_keys_map = AccountPublicKeysMap {
+            pk_to_idx: self
+                .public_keys_map
+                .pk_to_idx
+                .iter()
+                .map(|(pk, ix)| (rotate(pk), *ix))
+                .collect(),
+            idx_to_pk: self
+                .public_keys_map
+                .idx_to_pk
+                .iter()
+                .map(|(ix, pk)| (*ix, rotate(pk)))
+                .collect(),
+        };
+        Ok(Account {
+            public_keys_map,
+            threshold: self.threshold,
+            address: self.address.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use namada_core::address::testing::established_address_1;
+    use namada_core::key::testing::common_sk_from_simple_seed;
+    use namada_core::key::RefTo;
+
+    use super::*;
+
+    fn account(public_keys: Vec<common::PublicKey>, threshold: u8) -> Account {
+        Account {
+            public_keys_map: public_keys.into_iter().collect(),
+            threshold,
+            address: established_address_1(),
+        }
+    }
+
+    #[test]
+    fn test_validate_threshold() {
+        let pks: Vec<common::PublicKey> = (0..3)
+            .map(|seed| common_sk_from_simple_seed(seed).ref_to())
+            .collect();
+
+        for threshold in 1..=3 {
+            assert_eq!(
+                account(pks.clone(), threshold).validate_threshold(),
+                Ok(())
+            );
+        }
+
+        // threshold zero
+        assert_eq!(
+            account(pks.clone(), 0).validate_threshold(),
+            Err(AccountError::ZeroThreshold)
+        );
+        assert_eq!(
+            account(vec![], 0).validate_threshold(),
+            Err(AccountError::ZeroThreshold)
+        );
+
+        // threshold exceeding the key count
+        assert_eq!(
+            account(pks.clone(), 4).validate_threshold(),
+            Err(AccountError::ThresholdExceedsKeys {
+                threshold: 4,
+                num_keys: 3,
+            })
+        );
+        assert_eq!(
+            account(vec![], 1).validate_threshold(),
+            Err(AccountError::ThresholdExceedsKeys {
+                threshold: 1,
+                num_keys: 0,
+            })
+        );
+
+        // duplicate keys
+        let duplicated = vec![pks[0].clone(), pks[1].clone(), pks[0].clone()];
+        assert_eq!(
+            account(duplicated, 1).validate_threshold(),
+            Err(AccountError::DuplicatePublicKey(pks[0].clone()))
+        );
+    }
+
+    #[test]
+    fn test_with_rotated_key() {
+        let pks: Vec<common::PublicKey> = (0..4)
+            .map(|seed| common_sk_from_simple_seed(seed).ref_to())
+            .collect();
+        let account = account(pks[..3].to_vec(), 2);
+
+        let rotated = account
+            .with_rotated_key(&pks[1], pks[3].clone())
+            .expect("Test failed");
+        assert_eq!(rotated.threshold, account.threshold);
+        assert_eq!(rotated.address, account.address);
+        assert_eq!(rotated.get_index_from_public_key(&pks[1]), None);
+        assert_eq!(rotated.get_index_from_public_key(&pks[3]), Some(1));
+        assert_eq!(rotated.get_public_key_from_index(1), Some(pks[3].clone()));
+        for ix in [0, 2] {
+            assert_eq!(
+                rotated.get_public_key_from_index(ix),
+                account.get_public_key_from_index(ix)
+            );
+        }
+        assert_eq!(
+            rotated.get_all_public_keys(),
+            vec![pks[0].clone(), pks[3].clone(), pks[2].clone()]
+        );
+        assert_eq!(rotated.validate_threshold(), Ok(()));
+
+        // the old key must be present
+        assert_eq!(
+            account
+                .with_rotated_key(&pks[3], pks[3].clone())
+                .map(|_| ()),
+            Err(AccountError::MissingPublicKey(pks[3].clone()))
+        );
+
+        // the new key must not be present
+        assert_eq!(
+            account
+                .with_rotated_key(&pks[1], pks[2].clone())
+                .map(|_| ()),
+            Err(AccountError::DuplicatePublicKey(pks[2].clone()))
+        );
+    }
 }
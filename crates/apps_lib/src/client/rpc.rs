@@ -731,6 +731,10 @@ pub async fn query_protocol_parameters(
         max_proposal_content_size,
         min_proposal_grace_epochs,
         max_proposal_latency,
+        max_proposal_execution_gas,
+        rejection_refund_fraction,
+        min_proposal_quorum,
+        max_proposal_code_retries,
     } = query_governance_parameters(context.client()).await;
 
     display_line!(context.io(), "\nGovernance Parameters");
@@ -776,6 +780,30 @@ pub async fn query_protocol_parameters(
         "",
         max_proposal_latency
     );
+    display_line!(
+        context.io(),
+        "{:4}Max. proposal execution gas: {}",
+        "",
+        max_proposal_execution_gas
+    );
+    display_line!(
+        context.io(),
+        "{:4}Rejected proposal refund fraction: {}",
+        "",
+        rejection_refund_fraction
+    );
+    display_line!(
+        context.io(),
+        "{:4}Min. proposal quorum: {}",
+        "",
+        min_proposal_quorum
+    );
+    display_line!(
+        context.io(),
+        "{:4}Max. proposal code retries: {}",
+        "",
+        max_proposal_code_retries
+    );
 
     let PgfParameters {
         stewards: _,
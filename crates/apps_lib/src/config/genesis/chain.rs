@@ -444,6 +444,10 @@ impl Finalized {
             max_proposal_content_size,
             min_proposal_grace_epochs,
             max_proposal_latency,
+            max_proposal_execution_gas,
+            rejection_refund_fraction,
+            min_proposal_quorum,
+            max_proposal_code_retries,
         } = self.parameters.gov_params.clone();
         namada_sdk::governance::parameters::GovernanceParameters {
             min_proposal_fund: Amount::native_whole(min_proposal_fund),
@@ -453,6 +457,10 @@ impl Finalized {
             min_proposal_grace_epochs,
             min_proposal_voting_period,
             max_proposal_latency,
+            max_proposal_execution_gas,
+            rejection_refund_fraction,
+            min_proposal_quorum,
+            max_proposal_code_retries,
         }
     }
 
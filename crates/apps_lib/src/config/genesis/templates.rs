@@ -444,6 +444,14 @@ pub struct GovernanceParams {
     pub min_proposal_grace_epochs: u64,
     /// Maximum number of epochs between current epoch and start epochs
     pub max_proposal_latency: u64,
+    /// Maximum amount of gas that the code of a proposal can consume
+    pub max_proposal_execution_gas: u64,
+    /// Fraction of the funds of a rejected proposal refunded to its author
+    pub rejection_refund_fraction: Dec,
+    /// Min fraction of the total voting power that must vote on a proposal
+    pub min_proposal_quorum: Dec,
+    /// Max number of times the code of a passed proposal is retried
+    pub max_proposal_code_retries: u64,
 }
 
 #[derive(
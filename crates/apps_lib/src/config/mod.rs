@@ -128,6 +128,10 @@ pub struct Shell {
     pub blocks_between_snapshots: Option<NonZeroU64>,
     /// Number of snapshots to keep
     pub snapshots_to_keep: Option<NonZeroU64>,
+    /// When set, the outcomes of all the governance proposals ended in a
+    /// block are emitted as a single event, rather than one event each
+    #[serde(default)]
+    pub batch_governance_events: bool,
 }
 
 impl Ledger {
@@ -159,6 +163,7 @@ impl Ledger {
                 last_tendermint_mode: None,
                 blocks_between_snapshots: None,
                 snapshots_to_keep: None,
+                batch_governance_events: false,
             },
             cometbft: tendermint_config,
             ethereum_bridge: ethereum_bridge::ledger::Config::default(),
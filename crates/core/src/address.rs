@@ -7,6 +7,7 @@ use std::fmt::{Debug, Display};
 use std::hash::Hash;
 use std::str::FromStr;
 
+use bech32::FromBase32;
 use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
 use data_encoding::HEXUPPER;
 use ibc::primitives::Signer;
@@ -15,6 +16,7 @@ use namada_macros::BorshDeserializer;
 use namada_migrations::*;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
+use thiserror::Error;
 
 use crate::ethereum_events::EthAddress;
 use crate::ibc::IbcTokenHash;
@@ -78,6 +80,21 @@ pub type DecodeError = string_encoding::DecodeError;
 /// Result of decoding address from string
 pub type Result<T> = std::result::Result<T, DecodeError>;
 
+/// Detailed error from decoding an address from a string, returned by
+/// [`Address::from_str_verbose`]
+#[allow(missing_docs)]
+#[derive(Error, Debug)]
+pub enum AddressDecodeError {
+    #[error("Unexpected address prefix {got:?}, expected {expected:?}")]
+    BadPrefix { got: String, expected: String },
+    #[error("Invalid Bech32m checksum, the address may contain a typo")]
+    BadChecksum,
+    #[error("Unknown address type with discriminant {0}")]
+    UnknownAddressType(u8),
+    #[error("Malformed address: {0}")]
+    Malformed(DecodeError),
+}
+
 /// An account's address
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(
@@ -283,6 +300,48 @@ impl Address {
         string_encoding::Format::decode(string)
     }
 
+    /// Decode an address from Bech32m encoding, like [`Address::decode`], but
+    /// telling apart addresses with the wrong prefix, with an invalid
+    /// Bech32m checksum (e.g. because of a typo) and with an unknown address
+    /// type.
+    pub fn from_str_verbose(
+        string: &str,
+    ) -> std::result::Result<Self, AddressDecodeError> {
+        let (hrp, base32, variant) =
+            bech32::decode(string).map_err(|err| match err {
+                bech32::Error::InvalidChecksum => {
+                    AddressDecodeError::BadChecksum
+                }
+                err => AddressDecodeError::Malformed(
+                    DecodeError::DecodeBech32(err),
+                ),
+            })?;
+        if hrp != string_encoding::ADDRESS_HRP {
+            return Err(AddressDecodeError::BadPrefix {
+                got: hrp,
+                expected: string_encoding::ADDRESS_HRP.to_string(),
+            });
+        }
+        // A valid Bech32 (rather than Bech32m) checksum is still the wrong
+        // checksum for an address
+        if variant != string_encoding::BECH32M_VARIANT {
+            return Err(AddressDecodeError::BadChecksum);
+        }
+        let bytes: Vec<u8> =
+            FromBase32::from_base32(&base32).map_err(|err| {
+                AddressDecodeError::Malformed(DecodeError::DecodeBase32(err))
+            })?;
+        if let Some(&discriminant) = bytes.first() {
+            if raw::Discriminant::try_from(discriminant).is_err() {
+                return Err(AddressDecodeError::UnknownAddressType(
+                    discriminant,
+                ));
+            }
+        }
+        <Self as string_encoding::Format>::decode_bytes(&bytes)
+            .map_err(AddressDecodeError::Malformed)
+    }
+
     /// Try to get a raw hash of an address, only defined for established and
     /// implicit addresses.
     pub fn raw_hash(&self) -> Option<String> {
@@ -660,6 +719,7 @@ impl InternalAddress {
 
 #[cfg(test)]
 pub mod tests {
+    use assert_matches::assert_matches;
     use proptest::prelude::*;
 
     use super::*;
@@ -699,6 +759,75 @@ pub mod tests {
         assert_eq!(encoded_address, expect);
     }
 
+    #[test]
+    fn test_address_from_str_verbose() {
+        use bech32::{ToBase32, Variant};
+
+        let encoded = "tnam1q8j5s6xp55p05yznwnftkv3kr9gjtsw3nq7x6tw5";
+        let address = Address::from_str_verbose(encoded).unwrap();
+        assert_eq!(address, Address::from_str(encoded).unwrap());
+
+        // a single character typo breaks the checksum
+        let typo = encoded.replace("tw5", "tw4");
+        assert_matches!(
+            Address::from_str_verbose(&typo),
+            Err(AddressDecodeError::BadChecksum)
+        );
+        assert!(Address::from_str(&typo).is_err());
+
+        let raw_bytes = string_encoding::Format::to_bytes(&address);
+        let encode = |hrp: &str, bytes: &[u8], variant: Variant| {
+            bech32::encode(hrp, bytes.to_base32(), variant).unwrap()
+        };
+
+        // an otherwise valid encoding with the wrong prefix
+        assert_matches!(
+            Address::from_str_verbose(&encode(
+                string_encoding::COMMON_PK_HRP,
+                &raw_bytes,
+                Variant::Bech32m,
+            )),
+            Err(AddressDecodeError::BadPrefix { got, .. })
+                if got == string_encoding::COMMON_PK_HRP
+        );
+
+        // a Bech32 (rather than Bech32m) checksum
+        assert_matches!(
+            Address::from_str_verbose(&encode(
+                string_encoding::ADDRESS_HRP,
+                &raw_bytes,
+                Variant::Bech32,
+            )),
+            Err(AddressDecodeError::BadChecksum)
+        );
+
+        // an unknown address type
+        let mut unknown_bytes = raw_bytes;
+        unknown_bytes[0] = u8::MAX;
+        assert_matches!(
+            Address::from_str_verbose(&encode(
+                string_encoding::ADDRESS_HRP,
+                &unknown_bytes,
+                Variant::Bech32m,
+            )),
+            Err(AddressDecodeError::UnknownAddressType(u8::MAX))
+        );
+
+        // a known address type with the wrong length
+        assert_matches!(
+            Address::from_str_verbose(&encode(
+                string_encoding::ADDRESS_HRP,
+                &raw_bytes[..10],
+                Variant::Bech32m,
+            )),
+            Err(AddressDecodeError::Malformed(_))
+        );
+        assert_matches!(
+            Address::from_str_verbose(""),
+            Err(AddressDecodeError::Malformed(_))
+        );
+    }
+
     proptest! {
         #[test]
         /// Check that all the address types are of the same length
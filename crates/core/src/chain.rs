@@ -51,6 +51,10 @@ pub const DEFAULT_CHAIN_ID: &str = "namada-internal.00000000000000";
 pub struct ChainId(pub String);
 
 impl ChainId {
+    /// The maximum length of a chain ID. Chain IDs derived from the genesis
+    /// are always exactly this long.
+    pub const MAX_LEN: usize = CHAIN_ID_LENGTH;
+
     /// Extracts a string slice containing the entire chain ID.
     pub fn as_str(&self) -> &str {
         &self.0
@@ -73,6 +77,17 @@ impl ChainId {
         ChainId(raw)
     }
 
+    /// Derive the chain ID from the genesis hash and a raw prefix, which is
+    /// validated first. This matches the derivation of the node, such that
+    /// tooling can reproduce a chain ID offline from the genesis.
+    pub fn try_from_genesis(
+        prefix: &str,
+        genesis_bytes: impl AsRef<[u8]>,
+    ) -> Result<Self, ChainIdPrefixParseError> {
+        let prefix = ChainIdPrefix::from_str(prefix)?;
+        Ok(Self::from_genesis(prefix, genesis_bytes))
+    }
+
     /// Validate that chain ID is matching the expected value derived from the
     /// genesis hash and release version.
     pub fn validate(
@@ -258,6 +273,15 @@ impl BlockHeight {
         Some(Self(self.0.checked_add(rhs)?))
     }
 
+    /// Saturating block height addition. Never panics, returning the maximum
+    /// block height on overflow.
+    #[must_use = "this returns the result of the operation, without modifying \
+                  the original"]
+    pub fn saturating_add(self, rhs: impl Into<BlockHeight>) -> Self {
+        let BlockHeight(rhs) = rhs.into();
+        Self(self.0.saturating_add(rhs))
+    }
+
     /// Checked block height subtraction.
     #[must_use = "this returns the result of the operation, without modifying \
                   the original"]
@@ -353,7 +377,17 @@ impl Epoch {
         (start_ix..end_ix).map(Epoch::from)
     }
 
-    /// Iterate a range of epochs, inclusive of the start and end.
+    /// Iterate a range of epochs, inclusive of the start and exclusive of the
+    /// end. Yields nothing if `start` is not before `end`.
+    pub fn iter_bounds(
+        start: Self,
+        end: Self,
+    ) -> impl Iterator<Item = Epoch> + Clone {
+        (start.0..end.0).map(Epoch::from)
+    }
+
+    /// Iterate a range of epochs, inclusive of the start and end. Yields
+    /// nothing if `start` is after `end`.
     pub fn iter_bounds_inclusive(
         start: Self,
         end: Self,
@@ -580,13 +614,14 @@ impl FromStr for ChainId {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let len = s.len();
-        if len != CHAIN_ID_LENGTH {
+        if len != Self::MAX_LEN {
             return Err(ChainIdParseError::UnexpectedLen(len));
         }
         let mut forbidden_chars = s
             .chars()
             .filter(|char| {
-                !matches!(*char as u8, b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'-' | b'_' | b'.')
+                !(char.is_ascii_alphanumeric()
+                    || matches!(*char, '-' | '_' | '.'))
             })
             .peekable();
         if forbidden_chars.peek().is_some() {
@@ -656,7 +691,8 @@ impl FromStr for ChainIdPrefix {
         let mut forbidden_chars = s
             .chars()
             .filter(|char| {
-                !matches!(*char as u8, b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'-' | b'_' | b'.')
+                !(char.is_ascii_alphanumeric()
+                    || matches!(*char, '-' | '_' | '.'))
             })
             .peekable();
         if forbidden_chars.peek().is_some() {
@@ -764,6 +800,120 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_chain_id_from_genesis_vector() {
+        const GENESIS: &[u8] = b"namada genesis";
+        let chain_id = ChainId::try_from_genesis("namada-test", GENESIS)
+            .expect("Test failed");
+        assert_eq!(chain_id.as_str(), "namada-test.5fb663ad67dec293ae");
+        assert_eq!(chain_id.as_str().len(), CHAIN_ID_LENGTH);
+        assert!(chain_id.validate(GENESIS).is_empty());
+        assert_eq!(
+            chain_id,
+            ChainId::from_genesis(
+                ChainIdPrefix::from_str("namada-test").unwrap(),
+                GENESIS
+            )
+        );
+
+        // invalid prefixes are rejected
+        assert!(matches!(
+            ChainId::try_from_genesis("", GENESIS),
+            Err(ChainIdPrefixParseError::UnexpectedLen(0))
+        ));
+        assert!(matches!(
+            ChainId::try_from_genesis("namada test", GENESIS),
+            Err(ChainIdPrefixParseError::ForbiddenCharacters(_))
+        ));
+    }
+
+    #[test]
+    fn test_chain_id_parse_validation() {
+        assert!(ChainId::from_str(DEFAULT_CHAIN_ID).is_ok());
+
+        // over-length
+        let too_long = format!("{DEFAULT_CHAIN_ID}0");
+        assert!(too_long.len() > ChainId::MAX_LEN);
+        assert!(matches!(
+            ChainId::from_str(&too_long),
+            Err(ChainIdParseError::UnexpectedLen(len))
+                if len == too_long.len()
+        ));
+
+        // a space
+        let with_space = DEFAULT_CHAIN_ID.replacen('-', " ", 1);
+        assert!(matches!(
+            ChainId::from_str(&with_space),
+            Err(ChainIdParseError::ForbiddenCharacters(chars))
+                if chars == vec![' ']
+        ));
+
+        // a non-ascii char whose low byte is an allowed ascii char
+        let non_ascii = DEFAULT_CHAIN_ID.replacen("00", "\u{161}", 1);
+        assert_eq!(non_ascii.len(), ChainId::MAX_LEN);
+        assert!(matches!(
+            ChainId::from_str(&non_ascii),
+            Err(ChainIdParseError::ForbiddenCharacters(chars))
+                if chars == vec!['\u{161}']
+        ));
+    }
+
+    #[test]
+    fn test_epoch_prev() {
+        assert_eq!(Epoch(0).prev(), None);
+        assert_eq!(Epoch(1).prev(), Some(Epoch(0)));
+        assert_eq!(Epoch(42).prev(), Some(Epoch(41)));
+        assert_eq!(Epoch(42).next().prev(), Some(Epoch(42)));
+        assert_eq!(
+            Epoch(u64::MAX).prev().map(|epoch| epoch.next()),
+            Some(Epoch(u64::MAX))
+        );
+    }
+
+    #[test]
+    fn test_block_height_add_at_max_boundary() {
+        let max = BlockHeight(u64::MAX);
+        let max_minus_one = max.prev_height().unwrap();
+        assert_eq!(max.checked_add(0), Some(max));
+        assert_eq!(max.checked_add(1), None);
+        assert_eq!(max_minus_one.checked_add(1), Some(max));
+        assert_eq!(max.saturating_add(0), max);
+        assert_eq!(max.saturating_add(1), max);
+        assert_eq!(max.saturating_add(u64::MAX), max);
+        assert_eq!(max_minus_one.saturating_add(BlockHeight(5)), max);
+        assert_eq!(BlockHeight(1).saturating_add(1), BlockHeight(2));
+    }
+
+    #[test]
+    fn test_epoch_iter_bounds() {
+        let collect = |iter: &mut dyn Iterator<Item = Epoch>| {
+            iter.map(|epoch| epoch.0).collect::<Vec<_>>()
+        };
+        assert_eq!(
+            collect(&mut Epoch::iter_bounds(Epoch(2), Epoch(5))),
+            vec![2, 3, 4]
+        );
+        assert_eq!(
+            collect(&mut Epoch::iter_bounds_inclusive(Epoch(2), Epoch(5))),
+            vec![2, 3, 4, 5]
+        );
+        assert!(collect(&mut Epoch::iter_bounds(Epoch(3), Epoch(3))).is_empty());
+        assert_eq!(
+            collect(&mut Epoch::iter_bounds_inclusive(Epoch(3), Epoch(3))),
+            vec![3]
+        );
+        // a start after the end yields nothing
+        assert!(collect(&mut Epoch::iter_bounds(Epoch(5), Epoch(2))).is_empty());
+        assert!(
+            collect(&mut Epoch::iter_bounds_inclusive(Epoch(5), Epoch(2)))
+                .is_empty()
+        );
+        assert!(
+            collect(&mut Epoch::iter_bounds(Epoch(u64::MAX), Epoch(0)))
+                .is_empty()
+        );
+    }
+
     #[test]
     fn test_predecessor_epochs_and_heights() {
         let mut epochs = Epochs {
@@ -17,7 +17,7 @@ use serde::{Deserialize, Serialize};
 use super::token::NATIVE_MAX_DECIMAL_PLACES;
 use crate::arith::{self, checked};
 use crate::token;
-use crate::uint::{Uint, I256};
+use crate::uint::{Uint, I256, MAX_SIGNED_VALUE};
 
 /// The number of Dec places for PoS rational calculations
 pub const POS_DECIMAL_PRECISION: u8 = 12;
@@ -56,6 +56,12 @@ pub type Result<T> = std::result::Result<T, Error>;
 pub struct Dec(pub I256);
 
 impl Dec {
+    /// The largest value of a [`Dec`]
+    pub const MAX: Self = Self(I256(MAX_SIGNED_VALUE));
+    /// The smallest value of a [`Dec`]
+    pub const MIN: Self =
+        Self(I256(Uint([1, 0, 0, 0x8000_0000_0000_0000_u64])));
+
     /// Performs division with truncation.
     ///
     /// This method divides `self` by `rhs` (right-hand side) and truncates the
@@ -179,6 +185,30 @@ impl Dec {
         }
     }
 
+    /// Create a new [`Dec`] from the ratio of two integers, truncated to
+    /// [`POS_DECIMAL_PRECISION`] decimal places. Errors if the denominator is
+    /// zero.
+    pub fn from_ratio(numer: u64, denom: u64) -> Result<Self> {
+        if denom == 0 {
+            return Err(eyre!(
+                "Cannot create a Dec from the ratio {numer}/{denom} with a \
+                 zero denominator"
+            )
+            .into());
+        }
+        Self::from(numer)
+            .trunc_div(&Self::from(denom))
+            .ok_or_else(|| eyre!("Dec ratio {numer}/{denom} overflow").into())
+    }
+
+    /// Check that the value lies between [`Dec::MIN`] and [`Dec::MAX`]. The
+    /// only representable value outside of these bounds is the negative zero
+    /// of the underlying [`I256`], which cannot be negated and does not
+    /// round-trip through the string encoding of a [`Dec`].
+    pub fn is_within_storage_bounds(&self) -> bool {
+        !(self.is_negative() && self.0.abs().is_zero())
+    }
+
     /// Get the non-negative difference between two [`Dec`]s.
     pub fn abs_diff(
         &self,
@@ -814,6 +844,62 @@ mod test_dec {
         );
     }
 
+    /// Test that the boundary values of [`Dec`] round-trip through Borsh and
+    /// its string encoding, and that the negative zero is out of bounds.
+    #[test]
+    fn test_dec_storage_bounds() {
+        use crate::borsh::BorshSerializeExt;
+
+        for value in [Dec::MIN, Dec::zero(), Dec::MAX] {
+            assert!(value.is_within_storage_bounds());
+            let bytes = value.serialize_to_vec();
+            assert_eq!(
+                Dec::try_from_slice(&bytes).expect("Test failed"),
+                value
+            );
+            assert_eq!(
+                Dec::from_str(&value.to_string()).expect("Test failed"),
+                value
+            );
+        }
+        assert!(Dec::MIN < Dec::zero());
+        assert_eq!(Dec::MIN.checked_neg(), Some(Dec::MAX));
+
+        let negative_zero = Dec(I256(Uint([0, 0, 0, 0x8000_0000_0000_0000])));
+        assert!(!negative_zero.is_within_storage_bounds());
+        assert_ne!(
+            Dec::from_str(&negative_zero.to_string()).expect("Test failed"),
+            negative_zero
+        );
+    }
+
+    /// Test that a [`Dec`] is created from an exact or repeating ratio, and
+    /// not from a ratio with a zero denominator.
+    #[test]
+    fn test_dec_from_ratio() {
+        assert_eq!(
+            Dec::from_ratio(3, 4).expect("Test failed"),
+            Dec::from_str("0.75").expect("Test failed")
+        );
+        assert_eq!(Dec::from_ratio(10, 2).expect("Test failed"), Dec::from(5));
+        assert_eq!(Dec::from_ratio(0, 7).expect("Test failed"), Dec::zero());
+        // Repeating decimals are truncated
+        assert_eq!(
+            Dec::from_ratio(2, 3).expect("Test failed"),
+            Dec::from_str("0.666666666666").expect("Test failed")
+        );
+        assert_eq!(
+            Dec::from_ratio(1, 3).expect("Test failed"),
+            Dec::one_third()
+        );
+        assert_eq!(
+            Dec::from_ratio(u64::MAX, 1).expect("Test failed"),
+            Dec::from(u64::MAX)
+        );
+        assert!(Dec::from_ratio(1, 0).is_err());
+        assert!(Dec::from_ratio(0, 0).is_err());
+    }
+
     #[test]
     fn test_dec_display() {
         let num = Dec::from_str("14000.0000").unwrap();
@@ -7,6 +7,7 @@ use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
 #[doc(inline)]
 pub use ethabi::token::Token;
 
+use crate::ethereum_events::EthAddress;
 use crate::keccak::{keccak_hash, KeccakHash};
 use crate::key::{Signable, SignableEthMessage};
 
@@ -118,6 +119,75 @@ impl<const N: usize> Encode<N> for AbiEncode<N> {
     }
 }
 
+/// Contains methods to encode data as EIP-712 typed structured data.
+pub trait Eip712Encode {
+    /// The EIP-712 encoded type of `Self`, e.g.
+    /// `Mail(Person from,Person to,string contents)`, followed by the
+    /// encoded types of the structs it references, sorted by name.
+    const ENCODED_TYPE: &'static str;
+
+    /// Encodes the members of `Self` into a sequence of ABI [`Token`]
+    /// instances, in the order they are declared in
+    /// [`Eip712Encode::ENCODED_TYPE`]. Dynamic values, such as strings,
+    /// and referenced structs must be encoded as their keccak hashes.
+    fn encode_data(&self) -> Vec<Token>;
+
+    /// Returns the keccak hash of [`Eip712Encode::ENCODED_TYPE`].
+    fn type_hash() -> KeccakHash {
+        keccak_hash(Self::ENCODED_TYPE)
+    }
+
+    /// Returns the EIP-712 `hashStruct` of `self`.
+    fn hash_struct(&self) -> KeccakHash {
+        let KeccakHash(type_hash) = Self::type_hash();
+        let mut tokens = vec![Token::FixedBytes(type_hash.to_vec())];
+        tokens.extend(self.encode_data());
+        keccak_hash(ethabi::encode(&tokens))
+    }
+
+    /// Returns the keccak hash of `self`, domain separated by `domain`,
+    /// as specified by EIP-712. This can then be signed.
+    fn eip712_hash(&self, domain: &Eip712Domain) -> KeccakHash {
+        let KeccakHash(domain_separator) = domain.hash_struct();
+        let KeccakHash(struct_hash) = self.hash_struct();
+
+        let mut message = [0u8; 66];
+        message[..2].copy_from_slice(b"\x19\x01");
+        message[2..34].copy_from_slice(&domain_separator);
+        message[34..].copy_from_slice(&struct_hash);
+        keccak_hash(message)
+    }
+}
+
+/// The EIP-712 domain used to separate the hashes of typed
+/// structured data signed for different dApps.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Eip712Domain {
+    /// The name of the signing domain.
+    pub name: String,
+    /// The current major version of the signing domain.
+    pub version: String,
+    /// The EIP-155 chain id of the Ethereum network.
+    pub chain_id: u64,
+    /// The address of the contract that will verify signatures.
+    pub verifying_contract: EthAddress,
+}
+
+impl Eip712Encode for Eip712Domain {
+    const ENCODED_TYPE: &'static str = "EIP712Domain(string name,string \
+                                        version,uint256 chainId,address \
+                                        verifyingContract)";
+
+    fn encode_data(&self) -> Vec<Token> {
+        vec![
+            Token::FixedBytes(keccak_hash(&self.name).0.to_vec()),
+            Token::FixedBytes(keccak_hash(&self.version).0.to_vec()),
+            Token::Uint(self.chain_id.into()),
+            Token::Address(self.verifying_contract.0.into()),
+        ]
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
@@ -127,7 +197,6 @@ mod tests {
     use tiny_keccak::{Hasher, Keccak};
 
     use super::*;
-    use crate::ethereum_events::EthAddress;
 
     /// Checks if we get the same result as `abi.encode`, for some given
     /// input data.
@@ -173,6 +242,97 @@ mod tests {
         assert_eq!(keccak_hash.to_string().as_str(), original);
     }
 
+    /// Checks that we produce the same hashes as the reference
+    /// implementation of EIP-712, for its `Mail` example.
+    #[test]
+    fn test_eip712_encode() {
+        struct Person {
+            name: &'static str,
+            wallet: EthAddress,
+        }
+
+        impl Eip712Encode for Person {
+            const ENCODED_TYPE: &'static str =
+                "Person(string name,address wallet)";
+
+            fn encode_data(&self) -> Vec<Token> {
+                vec![
+                    Token::FixedBytes(keccak_hash(self.name).0.to_vec()),
+                    Token::Address(self.wallet.0.into()),
+                ]
+            }
+        }
+
+        struct Mail {
+            from: Person,
+            to: Person,
+            contents: &'static str,
+        }
+
+        impl Eip712Encode for Mail {
+            const ENCODED_TYPE: &'static str =
+                "Mail(Person from,Person to,string contents)Person(string \
+                 name,address wallet)";
+
+            fn encode_data(&self) -> Vec<Token> {
+                vec![
+                    Token::FixedBytes(self.from.hash_struct().0.to_vec()),
+                    Token::FixedBytes(self.to.hash_struct().0.to_vec()),
+                    Token::FixedBytes(keccak_hash(self.contents).0.to_vec()),
+                ]
+            }
+        }
+
+        let domain = Eip712Domain {
+            name: "Ether Mail".into(),
+            version: "1".into(),
+            chain_id: 1,
+            verifying_contract: EthAddress::from_str(
+                "0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC",
+            )
+            .expect("Test failed"),
+        };
+        let mail = Mail {
+            from: Person {
+                name: "Cow",
+                wallet: EthAddress::from_str(
+                    "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826",
+                )
+                .expect("Test failed"),
+            },
+            to: Person {
+                name: "Bob",
+                wallet: EthAddress::from_str(
+                    "0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB",
+                )
+                .expect("Test failed"),
+            },
+            contents: "Hello, Bob!",
+        };
+
+        let expected_type_hash =
+            "a0cedeb2dc280ba39b857546d74f5549c3a1d7bdc2dd96bf881f76108e23dac2";
+        assert_eq!(HEXLOWER.encode(&Mail::type_hash().0), expected_type_hash);
+        let expected_domain_separator =
+            "f2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f";
+        assert_eq!(
+            HEXLOWER.encode(&domain.hash_struct().0),
+            expected_domain_separator
+        );
+        let expected_struct_hash =
+            "c52c0ee5d84264471806290a3f2c4cecfc5490626bf912d01f240d7a274b371e";
+        assert_eq!(
+            HEXLOWER.encode(&mail.hash_struct().0),
+            expected_struct_hash
+        );
+        let expected_hash =
+            "be609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2";
+        assert_eq!(
+            HEXLOWER.encode(&mail.eip712_hash(&domain).0),
+            expected_hash
+        );
+    }
+
     #[test]
     fn test_abi_encode_address() {
         let address =
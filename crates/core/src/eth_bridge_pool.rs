@@ -9,6 +9,7 @@ use namada_macros::{BorshDeserializer, StorageKeys};
 #[cfg(feature = "migrations")]
 use namada_migrations::*;
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
 use super::address::InternalAddress;
 use super::keccak::KeccakHash;
@@ -226,6 +227,22 @@ pub struct PendingTransfer {
     pub gas_fee: GasFee,
 }
 
+/// Errors from validating a [`PendingTransfer`].
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum BridgePoolError {
+    /// The recipient of the transfer is the zero Ethereum address.
+    #[error(
+        "The recipient of a transfer to Ethereum must not be the zero address"
+    )]
+    ZeroRecipient,
+    /// The amount of the transfer is zero.
+    #[error("The amount of a transfer to Ethereum must not be zero")]
+    ZeroAmount,
+    /// The gas fees are to be paid in a token that is not accepted.
+    #[error("Unrecognized gas fee token {0}")]
+    UnrecognizedFeeToken(Address),
+}
+
 /// Construct a token address from an ERC20 address.
 pub fn erc20_token_address(address: &EthAddress) -> Address {
     Address::Internal(InternalAddress::Erc20(*address))
@@ -250,6 +267,28 @@ impl PendingTransfer {
         }
     }
 
+    /// Check that this [`PendingTransfer`] is well-formed, i.e. that it has a
+    /// non-zero Ethereum recipient and amount, and that its gas fees are paid
+    /// in a recognized token. Checks that depend on the state of the chain,
+    /// such as the ERC20 whitelist, are left to the Bridge pool VP.
+    pub fn validate(&self) -> Result<(), BridgePoolError> {
+        if self.transfer.recipient == EthAddress([0; 20]) {
+            return Err(BridgePoolError::ZeroRecipient);
+        }
+        if self.transfer.amount.is_zero() {
+            return Err(BridgePoolError::ZeroAmount);
+        }
+        // NUTs can't be used to pay for gas fees, and neither can non-token
+        // internal addresses
+        match &self.gas_fee.token {
+            Address::Established(_)
+            | Address::Internal(
+                InternalAddress::Erc20(_) | InternalAddress::IbcToken(_),
+            ) => Ok(()),
+            token => Err(BridgePoolError::UnrecognizedFeeToken(token.clone())),
+        }
+    }
+
     /// Retrieve a reference to the appendix of this [`PendingTransfer`].
     #[inline]
     pub fn appendix(&self) -> PendingTransferAppendix<'_> {
@@ -467,4 +506,51 @@ mod test_eth_bridge_pool_types {
         let event: TransferToEthereumEvent = (&pending).into();
         assert_eq!(pending.keccak256(), event.keccak256());
     }
+
+    /// Test the validation of malformed [`PendingTransfer`] instances.
+    #[test]
+    fn test_pending_transfer_validation() {
+        let pending = PendingTransfer {
+            transfer: TransferToEthereum {
+                kind: TransferToEthereumKind::Erc20,
+                amount: 10u64.into(),
+                asset: EthAddress([0xaa; 20]),
+                recipient: EthAddress([0xbb; 20]),
+                sender: established_address_1(),
+            },
+            gas_fee: GasFee {
+                token: nam(),
+                amount: 10u64.into(),
+                payer: established_address_1(),
+            },
+        };
+        assert_eq!(pending.validate(), Ok(()));
+
+        let mut erc20_fees = pending.clone();
+        erc20_fees.gas_fee.token = erc20_token_address(&EthAddress([0xcc; 20]));
+        assert_eq!(erc20_fees.validate(), Ok(()));
+
+        let mut zero_recipient = pending.clone();
+        zero_recipient.transfer.recipient = EthAddress([0; 20]);
+        assert_eq!(
+            zero_recipient.validate(),
+            Err(BridgePoolError::ZeroRecipient)
+        );
+
+        let mut zero_amount = pending.clone();
+        zero_amount.transfer.amount = Amount::zero();
+        assert_eq!(zero_amount.validate(), Err(BridgePoolError::ZeroAmount));
+
+        for token in [
+            erc20_nut_address(&EthAddress([0xcc; 20])),
+            Address::Internal(InternalAddress::PoS),
+        ] {
+            let mut bad_fee_token = pending.clone();
+            bad_fee_token.gas_fee.token = token.clone();
+            assert_eq!(
+                bad_fee_token.validate(),
+                Err(BridgePoolError::UnrecognizedFeeToken(token))
+            );
+        }
+    }
 }
@@ -1,5 +1,6 @@
 //! Ethereum bridge struct re-exports and types to do with ethereum.
 
+use std::collections::BTreeMap;
 use std::fmt;
 use std::io::Read;
 use std::num::NonZeroU64;
@@ -10,6 +11,110 @@ pub use ethbridge_structs::*;
 use num256::Uint256;
 use serde::{Deserialize, Serialize};
 
+use crate::eth_abi::Encode;
+use crate::eth_bridge_pool::PendingTransfer;
+use crate::keccak::{keccak_hash, KeccakHash};
+
+/// Prefix to be used in Bridge pool tree root computations.
+/// This value corresponds to leaf nodes.
+pub const POOL_ROOT_PREFIX_LEAF: u8 = 0x00;
+
+/// Prefix to be used in Bridge pool tree root computations.
+/// This value corresponds to non-leaf nodes.
+pub const POOL_ROOT_PREFIX_NON_LEAF: u8 = 0xff;
+
+/// Concatenate a byte prefix and two keccak hashes,
+/// then compute the keccak hash of the resulting
+/// byte array.
+#[inline]
+pub fn hash_pair(
+    left: KeccakHash,
+    right: KeccakHash,
+    prefix: u8,
+) -> KeccakHash {
+    let mut buf = [0u8; 32 + 32 + 1];
+    buf[0] = prefix;
+    if left.0 < right.0 {
+        buf[1..33].copy_from_slice(&left.0);
+        buf[33..].copy_from_slice(&right.0);
+    } else {
+        buf[1..33].copy_from_slice(&right.0);
+        buf[33..].copy_from_slice(&left.0);
+    }
+    keccak_hash(buf)
+}
+
+/// Compute the root of the Bridge pool merkle tree with the given leaves,
+/// which must be sorted.
+///
+/// Note that an empty tree has root [0u8; 32] by definition.
+pub fn bridge_pool_root(mut hashes: Vec<KeccakHash>) -> KeccakHash {
+    let mut prefix = POOL_ROOT_PREFIX_LEAF;
+    while hashes.len() > 1 {
+        let mut next_hashes = vec![];
+        for pair in hashes.chunks(2) {
+            let left = pair[0].clone();
+            let right = pair.get(1).cloned().unwrap_or_default();
+            next_hashes.push(hash_pair(left, right, prefix));
+        }
+        hashes = next_hashes;
+        prefix = POOL_ROOT_PREFIX_NON_LEAF;
+    }
+
+    if hashes.is_empty() {
+        Default::default()
+    } else {
+        hashes.remove(0)
+    }
+}
+
+/// A batch of transfers to be relayed to Ethereum, in the canonical order
+/// expected by the merkle verification of the bridge contract, i.e. sorted
+/// by the keccak hash of each [`PendingTransfer`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RawTransfersToEthereum {
+    /// The transfers to relay, in canonical order
+    pub transfers: Vec<Erc20Transfer>,
+    /// The keccak hashes of the transfers, in canonical order
+    pub hashes: Vec<KeccakHash>,
+    /// The Bridge pool merkle root of the transfers
+    pub root: KeccakHash,
+}
+
+/// Builder of a [`RawTransfersToEthereum`] batch. The order in which
+/// transfers are added is irrelevant.
+#[derive(Debug, Clone, Default)]
+pub struct RawTransfersToEthereumBuilder {
+    transfers: BTreeMap<KeccakHash, Erc20Transfer>,
+}
+
+impl RawTransfersToEthereumBuilder {
+    /// Create a new, empty builder
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a pending transfer to the batch. Adding the same transfer more
+    /// than once has no effect.
+    pub fn transfer(mut self, transfer: &PendingTransfer) -> Self {
+        self.transfers
+            .insert(transfer.keccak256(), Erc20Transfer::from(transfer));
+        self
+    }
+
+    /// Sort the transfers canonically, and compute their merkle root
+    pub fn build(self) -> RawTransfersToEthereum {
+        let (hashes, transfers): (Vec<_>, Vec<_>) =
+            self.transfers.into_iter().unzip();
+        let root = bridge_pool_root(hashes.clone());
+        RawTransfersToEthereum {
+            transfers,
+            hashes,
+            root,
+        }
+    }
+}
+
 /// This type must be able to represent any valid Ethereum block height. It must
 /// also be Borsh serializeable, so that it can be stored in blockchain storage.
 ///
@@ -116,3 +221,40 @@ impl BorshDeserialize for BlockHeight {
         Ok(Self(Uint256::from_bytes_be(&be)))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eth_bridge_pool::testing::arb_pending_transfer;
+
+    proptest::proptest! {
+        /// Test that the order in which transfers are added to a batch does
+        /// not affect the batch.
+        #[test]
+        fn test_raw_transfers_insertion_order(
+            transfers in proptest::collection::vec(arb_pending_transfer(), 0..10),
+        ) {
+            let forward = transfers
+                .iter()
+                .fold(RawTransfersToEthereumBuilder::new(), |builder, t| {
+                    builder.transfer(t)
+                })
+                .build();
+            let backward = transfers
+                .iter()
+                .rev()
+                .fold(RawTransfersToEthereumBuilder::new(), |builder, t| {
+                    builder.transfer(t)
+                })
+                .build();
+            assert_eq!(forward, backward);
+
+            let mut hashes: Vec<_> =
+                transfers.iter().map(|t| t.keccak256()).collect();
+            hashes.sort();
+            hashes.dedup();
+            assert_eq!(forward.hashes, hashes);
+            assert_eq!(forward.root, bridge_pool_root(hashes));
+        }
+    }
+}
@@ -6,7 +6,7 @@ use std::str::FromStr;
 use arse_merkle_tree::traits::Hasher;
 use arse_merkle_tree::H256;
 use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
-use data_encoding::HEXUPPER;
+use data_encoding::{HEXLOWER, HEXUPPER};
 use namada_macros::BorshDeserializer;
 #[cfg(feature = "migrations")]
 use namada_migrations::*;
@@ -14,6 +14,8 @@ use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use sha2::{Digest, Sha256};
 use thiserror::Error;
 
+use crate::keccak::KeccakHash;
+
 /// The length of the transaction hash string
 pub const HASH_LENGTH: usize = 32;
 
@@ -31,6 +33,16 @@ pub enum Error {
     FromStringError(data_encoding::DecodeError),
     #[error("Cannot convert an empty CometBFT hash")]
     FromCometError,
+    #[error(
+        "Invalid hex encoded hash length {got}, expected {expected} hex \
+         characters"
+    )]
+    InvalidHexLength { expected: usize, got: usize },
+    #[error(
+        "Invalid character {character:?} at position {position} of hex \
+         encoded hash"
+    )]
+    InvalidHexCharacter { character: char, position: usize },
 }
 
 /// Result for functions that may fail
@@ -199,6 +211,40 @@ impl Hash {
         self.0.as_ptr()
     }
 
+    /// Parse a hex encoded hash, in either lowercase or uppercase and with an
+    /// optional `0x` prefix. Returns an error describing whether the input
+    /// has invalid characters or the wrong length.
+    pub fn from_hex(hex: &str) -> HashResult<Self> {
+        let hex = hex
+            .strip_prefix("0x")
+            .or_else(|| hex.strip_prefix("0X"))
+            .unwrap_or(hex);
+        if let Some((position, character)) =
+            hex.char_indices().find(|(_, c)| !c.is_ascii_hexdigit())
+        {
+            return Err(Error::InvalidHexCharacter {
+                character,
+                position,
+            });
+        }
+        if hex.len() != HEX_HASH_LENGTH {
+            return Err(Error::InvalidHexLength {
+                expected: HEX_HASH_LENGTH,
+                got: hex.len(),
+            });
+        }
+        let mut output = [0u8; HASH_LENGTH];
+        HEXLOWER
+            .decode_mut(hex.to_ascii_lowercase().as_bytes(), &mut output)
+            .map_err(|err| Error::FromStringError(err.error))?;
+        Ok(Self(output))
+    }
+
+    /// Encode this hash as a lowercase hex string, without a `0x` prefix.
+    pub fn to_hex(&self) -> String {
+        HEXLOWER.encode(&self.0)
+    }
+
     /// Given hashes A and B, compute Sha256(A||B),
     /// but if one value is the zero hash, the other
     /// value is returned.
@@ -315,6 +361,33 @@ impl Default for KeccakHasher {
     }
 }
 
+impl KeccakHasher {
+    /// Create a new incremental Keccak hasher.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feed some bytes to the hasher. Feeding some data in many chunks
+    /// yields the same digest as feeding it all at once.
+    #[inline]
+    pub fn update(&mut self, bytes: &[u8]) {
+        use tiny_keccak::Hasher;
+
+        self.0.update(bytes);
+    }
+
+    /// Compute the Keccak hash of all the bytes fed to the hasher.
+    #[inline]
+    pub fn finalize(self) -> KeccakHash {
+        use tiny_keccak::Hasher;
+        let mut output = [0; 32];
+
+        self.0.finalize(&mut output);
+        KeccakHash(output)
+    }
+}
+
 impl StorageHasher for KeccakHasher {
     fn hash(value: impl AsRef<[u8]>) -> H256 {
         use tiny_keccak::{Hasher, Keccak};
@@ -391,6 +464,7 @@ pub mod testing {
 #[cfg(test)]
 /// Tests and strategies for hashes
 pub mod tests {
+    use assert_matches::assert_matches;
     use proptest::prelude::*;
     use proptest::string::{string_regex, RegexGeneratorStrategy};
 
@@ -406,5 +480,61 @@ pub mod tests {
         fn test_hash_string(hex_hash in hex_encoded_hash_strat()) {
             let _: Hash = hex_hash.try_into().unwrap();
         }
+
+        #[test]
+        fn test_hash_hex_roundtrip(hex_hash in hex_encoded_hash_strat()) {
+            let hash = Hash::from_hex(&hex_hash).unwrap();
+            assert_eq!(hash.to_hex(), hex_hash.to_lowercase());
+            assert_eq!(Hash::from_hex(&hash.to_hex()).unwrap(), hash);
+            assert_eq!(Hash::from_hex(&format!("0x{hex_hash}")).unwrap(), hash);
+        }
+    }
+
+    #[test]
+    fn test_hash_from_hex() {
+        let hash = Hash::sha256(b"namada");
+        let lower = hash.to_hex();
+        assert_eq!(lower, lower.to_lowercase());
+        assert!(!lower.starts_with("0x"));
+
+        // uppercase input, with and without a `0x` prefix
+        let upper = lower.to_uppercase();
+        assert_eq!(Hash::from_hex(&upper).unwrap(), hash);
+        assert_eq!(Hash::from_hex(&format!("0x{upper}")).unwrap(), hash);
+        assert_eq!(Hash::from_hex(&format!("0X{lower}")).unwrap(), hash);
+
+        assert_matches!(
+            Hash::from_hex(""),
+            Err(Error::InvalidHexLength {
+                expected: HEX_HASH_LENGTH,
+                got: 0
+            })
+        );
+        assert_matches!(
+            Hash::from_hex("0x"),
+            Err(Error::InvalidHexLength { got: 0, .. })
+        );
+        assert_matches!(
+            Hash::from_hex(&lower[1..]),
+            Err(Error::InvalidHexLength { got: 63, .. })
+        );
+        assert_matches!(
+            Hash::from_hex(&format!("{lower}00")),
+            Err(Error::InvalidHexLength { got: 66, .. })
+        );
+        assert_matches!(
+            Hash::from_hex(&format!("{}g", &lower[1..])),
+            Err(Error::InvalidHexCharacter {
+                character: 'g',
+                position: 63
+            })
+        );
+        assert_matches!(
+            Hash::from_hex("0x0x00"),
+            Err(Error::InvalidHexCharacter {
+                character: 'x',
+                position: 1
+            })
+        );
     }
 }
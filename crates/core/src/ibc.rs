@@ -3,18 +3,27 @@
 use std::collections::BTreeMap;
 use std::fmt::Display;
 use std::str::FromStr;
+use std::time::Duration;
 
 use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
 use data_encoding::{DecodePartial, HEXLOWER, HEXLOWER_PERMISSIVE};
+use ibc::apps::transfer::types::{
+    BaseDenom, PrefixedDenom, TracePath, TracePrefix,
+};
+use ibc::core::channel::types::timeout::{TimeoutHeight, TimeoutTimestamp};
+use ibc::core::client::types::Height;
 use ibc::core::host::types::identifiers::{ChannelId, PortId};
+use ibc::primitives::Timestamp;
 pub use ibc::*;
 use namada_macros::BorshDeserializer;
 #[cfg(feature = "migrations")]
 use namada_migrations::*;
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
 use super::address::HASH_LEN;
 use crate::hash::Hash;
+use crate::time::DateTimeUtc;
 use crate::token;
 
 /// IBC token hash derived from a denomination.
@@ -147,6 +156,164 @@ impl borsh::BorshSchema for PGFIbcTarget {
     }
 }
 
+/// Errors from parsing an IBC denom trace
+#[allow(missing_docs)]
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum IbcDenomError {
+    #[error("The denom is empty")]
+    Empty,
+    #[error("The denom trace {0:?} contains an empty segment")]
+    EmptySegment(String),
+    #[error("Invalid port ID {port:?} in the denom trace {denom:?}")]
+    InvalidPortId { denom: String, port: String },
+    #[error("Invalid channel ID {channel:?} in the denom trace {denom:?}")]
+    InvalidChannelId { denom: String, channel: String },
+    #[error("The denom trace {0:?} has no base denom")]
+    MissingBaseDenom(String),
+}
+
+/// The trace of an IBC fungible token denom, e.g. `transfer/channel-0/unam`,
+/// made of the `port/channel` hops the token went through followed by the
+/// base denom on its origin chain.
+///
+/// Unlike parsing a [`PrefixedDenom`] directly, which silently folds any
+/// malformed hop into the base denom, the hops are validated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DenomTrace(PrefixedDenom);
+
+impl DenomTrace {
+    /// Prefix of the channel IDs in a denom trace
+    const CHANNEL_PREFIX: &'static str = "channel-";
+
+    /// Parse a denom trace, validating the structure of its `port/channel`
+    /// hops. Segments that do not form a hop are part of the base denom,
+    /// which may itself contain `/`.
+    pub fn parse(denom: &str) -> Result<Self, IbcDenomError> {
+        if denom.is_empty() {
+            return Err(IbcDenomError::Empty);
+        }
+        let segments: Vec<&str> = denom.split('/').collect();
+        if segments.iter().any(|segment| segment.is_empty()) {
+            return Err(IbcDenomError::EmptySegment(denom.to_string()));
+        }
+
+        let mut hops = vec![];
+        let mut rest = segments.as_slice();
+        while let [port, channel, tail @ ..] = rest {
+            let Some(channel_number) =
+                channel.strip_prefix(Self::CHANNEL_PREFIX)
+            else {
+                break;
+            };
+            let channel_id = channel_number
+                .parse::<u64>()
+                .ok()
+                .map(ChannelId::new)
+                .filter(|channel_id| channel_id.as_str() == *channel)
+                .ok_or_else(|| IbcDenomError::InvalidChannelId {
+                    denom: denom.to_string(),
+                    channel: channel.to_string(),
+                })?;
+            let port_id = PortId::from_str(port).map_err(|_| {
+                IbcDenomError::InvalidPortId {
+                    denom: denom.to_string(),
+                    port: port.to_string(),
+                }
+            })?;
+            hops.push(TracePrefix::new(port_id, channel_id));
+            rest = tail;
+        }
+        let base_denom = BaseDenom::from_str(&rest.join("/"))
+            .map_err(|_| IbcDenomError::MissingBaseDenom(denom.to_string()))?;
+        // A `TracePath` holds its hops innermost first
+        hops.reverse();
+
+        Ok(Self(PrefixedDenom {
+            trace_path: TracePath::from(hops),
+            base_denom,
+        }))
+    }
+
+    /// Get the base denom on the origin chain of the token
+    pub fn base_denom(&self) -> &str {
+        self.0.base_denom.as_str()
+    }
+
+    /// Get the `port/channel` hops of the trace, e.g. `transfer/channel-0`.
+    /// This is empty for denoms native to this chain.
+    pub fn trace_path(&self) -> &TracePath {
+        &self.0.trace_path
+    }
+
+    /// Check whether the denom is native to this chain, i.e. has no trace
+    pub fn is_native(&self) -> bool {
+        self.0.trace_path.is_empty()
+    }
+}
+
+impl Display for DenomTrace {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl From<DenomTrace> for PrefixedDenom {
+    fn from(trace: DenomTrace) -> Self {
+        trace.0
+    }
+}
+
+/// Errors from computing the timeout of an IBC packet
+#[allow(missing_docs)]
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum TimeoutError {
+    #[error("The timeout height overflows")]
+    HeightOverflow,
+    #[error("The timeout timestamp overflows")]
+    TimestampOverflow,
+    #[error("The time {0} cannot be represented as an IBC timestamp")]
+    InvalidTime(DateTimeUtc),
+}
+
+/// Compute the timeout height and timestamp of an IBC packet, offset from
+/// the current height and time of the counterparty chain. A zero offset
+/// disables the corresponding timeout.
+pub fn compute_timeout(
+    current_height: Height,
+    current_time: DateTimeUtc,
+    height_offset: u64,
+    time_offset: Duration,
+) -> Result<(TimeoutHeight, TimeoutTimestamp), TimeoutError> {
+    let timeout_height = if height_offset == 0 {
+        TimeoutHeight::Never
+    } else {
+        let revision_height = current_height
+            .revision_height()
+            .checked_add(height_offset)
+            .ok_or(TimeoutError::HeightOverflow)?;
+        TimeoutHeight::At(
+            Height::new(current_height.revision_number(), revision_height)
+                .expect("A non-zero offset yields a non-zero revision height"),
+        )
+    };
+    let timeout_timestamp = if time_offset.is_zero() {
+        TimeoutTimestamp::Never
+    } else {
+        // IBC timestamps are in nanoseconds since the unix epoch
+        let now = current_time
+            .0
+            .timestamp_nanos_opt()
+            .and_then(|nanos| u64::try_from(nanos).ok())
+            .ok_or(TimeoutError::InvalidTime(current_time))?;
+        let nanos = u64::try_from(time_offset.as_nanos())
+            .ok()
+            .and_then(|offset| now.checked_add(offset))
+            .ok_or(TimeoutError::TimestampOverflow)?;
+        TimeoutTimestamp::At(Timestamp::from_nanoseconds(nanos))
+    };
+    Ok((timeout_height, timeout_timestamp))
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -183,4 +350,112 @@ mod test {
         let decoded: PGFIbcTarget = decode(bytes).unwrap();
         assert_eq!(target, decoded);
     }
+
+    #[test]
+    fn test_compute_timeout() {
+        let height = Height::new(1, 100).unwrap();
+        let time = DateTimeUtc::from_unix_timestamp(1_000).unwrap();
+
+        // zero offsets mean no timeout
+        assert_eq!(
+            compute_timeout(height, time, 0, Duration::ZERO),
+            Ok((TimeoutHeight::Never, TimeoutTimestamp::Never))
+        );
+
+        let (timeout_height, timeout_timestamp) =
+            compute_timeout(height, time, 10, Duration::from_secs(60)).unwrap();
+        assert_eq!(
+            timeout_height,
+            TimeoutHeight::At(Height::new(1, 110).unwrap())
+        );
+        assert_eq!(
+            timeout_timestamp,
+            TimeoutTimestamp::At(Timestamp::from_nanoseconds(
+                1_060_000_000_000
+            ))
+        );
+
+        // overflows
+        let max_height = Height::new(1, u64::MAX).unwrap();
+        assert_eq!(
+            compute_timeout(max_height, time, 1, Duration::ZERO),
+            Err(TimeoutError::HeightOverflow)
+        );
+        assert_eq!(
+            compute_timeout(height, time, 0, Duration::MAX),
+            Err(TimeoutError::TimestampOverflow)
+        );
+
+        // times before the unix epoch are not valid timestamps
+        let before_epoch = DateTimeUtc::from_unix_timestamp(-1).unwrap();
+        assert_eq!(
+            compute_timeout(height, before_epoch, 0, Duration::from_secs(1)),
+            Err(TimeoutError::InvalidTime(before_epoch))
+        );
+    }
+
+    #[test]
+    fn test_denom_trace_parse() {
+        // a native denom
+        let trace = DenomTrace::parse("unam").unwrap();
+        assert!(trace.is_native());
+        assert_eq!(trace.base_denom(), "unam");
+        assert!(trace.trace_path().is_empty());
+        assert_eq!(trace.to_string(), "unam");
+
+        // a single hop
+        let trace = DenomTrace::parse("transfer/channel-0/unam").unwrap();
+        assert!(!trace.is_native());
+        assert_eq!(trace.base_denom(), "unam");
+        assert_eq!(trace.trace_path().to_string(), "transfer/channel-0");
+        assert!(trace.trace_path().starts_with(&TracePrefix::new(
+            PortId::transfer(),
+            ChannelId::new(0)
+        )));
+        assert_eq!(trace.to_string(), "transfer/channel-0/unam");
+
+        // multiple hops, with a base denom containing `/`
+        let denom = "transfer/channel-1/transfer/channel-42/gamm/pool/1";
+        let trace = DenomTrace::parse(denom).unwrap();
+        assert_eq!(trace.base_denom(), "gamm/pool/1");
+        assert_eq!(
+            trace.trace_path().to_string(),
+            "transfer/channel-1/transfer/channel-42"
+        );
+        assert!(trace.trace_path().starts_with(&TracePrefix::new(
+            PortId::transfer(),
+            ChannelId::new(1)
+        )));
+        assert_eq!(trace.to_string(), denom);
+
+        // malformed traces
+        assert_eq!(DenomTrace::parse(""), Err(IbcDenomError::Empty));
+        assert_eq!(
+            DenomTrace::parse("transfer//unam"),
+            Err(IbcDenomError::EmptySegment("transfer//unam".to_string()))
+        );
+        assert_eq!(
+            DenomTrace::parse("transfer/channel-0"),
+            Err(IbcDenomError::MissingBaseDenom(
+                "transfer/channel-0".to_string()
+            ))
+        );
+        for channel in ["channel-x", "channel-01", "channel-"] {
+            let denom = format!("transfer/{channel}/unam");
+            assert_eq!(
+                DenomTrace::parse(&denom),
+                Err(IbcDenomError::InvalidChannelId {
+                    denom: denom.clone(),
+                    channel: channel.to_string(),
+                })
+            );
+        }
+        assert_eq!(
+            DenomTrace::parse("t/channel-0/unam"),
+            Err(IbcDenomError::InvalidPortId {
+                denom: "t/channel-0/unam".to_string(),
+                port: "t".to_string(),
+            })
+        );
+    }
 }
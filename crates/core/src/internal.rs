@@ -4,6 +4,10 @@ use borsh::{BorshDeserialize, BorshSerialize};
 use namada_macros::BorshDeserializer;
 #[cfg(feature = "migrations")]
 use namada_migrations::*;
+use thiserror::Error;
+
+use crate::chain::Epoch;
+use crate::storage::Key;
 
 /// A result of a wasm call to host functions that may fail.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -14,6 +18,31 @@ pub enum HostEnvResult {
     Fail = -1,
 }
 
+/// An inconsistency detected in storage. These should never occur, and
+/// must be surfaced loudly rather than silently ignored.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum StorageIntegrityError {
+    /// A key that is expected to be present is missing
+    #[error("Expected storage key {0} is missing")]
+    MissingKey(Key),
+    /// The value of a key could not be decoded as its expected type
+    #[error("Failed to decode the value of storage key {key}: {reason}")]
+    DecodeMismatch {
+        /// The storage key
+        key: Key,
+        /// Why decoding failed
+        reason: String,
+    },
+    /// Block height and epoch data are out of sync
+    #[error("Block heights of epoch {epoch} are out of sync: {reason}")]
+    HeightEpochDesync {
+        /// The epoch whose heights are out of sync
+        epoch: Epoch,
+        /// How the data is out of sync
+        reason: String,
+    },
+}
+
 /// Key-value pair represents data from account's subspace.
 /// It is used for prefix iterator's WASM host_env functions.
 #[derive(Clone, Debug, BorshSerialize, BorshDeserialize, BorshDeserializer)]
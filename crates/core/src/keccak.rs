@@ -16,6 +16,7 @@ use thiserror::Error;
 pub use tiny_keccak::{Hasher, Keccak};
 
 use crate::eth_abi::Encode;
+pub use crate::hash::KeccakHasher;
 use crate::hash::{Hash, HASH_LENGTH};
 
 /// Errors for converting / parsing Keccak hashes
@@ -159,15 +160,12 @@ impl<'de> Deserialize<'de> for KeccakHash {
     }
 }
 
-/// Hash bytes using Keccak
+/// Hash bytes using Keccak. Use a [`KeccakHasher`] to hash large payloads
+/// incrementally.
 pub fn keccak_hash<T: AsRef<[u8]>>(bytes: T) -> KeccakHash {
-    let mut output = [0; 32];
-
-    let mut hasher = Keccak::v256();
+    let mut hasher = KeccakHasher::new();
     hasher.update(bytes.as_ref());
-    hasher.finalize(&mut output);
-
-    KeccakHash(output)
+    hasher.finalize()
 }
 
 impl Encode<1> for KeccakHash {
@@ -194,4 +192,21 @@ mod tests {
 
         assert_eq!(deserialized, hash);
     }
+
+    #[test]
+    fn test_keccak_hasher_chunked_updates() {
+        let data: Vec<u8> = (0..=u8::MAX).cycle().take(10_000).collect();
+        let expected = keccak_hash(&data);
+
+        for chunk_size in [1, 7, 32, 136, 1_000, data.len()] {
+            let mut hasher = KeccakHasher::new();
+            for chunk in data.chunks(chunk_size) {
+                hasher.update(chunk);
+            }
+            assert_eq!(hasher.finalize(), expected);
+        }
+
+        // hashing no data at all matches the one-shot hash of an empty slice
+        assert_eq!(KeccakHasher::new().finalize(), keccak_hash(b""));
+    }
 }
@@ -1,13 +1,13 @@
 //! MASP types
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, VecDeque};
 use std::fmt::Display;
 use std::num::ParseIntError;
 use std::str::FromStr;
 
 use borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
 use masp_primitives::asset_type::AssetType;
-use masp_primitives::sapling::ViewingKey;
+use masp_primitives::sapling::{Diversifier, ViewingKey};
 use masp_primitives::transaction::TransparentAddress;
 pub use masp_primitives::transaction::{
     Transaction as MaspTransaction, TxId as TxIdInner,
@@ -19,11 +19,14 @@ use namada_migrations::*;
 use ripemd::Digest as RipemdDigest;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use sha2::{Digest, Sha256};
+use thiserror::Error;
 
 use crate::address::{Address, DecodeError, HASH_HEX_LEN, IBC, MASP};
 use crate::borsh::BorshSerializeExt;
 use crate::chain::Epoch;
+use crate::collections::HashMap;
 use crate::impl_display_and_from_str_via_format;
+use crate::storage::{self, DbKeySeg, KeySeg};
 use crate::string_encoding::{
     self, MASP_EXT_FULL_VIEWING_KEY_HRP, MASP_EXT_SPENDING_KEY_HRP,
     MASP_PAYMENT_ADDRESS_HRP,
@@ -112,6 +115,23 @@ impl Display for MaspEpoch {
     }
 }
 
+impl KeySeg for MaspEpoch {
+    fn parse(string: String) -> storage::Result<Self>
+    where
+        Self: Sized,
+    {
+        Epoch::parse(string).map(Self)
+    }
+
+    fn raw(&self) -> String {
+        self.0.raw()
+    }
+
+    fn to_db_key(&self) -> DbKeySeg {
+        self.0.to_db_key()
+    }
+}
+
 impl FromStr for MaspEpoch {
     type Err = ParseIntError;
 
@@ -150,6 +170,12 @@ impl MaspEpoch {
         Some(Self(self.0.checked_add(1)?))
     }
 
+    /// Returns the number of masp epochs elapsed since the given one, or
+    /// `None` if it is later than this one.
+    pub fn epochs_since(&self, earlier: Self) -> Option<u64> {
+        Some(self.0.checked_sub(earlier.0)?.0)
+    }
+
     /// Initialize a new masp epoch from the provided one
     #[cfg(any(test, feature = "testing"))]
     pub const fn new(epoch: u64) -> Self {
@@ -446,6 +472,77 @@ impl<'de> serde::Deserialize<'de> for PaymentAddress {
     }
 }
 
+/// A bounded cache of payment addresses derived from viewing keys, such that
+/// note scanning loops may reuse derivations across blocks. Once full, the
+/// oldest derivations are evicted first.
+#[derive(Debug, Clone)]
+pub struct ViewingKeyCache {
+    capacity: usize,
+    addresses: HashMap<(ExtendedViewingKey, [u8; 11]), Option<PaymentAddress>>,
+    /// The keys of `addresses`, oldest first
+    insertion_order: VecDeque<(ExtendedViewingKey, [u8; 11])>,
+}
+
+impl ViewingKeyCache {
+    /// Create a new cache holding at most `capacity` derivations
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            addresses: HashMap::new(),
+            insertion_order: VecDeque::new(),
+        }
+    }
+
+    /// Get the payment address of the given viewing key with the given
+    /// diversifier, deriving it if it is not cached. Returns `None` if the
+    /// diversifier is not valid.
+    pub fn payment_address(
+        &mut self,
+        viewing_key: &ExtendedViewingKey,
+        diversifier: Diversifier,
+    ) -> Option<PaymentAddress> {
+        let key = (*viewing_key, diversifier.0);
+        if let Some(address) = self.addresses.get(&key) {
+            return *address;
+        }
+        let address = viewing_key
+            .as_viewing_key()
+            .to_payment_address(diversifier)
+            .map(PaymentAddress::from);
+        if self.capacity > 0 {
+            if self.addresses.len() >= self.capacity {
+                if let Some(oldest) = self.insertion_order.pop_front() {
+                    self.addresses.swap_remove(&oldest);
+                }
+            }
+            self.addresses.insert(key, address);
+            self.insertion_order.push_back(key);
+        }
+        address
+    }
+
+    /// Get the maximum number of derivations held by this cache
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Get the number of derivations currently held by this cache
+    pub fn len(&self) -> usize {
+        self.addresses.len()
+    }
+
+    /// Check whether this cache holds no derivations
+    pub fn is_empty(&self) -> bool {
+        self.addresses.is_empty()
+    }
+
+    /// Evict all derivations from this cache
+    pub fn clear(&mut self) {
+        self.addresses.clear();
+        self.insertion_order.clear();
+    }
+}
+
 /// Wrapper for masp_primitive's ExtendedSpendingKey
 #[derive(
     Clone,
@@ -729,6 +826,181 @@ impl Display for TransferTarget {
     }
 }
 
+/// The kind of a transfer, determined by which of its endpoints are shielded
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
+pub enum TransferKind {
+    /// A transfer between transparent endpoints
+    Transparent,
+    /// A transfer from a transparent source to a shielded target
+    Shielding,
+    /// A transfer between shielded endpoints
+    Shielded,
+    /// A transfer from a shielded source to a transparent target
+    Unshielding,
+}
+
+impl TransferKind {
+    /// Check whether transfers of this kind draw from a shielded source
+    pub fn has_shielded_source(&self) -> bool {
+        matches!(self, Self::Shielded | Self::Unshielding)
+    }
+
+    /// Check whether transfers of this kind go to a shielded target
+    pub fn has_shielded_target(&self) -> bool {
+        matches!(self, Self::Shielding | Self::Shielded)
+    }
+}
+
+impl Display for TransferKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Transparent => write!(f, "transparent"),
+            Self::Shielding => write!(f, "shielding"),
+            Self::Shielded => write!(f, "shielded"),
+            Self::Unshielding => write!(f, "unshielding"),
+        }
+    }
+}
+
+/// Errors from validating the endpoints of a transfer
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum TransferEndpointError {
+    /// The source of the transfer should have been shielded
+    #[error("A {0} transfer requires a shielded source")]
+    ExpectedShieldedSource(TransferKind),
+    /// The source of the transfer should have been transparent
+    #[error("A {0} transfer requires a transparent source")]
+    ExpectedTransparentSource(TransferKind),
+    /// The target of the transfer should have been shielded
+    #[error("A {0} transfer requires a shielded target")]
+    ExpectedShieldedTarget(TransferKind),
+    /// The target of the transfer should have been transparent
+    #[error("A {0} transfer requires a transparent target")]
+    ExpectedTransparentTarget(TransferKind),
+    /// The MASP internal address was used as a transparent endpoint
+    #[error(
+        "The MASP internal address cannot be used as a transparent transfer \
+         endpoint"
+    )]
+    MaspAddress,
+}
+
+/// The source and target of a transfer, validated against the kind of
+/// transfer they are meant for
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub struct TransferEndpoints {
+    /// The source of the funds
+    pub source: TransferSource,
+    /// The target of the funds
+    pub target: TransferTarget,
+}
+
+impl TransferEndpoints {
+    /// Validate the given endpoints for a transfer of the given kind
+    pub fn new(
+        kind: TransferKind,
+        source: TransferSource,
+        target: TransferTarget,
+    ) -> Result<Self, TransferEndpointError> {
+        match (&source, kind.has_shielded_source()) {
+            (TransferSource::Address(_), true) => {
+                return Err(TransferEndpointError::ExpectedShieldedSource(
+                    kind,
+                ));
+            }
+            (TransferSource::ExtendedKey(_), false) => {
+                return Err(TransferEndpointError::ExpectedTransparentSource(
+                    kind,
+                ));
+            }
+            (TransferSource::Address(addr), false) if *addr == MASP => {
+                return Err(TransferEndpointError::MaspAddress);
+            }
+            _ => {}
+        }
+        match (&target, kind.has_shielded_target()) {
+            (TransferTarget::Address(_) | TransferTarget::Ibc(_), true) => {
+                return Err(TransferEndpointError::ExpectedShieldedTarget(
+                    kind,
+                ));
+            }
+            (TransferTarget::PaymentAddress(_), false) => {
+                return Err(TransferEndpointError::ExpectedTransparentTarget(
+                    kind,
+                ));
+            }
+            (TransferTarget::Address(addr), false) if *addr == MASP => {
+                return Err(TransferEndpointError::MaspAddress);
+            }
+            _ => {}
+        }
+        Ok(Self { source, target })
+    }
+
+    /// Validate the endpoints of a transparent transfer
+    pub fn transparent(
+        source: Address,
+        target: TransferTarget,
+    ) -> Result<Self, TransferEndpointError> {
+        Self::new(
+            TransferKind::Transparent,
+            TransferSource::Address(source),
+            target,
+        )
+    }
+
+    /// Validate the endpoints of a shielding transfer
+    pub fn shielding(
+        source: Address,
+        target: TransferTarget,
+    ) -> Result<Self, TransferEndpointError> {
+        Self::new(
+            TransferKind::Shielding,
+            TransferSource::Address(source),
+            target,
+        )
+    }
+
+    /// Validate the endpoints of a shielded transfer
+    pub fn shielded(
+        source: PseudoExtendedKey,
+        target: TransferTarget,
+    ) -> Result<Self, TransferEndpointError> {
+        Self::new(
+            TransferKind::Shielded,
+            TransferSource::ExtendedKey(source),
+            target,
+        )
+    }
+
+    /// Validate the endpoints of an unshielding transfer
+    pub fn unshielding(
+        source: PseudoExtendedKey,
+        target: TransferTarget,
+    ) -> Result<Self, TransferEndpointError> {
+        Self::new(
+            TransferKind::Unshielding,
+            TransferSource::ExtendedKey(source),
+            target,
+        )
+    }
+
+    /// Get the kind of transfer between these endpoints
+    pub fn kind(&self) -> TransferKind {
+        match (&self.source, &self.target) {
+            (TransferSource::Address(_), TransferTarget::PaymentAddress(_)) => {
+                TransferKind::Shielding
+            }
+            (TransferSource::Address(_), _) => TransferKind::Transparent,
+            (
+                TransferSource::ExtendedKey(_),
+                TransferTarget::PaymentAddress(_),
+            ) => TransferKind::Shielded,
+            (TransferSource::ExtendedKey(_), _) => TransferKind::Unshielding,
+        }
+    }
+}
+
 /// Represents the owner of arbitrary funds
 #[allow(clippy::large_enum_variant)]
 #[derive(
@@ -1162,4 +1434,155 @@ mod test {
         let pa = PaymentAddress::from(pa);
         string_encoding::testing::test_string_formatting(&pa);
     }
+
+    #[test]
+    fn test_transfer_endpoints() {
+        let sk = ExtendedSpendingKey::from(
+            masp_primitives::zip32::ExtendedSpendingKey::master(&[0_u8]),
+        );
+        let (_diversifier, pa) = sk.0.default_address();
+        let pa = TransferTarget::PaymentAddress(PaymentAddress::from(pa));
+        let key = PseudoExtendedKey::from(sk.0);
+        let addr = address::testing::established_address_1();
+        let transparent = TransferTarget::Address(addr.clone());
+
+        // shielded -> shielded
+        let endpoints =
+            TransferEndpoints::shielded(key, pa.clone()).expect("Test failed");
+        assert_eq!(endpoints.kind(), TransferKind::Shielded);
+
+        // transparent -> shielded
+        let endpoints = TransferEndpoints::shielding(addr.clone(), pa.clone())
+            .expect("Test failed");
+        assert_eq!(endpoints.kind(), TransferKind::Shielding);
+
+        // shielded -> transparent
+        let endpoints =
+            TransferEndpoints::unshielding(key, transparent.clone())
+                .expect("Test failed");
+        assert_eq!(endpoints.kind(), TransferKind::Unshielding);
+
+        // transparent -> transparent
+        let endpoints =
+            TransferEndpoints::transparent(addr.clone(), transparent.clone())
+                .expect("Test failed");
+        assert_eq!(endpoints.kind(), TransferKind::Transparent);
+
+        // shielding into a transparent target
+        assert_eq!(
+            TransferEndpoints::shielding(addr.clone(), transparent.clone()),
+            Err(TransferEndpointError::ExpectedShieldedTarget(
+                TransferKind::Shielding
+            ))
+        );
+        assert_eq!(
+            TransferEndpoints::shielded(
+                key,
+                TransferTarget::Ibc(String::new())
+            ),
+            Err(TransferEndpointError::ExpectedShieldedTarget(
+                TransferKind::Shielded
+            ))
+        );
+
+        // unshielding or transparent transfers into a payment address
+        assert_eq!(
+            TransferEndpoints::unshielding(key, pa.clone()),
+            Err(TransferEndpointError::ExpectedTransparentTarget(
+                TransferKind::Unshielding
+            ))
+        );
+        assert_eq!(
+            TransferEndpoints::transparent(addr.clone(), pa.clone()),
+            Err(TransferEndpointError::ExpectedTransparentTarget(
+                TransferKind::Transparent
+            ))
+        );
+
+        // mismatched sources
+        assert_eq!(
+            TransferEndpoints::new(
+                TransferKind::Shielded,
+                TransferSource::Address(addr.clone()),
+                pa.clone()
+            ),
+            Err(TransferEndpointError::ExpectedShieldedSource(
+                TransferKind::Shielded
+            ))
+        );
+        assert_eq!(
+            TransferEndpoints::new(
+                TransferKind::Shielding,
+                TransferSource::ExtendedKey(key),
+                pa.clone()
+            ),
+            Err(TransferEndpointError::ExpectedTransparentSource(
+                TransferKind::Shielding
+            ))
+        );
+
+        // the MASP address is not a valid transparent endpoint
+        assert_eq!(
+            TransferEndpoints::shielding(MASP, pa),
+            Err(TransferEndpointError::MaspAddress)
+        );
+        assert_eq!(
+            TransferEndpoints::unshielding(key, TransferTarget::Address(MASP)),
+            Err(TransferEndpointError::MaspAddress)
+        );
+    }
+
+    #[test]
+    fn test_viewing_key_cache() {
+        let sks: Vec<ExtendedSpendingKey> = (0..3u8)
+            .map(|seed| {
+                masp_primitives::zip32::ExtendedSpendingKey::master(&[seed])
+                    .into()
+            })
+            .collect();
+        let vks: Vec<ExtendedViewingKey> = sks
+            .iter()
+            .map(ExtendedSpendingKey::to_viewing_key)
+            .collect();
+        // diversifiers are valid independently of the key
+        let diversifiers: Vec<Diversifier> = sks
+            .iter()
+            .take(2)
+            .map(|sk| sk.0.default_address().0)
+            .collect();
+
+        // the second pass is served entirely from the cache
+        let mut cache = ViewingKeyCache::new(6);
+        for _ in 0..2 {
+            for vk in &vks {
+                for diversifier in &diversifiers {
+                    let uncached = vk
+                        .as_viewing_key()
+                        .to_payment_address(*diversifier)
+                        .map(PaymentAddress::from);
+                    assert!(uncached.is_some());
+                    assert_eq!(
+                        cache.payment_address(vk, *diversifier),
+                        uncached
+                    );
+                }
+            }
+            assert_eq!(cache.len(), 6);
+        }
+
+        // the cache does not grow past its capacity
+        let mut cache = ViewingKeyCache::new(4);
+        for vk in &vks {
+            for diversifier in &diversifiers {
+                assert!(cache.payment_address(vk, *diversifier).is_some());
+                assert!(cache.len() <= cache.capacity());
+            }
+        }
+        assert_eq!(cache.len(), 4);
+
+        // a zero capacity cache never stores anything
+        let mut cache = ViewingKeyCache::new(0);
+        assert!(cache.payment_address(&vks[0], diversifiers[0]).is_some());
+        assert!(cache.is_empty());
+    }
 }
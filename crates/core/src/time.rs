@@ -13,6 +13,7 @@ use namada_macros::BorshDeserializer;
 #[cfg(feature = "migrations")]
 use namada_migrations::*;
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
 /// Check if the given `duration` has passed since the given `start.
 #[allow(clippy::arithmetic_side_effects)]
@@ -24,6 +25,57 @@ pub fn duration_passed(
     start + duration <= current
 }
 
+/// Errors that may occur when parsing a [`DateTimeUtc`].
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum TimeError {
+    /// The timestamp has no timezone offset
+    #[error("Missing timezone in timestamp {0:?}, expected a `+00:00` suffix")]
+    MissingTimezone(String),
+    /// The timestamp has a timezone other than `+00:00`
+    #[error(
+        "Unsupported timezone {timezone:?} in timestamp {input:?}, only \
+         `+00:00` is supported"
+    )]
+    UnsupportedTimezone {
+        /// The input timestamp
+        input: String,
+        /// The timezone found in the input
+        timezone: String,
+    },
+    /// The timestamp could not be parsed
+    #[error("Malformed timestamp {input:?}: {error}")]
+    Malformed {
+        /// The input timestamp
+        input: String,
+        /// The underlying parse error
+        error: ParseError,
+    },
+    /// A block time is not strictly greater than its predecessor's
+    #[error(
+        "Block time {current} is not strictly greater than the previous block \
+         time {prev}"
+    )]
+    NonMonotonic {
+        /// The previous block time
+        prev: DateTimeUtc,
+        /// The offending block time
+        current: DateTimeUtc,
+    },
+    /// A block time is too far ahead of its predecessor's
+    #[error(
+        "Block time {current} is more than {max_future_drift} ahead of the \
+         previous block time {prev}"
+    )]
+    TooFarInFuture {
+        /// The previous block time
+        prev: DateTimeUtc,
+        /// The offending block time
+        current: DateTimeUtc,
+        /// The maximum allowed drift
+        max_future_drift: Duration,
+    },
+}
+
 /// A duration in seconds precision.
 #[derive(
     Clone,
@@ -190,15 +242,87 @@ impl DateTimeUtc {
     }
 
     /// Parses a rfc3339 string, or returns an error.
-    pub fn from_rfc3339(s: &str) -> Result<Self, ParseError> {
+    ///
+    /// Timestamps must have a `+00:00` timezone.
+    pub fn from_rfc3339(s: &str) -> Result<Self, TimeError> {
         use chrono::format;
         use chrono::format::strftime::StrftimeItems;
 
+        let malformed = |error| {
+            if s.ends_with("+00:00") {
+                TimeError::Malformed {
+                    input: s.to_string(),
+                    error,
+                }
+            } else {
+                Self::timezone_error(s)
+            }
+        };
         let format = StrftimeItems::new(Self::FORMAT);
         let mut parsed = format::Parsed::new();
-        format::parse(&mut parsed, s, format)?;
+        format::parse(&mut parsed, s, format).map_err(malformed)?;
+
+        parsed
+            .to_datetime_with_timezone(&chrono::Utc)
+            .map(Self)
+            .map_err(malformed)
+    }
 
-        parsed.to_datetime_with_timezone(&chrono::Utc).map(Self)
+    /// Classify the timezone of a timestamp without a `+00:00` suffix.
+    fn timezone_error(s: &str) -> TimeError {
+        if s.ends_with(['Z', 'z']) {
+            return TimeError::UnsupportedTimezone {
+                input: s.to_string(),
+                timezone: "Z".to_string(),
+            };
+        }
+        let offset = s
+            .len()
+            .checked_sub(6)
+            .and_then(|start| s.get(start..))
+            .filter(|offset| {
+                let bytes = offset.as_bytes();
+                matches!(bytes[0], b'+' | b'-')
+                    && bytes[3] == b':'
+                    && [1, 2, 4, 5].iter().all(|&i| bytes[i].is_ascii_digit())
+            });
+        match offset {
+            Some(timezone) => TimeError::UnsupportedTimezone {
+                input: s.to_string(),
+                timezone: timezone.to_string(),
+            },
+            None => TimeError::MissingTimezone(s.to_string()),
+        }
+    }
+
+    /// Check that this block time is strictly greater than the `prev` block
+    /// time, and not more than `max_future_drift` ahead of it.
+    ///
+    /// The drift is measured against `prev` rather than the local clock,
+    /// such that the check is deterministic.
+    pub fn check_monotonic(
+        &self,
+        prev: DateTimeUtc,
+        max_future_drift: Duration,
+    ) -> Result<(), TimeError> {
+        if *self <= prev {
+            return Err(TimeError::NonMonotonic {
+                prev,
+                current: *self,
+            });
+        }
+        let too_far = prev
+            .0
+            .checked_add_signed(max_future_drift)
+            .map_or(false, |max_time| self.0 > max_time);
+        if too_far {
+            return Err(TimeError::TooFarInFuture {
+                prev,
+                current: *self,
+                max_future_drift,
+            });
+        }
+        Ok(())
     }
 
     /// Returns the DateTimeUtc corresponding to one second in the future
@@ -219,7 +343,7 @@ impl DateTimeUtc {
 }
 
 impl FromStr for DateTimeUtc {
-    type Err = ParseError;
+    type Err = TimeError;
 
     #[inline]
     fn from_str(s: &str) -> Result<Self, Self::Err> {
@@ -358,7 +482,7 @@ impl From<DateTimeUtc> for std::time::SystemTime {
 }
 
 impl TryFrom<Rfc3339String> for DateTimeUtc {
-    type Error = chrono::ParseError;
+    type Error = TimeError;
 
     fn try_from(str: Rfc3339String) -> Result<Self, Self::Error> {
         Self::from_rfc3339(&str.0)
@@ -409,6 +533,7 @@ pub mod test_utils {
 
 #[cfg(test)]
 mod core_time_tests {
+    use assert_matches::assert_matches;
     use proptest::prelude::*;
 
     use super::*;
@@ -464,6 +589,92 @@ mod core_time_tests {
         assert!(DateTimeUtc::from_rfc3339(TIMESTAMP).is_err());
     }
 
+    #[test]
+    fn test_datetime_utc_rfc3339_errors() {
+        // leap seconds are accepted
+        const LEAP: &str = "2016-12-31T23:59:60.000000000+00:00";
+        let datetime = DateTimeUtc::from_rfc3339(LEAP).unwrap();
+        assert_eq!(datetime.to_rfc3339(), LEAP);
+
+        // a timezone must be present
+        const NO_TZ: &str = "2023-08-30T00:00:00.000000000";
+        assert_eq!(
+            DateTimeUtc::from_rfc3339(NO_TZ),
+            Err(TimeError::MissingTimezone(NO_TZ.to_string()))
+        );
+
+        // and it must be `+00:00`
+        const ZULU: &str = "2023-08-30T00:00:00.000000000Z";
+        assert_eq!(
+            DateTimeUtc::from_rfc3339(ZULU),
+            Err(TimeError::UnsupportedTimezone {
+                input: ZULU.to_string(),
+                timezone: "Z".to_string(),
+            })
+        );
+        const OFFSET: &str = "2023-08-30T00:00:00.000000000+01:00";
+        assert_eq!(
+            DateTimeUtc::from_rfc3339(OFFSET),
+            Err(TimeError::UnsupportedTimezone {
+                input: OFFSET.to_string(),
+                timezone: "+01:00".to_string(),
+            })
+        );
+
+        // fractional seconds of any precision are accepted
+        for (timestamp, nanos) in [
+            ("2023-08-30T00:00:00+00:00", 0),
+            ("2023-08-30T00:00:00.5+00:00", 500_000_000),
+            ("2023-08-30T00:00:00.520+00:00", 520_000_000),
+            ("2023-08-30T00:00:00.500000000+00:00", 500_000_000),
+        ] {
+            let datetime = DateTimeUtc::from_rfc3339(timestamp).unwrap();
+            assert_eq!(datetime.0.timestamp_subsec_nanos(), nanos);
+        }
+
+        // anything else is malformed
+        for timestamp in [
+            "2023-08-30T00:00:00.00000000a+00:00",
+            "2023-13-30T00:00:00.000000000+00:00",
+        ] {
+            assert_matches!(
+                DateTimeUtc::from_rfc3339(timestamp),
+                Err(TimeError::Malformed { .. })
+            );
+        }
+    }
+
+    #[test]
+    fn test_check_monotonic() {
+        let prev = DateTimeUtc::from_unix_timestamp(1_000).unwrap();
+        let max_drift = Duration::seconds(10);
+
+        // equal or earlier times are not monotonic
+        for current in [prev, DateTimeUtc::from_unix_timestamp(999).unwrap()] {
+            assert_eq!(
+                current.check_monotonic(prev, max_drift),
+                Err(TimeError::NonMonotonic { prev, current })
+            );
+        }
+
+        // anything within the drift is fine, including the exact bound
+        for secs in [1_001, 1_005, 1_010] {
+            let current = DateTimeUtc::from_unix_timestamp(secs).unwrap();
+            assert_eq!(current.check_monotonic(prev, max_drift), Ok(()));
+        }
+
+        // times past the drift are rejected
+        let current = DateTimeUtc::from_unix_timestamp(1_011).unwrap();
+        assert_eq!(
+            current.check_monotonic(prev, max_drift),
+            Err(TimeError::TooFarInFuture {
+                prev,
+                current,
+                max_future_drift: max_drift,
+            })
+        );
+    }
+
     #[test]
     fn test_valid_test_utils_genesis_time() {
         assert!(DateTimeUtc::from_rfc3339(test_utils::GENESIS_TIME).is_ok());
@@ -134,6 +134,23 @@ impl Amount {
         }
     }
 
+    /// Get the raw [`Uint`] value of this [`Amount`], e.g. to be used in
+    /// an Ethereum bridge transfer.
+    pub fn to_uint(&self) -> Uint {
+        self.raw
+    }
+
+    /// Attempt to convert a raw [`Uint`] (e.g. received from the Ethereum
+    /// bridge) to an [`Amount`]. Fails with [`AmountError::Overflow`] if the
+    /// value exceeds [`Amount::max_signed`], as such an amount could not be
+    /// converted to a [`Change`].
+    pub fn try_from_uint(raw: Uint) -> Result<Self, AmountError> {
+        if raw > uint::MAX_SIGNED_VALUE {
+            return Err(AmountError::Overflow);
+        }
+        Ok(Self { raw })
+    }
+
     /// Zero [`Amount`].
     pub fn zero() -> Self {
         Self::default()
@@ -275,6 +292,25 @@ impl Amount {
         .to_string_precise()
     }
 
+    /// Get a string representation of the amount with the given number of
+    /// decimal places, keeping any trailing zeros.
+    pub fn to_string_precise(&self, denom: impl Into<u8>) -> String {
+        DenominatedAmount::new(*self, denom.into().into()).to_string_precise()
+    }
+
+    /// Get a formatter of the amount with the given denomination, which
+    /// optionally trims the trailing zeros of its decimal places.
+    pub fn display_denominated(
+        &self,
+        denom: impl Into<Denomination>,
+        trim_trailing_zeros: bool,
+    ) -> DisplayDenominated {
+        DisplayDenominated {
+            amount: DenominatedAmount::new(*self, denom.into()),
+            trim_trailing_zeros,
+        }
+    }
+
     /// Return a denominated native token amount.
     #[inline]
     pub const fn native_denominated(self) -> DenominatedAmount {
@@ -319,6 +355,21 @@ impl Amount {
         Ok(Self { raw })
     }
 
+    /// Multiply by a decimal rate [`Dec`], such as a reward rate. The exact
+    /// product is computed with a 512-bit intermediate value and then
+    /// truncated toward zero to the nearest raw unit, so the result never
+    /// exceeds the exact product and no intermediate overflow can occur.
+    /// Returns `None` if the rate is negative or if the result does not fit
+    /// in an [`Amount`].
+    pub fn checked_mul_dec(&self, rate: Dec) -> Option<Self> {
+        if rate.is_negative() {
+            return None;
+        }
+        let denom = Uint::from(10u64.pow(u32::from(POS_DECIMAL_PRECISION)));
+        let (raw, _rem) = self.raw.checked_mul_div(rate.0.abs(), denom)?;
+        Some(Self { raw })
+    }
+
     /// Sum with overflow check
     pub fn sum<I: Iterator<Item = Self>>(mut iter: I) -> Option<Self> {
         iter.try_fold(Amount::zero(), |acc, amt| acc.checked_add(amt))
@@ -614,6 +665,24 @@ impl Display for DenominatedAmount {
     }
 }
 
+/// A formatter of an [`Amount`] with a given [`Denomination`], obtained
+/// from [`Amount::display_denominated`].
+#[derive(Debug, Clone, Copy)]
+pub struct DisplayDenominated {
+    amount: DenominatedAmount,
+    trim_trailing_zeros: bool,
+}
+
+impl Display for DisplayDenominated {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.trim_trailing_zeros {
+            Display::fmt(&self.amount, f)
+        } else {
+            f.write_str(&self.amount.to_string_precise())
+        }
+    }
+}
+
 impl FromStr for DenominatedAmount {
     type Err = AmountParseError;
 
@@ -1101,6 +1170,30 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_amount_uint_conversion_bounds() {
+        let max = uint::MAX_SIGNED_VALUE;
+        let amount = Amount::try_from_uint(max).expect("Test failed");
+        assert_eq!(amount, Amount::max_signed());
+        assert_eq!(amount.to_uint(), max);
+
+        let above_max = max.checked_add(Uint::one()).expect("Test failed");
+        assert_matches!(
+            Amount::try_from_uint(above_max),
+            Err(AmountError::Overflow)
+        );
+        assert_matches!(
+            Amount::try_from_uint(uint::MAX_VALUE),
+            Err(AmountError::Overflow)
+        );
+
+        let amount = Amount::native_whole(123);
+        assert_eq!(
+            Amount::try_from_uint(amount.to_uint()).expect("Test failed"),
+            amount
+        );
+    }
+
     #[test]
     fn test_token_display() {
         let max = Amount::from_uint(u64::MAX, 0).expect("Test failed");
@@ -1311,6 +1404,97 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_token_amount_checked_mul_dec() {
+        let zero = Amount::zero();
+        let one = Amount::from(1);
+        let three = Amount::from(3);
+        let dec = Dec::from_str("0.34").unwrap();
+        // the result is truncated toward zero
+        assert_eq!(one.checked_mul_dec(dec), Some(zero));
+        assert_eq!(three.checked_mul_dec(dec), Some(one));
+        assert_eq!(
+            three.checked_mul_dec(Dec::from_str("1.5").unwrap()),
+            Some(Amount::from(4))
+        );
+
+        // a zero rate always yields zero
+        assert_eq!(Amount::max().checked_mul_dec(Dec::zero()), Some(zero));
+        assert_eq!(zero.checked_mul_dec(Dec::zero()), Some(zero));
+
+        // the max amount doesn't overflow in intermediate computations
+        assert_eq!(
+            Amount::max().checked_mul_dec(Dec::one()),
+            Some(Amount::max())
+        );
+        assert_eq!(
+            Amount::max().checked_mul_dec(Dec::new(5, 1).unwrap()),
+            Some(Amount::from_uint(uint::MAX_VALUE >> 1, 0).unwrap())
+        );
+
+        // a rate above one overflows the max amount
+        assert_eq!(
+            Amount::max()
+                .checked_mul_dec(Dec::from_str("1.000000000001").unwrap()),
+            None
+        );
+        assert_eq!(Amount::max().checked_mul_dec(Dec::two()), None);
+
+        // negative rates are rejected
+        assert_eq!(one.checked_mul_dec(-dec), None);
+    }
+
+    #[test]
+    fn test_token_amount_display_with_denom() {
+        let amount = Amount::from(1_234_500_000);
+        assert_eq!(amount.to_string_precise(0), "1234500000");
+        assert_eq!(amount.to_string_precise(6), "1234.500000");
+        assert_eq!(amount.to_string_precise(18), "0.000000001234500000");
+        assert_eq!(amount.display_denominated(6, true).to_string(), "1234.5");
+        assert_eq!(
+            amount.display_denominated(18, true).to_string(),
+            "0.0000000012345"
+        );
+        assert_eq!(
+            amount.display_denominated(6, false).to_string(),
+            "1234.500000"
+        );
+        assert_eq!(
+            Amount::from(1_000_000)
+                .display_denominated(6, true)
+                .to_string(),
+            "1"
+        );
+        assert_eq!(Amount::zero().to_string_precise(6), "0.000000");
+        assert_eq!(
+            Amount::zero().display_denominated(6, true).to_string(),
+            "0"
+        );
+
+        // the rendered amounts can be parsed back
+        for amount in [
+            Amount::zero(),
+            Amount::from(1),
+            Amount::from(1_234_500_000),
+            Amount::from(1_000_000),
+            Amount::from_uint(u64::MAX, 0).unwrap(),
+        ] {
+            for denom in [0u8, 6, 18] {
+                let precise = amount.to_string_precise(denom);
+                assert_eq!(Amount::from_str(&precise, denom).unwrap(), amount);
+                for trim_trailing_zeros in [true, false] {
+                    let rendered = amount
+                        .display_denominated(denom, trim_trailing_zeros)
+                        .to_string();
+                    assert_eq!(
+                        Amount::from_str(&rendered, denom).unwrap(),
+                        amount
+                    );
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_denominateed_arithmetic() {
         let a = DenominatedAmount::new(10.into(), 3.into());
@@ -450,6 +450,23 @@ impl Integer for Uint {
 pub const MAX_VALUE: Uint = Uint([u64::MAX; 4]);
 
 impl Uint {
+    /// Encode this [`Uint`] as a big-endian 32 byte word, matching the
+    /// Ethereum ABI encoding of `uint256` values.
+    pub fn to_be_bytes_32(&self) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        self.to_big_endian(&mut bytes);
+        bytes
+    }
+
+    /// Decode a [`Uint`] from a big-endian 32 byte word, as found in
+    /// the Ethereum ABI encoding of `uint256` values.
+    ///
+    /// Every 32 byte word is a valid 256 bit integer, therefore this
+    /// conversion cannot fail.
+    pub fn from_be_bytes_32(bytes: &[u8; 32]) -> Self {
+        Self::from_big_endian(bytes)
+    }
+
     /// Divide two [`Uint`]s with scaled to allow the `denom` number
     /// of decimal places.
     ///
@@ -1230,6 +1247,38 @@ mod test_uint {
 
     use super::*;
 
+    /// Test that big-endian 32 byte words match the Ethereum ABI encoding
+    /// of `uint256` values, and round trip.
+    #[test]
+    fn test_uint_be_bytes_32() {
+        use ethabi::ethereum_types::U256;
+
+        let mut one = [0u8; 32];
+        one[31] = 1;
+        assert_eq!(Uint::one().to_be_bytes_32(), one);
+        assert_eq!(MAX_VALUE.to_be_bytes_32(), [0xff; 32]);
+        assert_eq!(Uint::zero().to_be_bytes_32(), [0; 32]);
+
+        let mut partial = [0u8; 32];
+        partial[29..].copy_from_slice(&[0x12, 0x34, 0x56]);
+        assert_eq!(Uint::from(0x123456).to_be_bytes_32(), partial);
+
+        for value in [
+            Uint::zero(),
+            Uint::one(),
+            Uint::from(0x123456),
+            Uint::from(u64::MAX),
+            Uint([0, 1, 0, 0]),
+            MAX_VALUE,
+        ] {
+            let bytes = value.to_be_bytes_32();
+            let abi_encoded =
+                ethabi::encode(&[ethabi::Token::Uint(U256(value.0))]);
+            assert_eq!(bytes.as_slice(), abi_encoded.as_slice());
+            assert_eq!(Uint::from_be_bytes_32(&bytes), value);
+        }
+    }
+
     /// Test that dividing two [`Uint`]s with the specified precision
     /// works correctly and performs correct checks.
     #[test]
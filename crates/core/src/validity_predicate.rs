@@ -3,11 +3,16 @@
 use thiserror::Error;
 
 use crate::borsh::{BorshDeserialize, BorshSerialize};
+use crate::storage::Key;
 
 /// Helper trait for converting between result types.
 pub trait VpErrorExtResult<T> {
     /// Convert to a [`Result`] with [`VpError`] errors.
     fn into_vp_error(self) -> Result<T, VpError>;
+
+    /// Convert to a [`Result`] with [`VpError::StorageReadFailed`] errors,
+    /// for failures to read the given storage `key`.
+    fn into_storage_read_error(self, key: &Key) -> Result<T, VpError>;
 }
 
 impl<T, E> VpErrorExtResult<T> for Result<T, E>
@@ -18,6 +23,14 @@ where
     fn into_vp_error(self) -> Result<T, VpError> {
         self.map_err(|err| VpError::Erased(err.to_string()))
     }
+
+    #[inline]
+    fn into_storage_read_error(self, key: &Key) -> Result<T, VpError> {
+        self.map_err(|err| VpError::StorageReadFailed {
+            key: key.clone(),
+            error: err.to_string(),
+        })
+    }
 }
 
 /// Error result returned by validity predicates.
@@ -28,4 +41,40 @@ pub enum VpError {
     Unspecified,
     #[error("{0}")]
     Erased(String), // type erased error
+    #[error("Failed to read storage key {key}: {error}")]
+    StorageReadFailed { key: Key, error: String },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vp_error_display() {
+        let key = Key::parse("some/storage/key").unwrap();
+        let errors = [
+            (VpError::Unspecified, "Transaction rejected"),
+            (
+                VpError::Erased("value violated the rule".to_string()),
+                "value violated the rule",
+            ),
+            (
+                VpError::StorageReadFailed {
+                    key: key.clone(),
+                    error: "decoding failed".to_string(),
+                },
+                "Failed to read storage key some/storage/key: decoding failed",
+            ),
+        ];
+        for (error, expected) in errors {
+            assert_eq!(error.to_string(), expected);
+        }
+
+        let read: Result<(), _> = Err("decoding failed");
+        assert!(matches!(
+            read.into_storage_read_error(&key),
+            Err(VpError::StorageReadFailed { key: k, error })
+                if k == key && error == "decoding failed"
+        ));
+    }
 }
@@ -149,6 +149,14 @@ impl FractionalVotingPower {
         Self::new(Uint::from_u64(numer), Uint::from_u64(denom))
     }
 
+    /// Check if this fraction of the voting power is strictly greater than
+    /// the given threshold. The comparison is performed exactly over the
+    /// underlying rational numbers.
+    #[inline]
+    pub fn exceeds(&self, threshold: FractionalVotingPower) -> bool {
+        *self > threshold
+    }
+
     /// Multiple with overflow checks.
     pub fn checked_mul(&self, v: &Self) -> Option<Self> {
         use num_traits::CheckedMul;
@@ -385,6 +393,39 @@ mod tests {
         );
     }
 
+    /// Test that thresholds are only exceeded by strictly greater fractions
+    /// of the voting power.
+    #[test]
+    fn test_fractional_voting_power_exceeds() {
+        let one_third_plus =
+            FractionalVotingPower::new_u64(333_333_334, 1_000_000_000).unwrap();
+        assert!(one_third_plus.exceeds(FractionalVotingPower::ONE_THIRD));
+        assert!(
+            !FractionalVotingPower::ONE_THIRD
+                .exceeds(FractionalVotingPower::ONE_THIRD)
+        );
+        assert!(
+            !FractionalVotingPower::new_u64(2, 6)
+                .unwrap()
+                .exceeds(FractionalVotingPower::ONE_THIRD)
+        );
+        assert!(
+            FractionalVotingPower::new_u64(51, 100)
+                .unwrap()
+                .exceeds(FractionalVotingPower::HALF)
+        );
+        assert!(
+            !FractionalVotingPower::HALF.exceeds(FractionalVotingPower::HALF)
+        );
+        assert!(
+            FractionalVotingPower::WHOLE
+                .exceeds(FractionalVotingPower::TWO_THIRDS)
+        );
+        assert!(
+            !FractionalVotingPower::NULL.exceeds(FractionalVotingPower::NULL)
+        );
+    }
+
     /// Test error handling on the FractionalVotingPower type
     #[test]
     fn test_fractional_voting_power_valid_fractions() {
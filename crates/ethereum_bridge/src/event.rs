@@ -1,7 +1,9 @@
 //! Ethereum Bridge transaction events.
 
 use namada_core::borsh::{BorshDeserialize, BorshSerialize};
+use namada_core::chain::Epoch;
 use namada_core::keccak::KeccakHash;
+use namada_core::voting_power::FractionalVotingPower;
 use namada_events::extend::{ComposeEvent, EventAttributeEntry};
 use namada_events::{Event, EventError, EventLevel, EventToEmit, EventType};
 use namada_macros::BorshDeserializer;
@@ -23,6 +25,10 @@ pub mod types {
     /// Bridge pool expiration event.
     pub const BRIDGE_POOL_EXPIRED: EventType =
         event_type!(EthBridgeEvent, "bridge-pool", "expired");
+
+    /// Complete validator set update proof event.
+    pub const VALSET_UPD_PROOF_COMPLETE: EventType =
+        event_type!(EthBridgeEvent, "valset-upd", "proof-complete");
 }
 
 /// Status of some Bridge pool transfer.
@@ -107,6 +113,16 @@ pub enum EthBridgeEvent {
         /// Status of the Bridge pool transfer.
         status: BpTransferStatus,
     },
+    /// A validator set update proof has acquired enough
+    /// signatures to be relayed to Ethereum.
+    ValsetUpdateProofComplete {
+        /// The epoch of the validator set the proof is for.
+        epoch: Epoch,
+        /// The number of signatures in the proof.
+        sigs: u64,
+        /// The fraction of voting power backing the proof.
+        voting_power: FractionalVotingPower,
+    },
 }
 
 impl EthBridgeEvent {
@@ -142,6 +158,15 @@ impl From<&EthBridgeEvent> for Event {
                     .with(BridgePoolTxHash(tx_hash))
                     .into()
             }
+            EthBridgeEvent::ValsetUpdateProofComplete {
+                epoch,
+                sigs,
+                voting_power,
+            } => Event::new(types::VALSET_UPD_PROOF_COMPLETE, EventLevel::Tx)
+                .with(ValsetUpdEpoch(*epoch))
+                .with(ValsetUpdSignatures(*sigs))
+                .with(ValsetUpdVotingPower(*voting_power))
+                .into(),
         }
     }
 }
@@ -163,3 +188,45 @@ impl<'tx> EventAttributeEntry<'tx> for BridgePoolTxHash<'tx> {
         self.0
     }
 }
+
+/// Epoch of a validator set update proof.
+pub struct ValsetUpdEpoch(pub Epoch);
+
+impl EventAttributeEntry<'static> for ValsetUpdEpoch {
+    type Value = Epoch;
+    type ValueOwned = Self::Value;
+
+    const KEY: &'static str = "valset_upd_epoch";
+
+    fn into_value(self) -> Self::Value {
+        self.0
+    }
+}
+
+/// Number of signatures in a validator set update proof.
+pub struct ValsetUpdSignatures(pub u64);
+
+impl EventAttributeEntry<'static> for ValsetUpdSignatures {
+    type Value = u64;
+    type ValueOwned = Self::Value;
+
+    const KEY: &'static str = "valset_upd_signatures";
+
+    fn into_value(self) -> Self::Value {
+        self.0
+    }
+}
+
+/// Fraction of voting power backing a validator set update proof.
+pub struct ValsetUpdVotingPower(pub FractionalVotingPower);
+
+impl EventAttributeEntry<'static> for ValsetUpdVotingPower {
+    type Value = FractionalVotingPower;
+    type ValueOwned = Self::Value;
+
+    const KEY: &'static str = "valset_upd_voting_power";
+
+    fn into_value(self) -> Self::Value {
+        self.0
+    }
+}
@@ -229,9 +229,10 @@ where
         );
         update.0.attach_signature_batch(partial.0.signatures);
         let new_votes = NewVotes::new(seen_by, voting_powers)?;
-        let (vote_tracking, changed) = votes::update::calculate::<D, H, Gov, _>(
-            state, &bp_key, new_votes,
-        )?;
+        let (vote_tracking, changed, _) =
+            votes::update::calculate::<D, H, Gov, _>(
+                state, &bp_key, new_votes,
+            )?;
         if changed.is_empty() {
             return Ok((changed, None));
         }
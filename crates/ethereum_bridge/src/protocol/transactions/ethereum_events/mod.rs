@@ -229,7 +229,7 @@ where
             );
             let new_votes =
                 NewVotes::new(update.seen_by.clone(), voting_powers)?;
-            let (vote_tracking, changed) =
+            let (vote_tracking, changed, _) =
                 votes::update::calculate::<D, H, Gov, _>(
                     state,
                     &eth_msg_keys,
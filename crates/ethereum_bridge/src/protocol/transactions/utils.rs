@@ -34,6 +34,20 @@ where
     let voters = proof.get_voters();
     tracing::debug!(?voters, "Got validators who voted on at least one event");
 
+    get_voting_powers_for(state, voters)
+}
+
+/// Like [`get_voting_powers`], but operates on a precomputed set of
+/// `voters`, i.e. pairs of validator addresses and the block heights
+/// at which they voted.
+pub(super) fn get_voting_powers_for<D, H>(
+    state: &WlState<D, H>,
+    voters: HashSet<(Address, BlockHeight)>,
+) -> eyre::Result<HashMap<(Address, BlockHeight), token::Amount>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
     let consensus_validators = get_consensus_validators(
         state,
         voters.iter().map(|(_, h)| h.to_owned()).collect(),
@@ -123,6 +137,7 @@ mod tests {
     use namada_core::voting_power::FractionalVotingPower;
 
     use super::*;
+    use crate::test_utils;
 
     #[test]
     /// Test getting the voting power for the sole consensus validator from the
@@ -249,4 +264,19 @@ mod tests {
             Some(v) if *v == expected_stake
         );
     }
+
+    #[test]
+    /// Test getting the voting powers of a precomputed set of voters
+    fn test_get_voting_powers_for_precomputed_voters() {
+        let (state, _) = test_utils::setup_default_storage();
+        let (validator, stake) = test_utils::default_validator();
+        let height = state.in_mem().get_last_block_height();
+        let voters = HashSet::from_iter([(validator.clone(), height)]);
+
+        let voting_powers =
+            get_voting_powers_for(&state, voters).expect("Test failed");
+
+        assert_eq!(voting_powers.len(), 1);
+        assert_eq!(voting_powers.get(&(validator, height)), Some(&stake));
+    }
 }
@@ -1,37 +1,40 @@
 //! Code for handling validator set update protocol txs.
 
-use eyre::Result;
+use std::collections::BTreeSet;
+use std::num::NonZeroU64;
+
+use eyre::{eyre, Result};
 use namada_core::address::Address;
+use namada_core::borsh::BorshDeserialize;
 use namada_core::chain::{BlockHeight, Epoch};
-use namada_core::collections::{HashMap, HashSet};
+use namada_core::collections::HashMap;
+use namada_core::internal::StorageIntegrityError;
 use namada_core::key::common;
+use namada_core::storage::Key as StorageKey;
 use namada_core::token::Amount;
-use namada_state::{DBIter, StorageHasher, WlState, DB};
+use namada_core::voting_power::FractionalVotingPower;
+use namada_state::{
+    DBIter, StorageHasher, StorageRead, StorageWrite, WlState, DB,
+};
 use namada_systems::governance;
 use namada_tx::data::BatchedTxResult;
-use namada_vote_ext::validator_set_update;
+use namada_vote_ext::validator_set_update::{
+    self, VotingPowersMap, VotingPowersMapExt,
+};
+use thiserror::Error;
 
 use super::ChangedKeys;
+use crate::event::EthBridgeEvent;
 use crate::protocol::transactions::utils;
 use crate::protocol::transactions::votes::update::NewVotes;
-use crate::protocol::transactions::votes::{self, Votes};
+use crate::protocol::transactions::votes::{
+    self, EpochedVotingPowerExt, Tally, Votes,
+};
 use crate::storage::eth_bridge_queries::{EthBridgeQueries, SendValsetUpd};
+use crate::storage::parameters::MinValsetSigners;
 use crate::storage::proof::EthereumProof;
 use crate::storage::vote_tallies;
 
-impl utils::GetVoters for (&validator_set_update::VextDigest, BlockHeight) {
-    #[inline]
-    fn get_voters(self) -> HashSet<(Address, BlockHeight)> {
-        // votes were cast at the 2nd block height of the ext's signing epoch
-        let (ext, epoch_2nd_height) = self;
-        ext.signatures
-            .keys()
-            .cloned()
-            .zip(std::iter::repeat(epoch_2nd_height))
-            .collect()
-    }
-}
-
 /// Sign the next set of validators, and return the associated
 /// vote extension protocol transaction.
 pub fn sign_validator_set_update<D, H, Gov>(
@@ -48,27 +51,73 @@ where
         .ethbridge_queries()
         .must_send_valset_upd(SendValsetUpd::Now)
         .then(|| {
-            let next_epoch = state.in_mem().get_current_epoch().0.next();
+            let signing_epoch = state.in_mem().get_current_epoch().0;
+            sign_validator_set_update_for::<D, H, Gov>(
+                state,
+                validator_addr,
+                eth_hot_key,
+                signing_epoch,
+            )
+        })
+}
 
-            let voting_powers = state
-                .ethbridge_queries()
-                .get_consensus_eth_addresses::<Gov>(next_epoch)
-                .map(|(eth_addr_book, _, voting_power)| {
-                    (eth_addr_book, voting_power)
-                })
-                .collect();
-
-            let ext = validator_set_update::Vext {
-                voting_powers,
-                validator_addr: validator_addr.clone(),
-                signing_epoch: state.in_mem().get_current_epoch().0,
-            };
+/// Sign the set of validators of the epoch following `signing_epoch`, and
+/// return the associated vote extension protocol transaction.
+///
+/// Unlike [`sign_validator_set_update`], this function does not check if
+/// a validator set update should be sent at the current block height, nor
+/// does it read the current epoch from storage.
+pub fn sign_validator_set_update_for<D, H, Gov>(
+    state: &WlState<D, H>,
+    validator_addr: &Address,
+    eth_hot_key: &common::SecretKey,
+    signing_epoch: Epoch,
+) -> validator_set_update::SignedVext
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+    Gov: governance::Read<WlState<D, H>>,
+{
+    let next_epoch = signing_epoch.next();
 
-            ext.sign(eth_hot_key)
-        })
+    let get_voting_powers = |epoch| -> VotingPowersMap {
+        state
+            .ethbridge_queries()
+            .get_consensus_eth_addresses::<Gov>(epoch)
+            .map(|(eth_addr_book, _, voting_power)| {
+                (eth_addr_book, voting_power)
+            })
+            .collect()
+    };
+    let voting_powers = get_voting_powers(next_epoch);
+
+    // Only look up the voting powers of the signing epoch when they are
+    // going to be logged
+    if tracing::level_enabled!(tracing::Level::DEBUG) {
+        let diff = voting_powers.diff(&get_voting_powers(signing_epoch));
+        tracing::debug!(
+            ?next_epoch,
+            added = diff.added.len(),
+            removed = diff.removed.len(),
+            changed = diff.changed.len(),
+            "Signing validator set update"
+        );
+    }
+
+    let ext = validator_set_update::Vext {
+        voting_powers,
+        validator_addr: validator_addr.clone(),
+        signing_epoch,
+    };
+
+    ext.sign(eth_hot_key)
 }
 
 /// Aggregate validators' votes
+///
+/// All logs emitted while aggregating are correlated under a `valset_upd`
+/// span, carrying the signing epoch of the votes, the number of voters
+/// in `ext` and whether the update is seen after aggregation.
 pub fn aggregate_votes<D, H, Gov>(
     state: &mut WlState<D, H>,
     ext: validator_set_update::VextDigest,
@@ -79,6 +128,14 @@ where
     H: 'static + StorageHasher + Sync,
     Gov: governance::Read<WlState<D, H>>,
 {
+    let span = tracing::info_span!(
+        "valset_upd",
+        epoch = %signing_epoch,
+        num_voters = tracing::field::Empty,
+        seen = tracing::field::Empty,
+    );
+    let _entered = span.enter();
+
     if ext.signatures.is_empty() {
         tracing::debug!("Ignoring empty validator set update");
         return Ok(Default::default());
@@ -89,21 +146,76 @@ where
         "Aggregating new votes for validator set update"
     );
 
-    let epoch_2nd_height = state
-        .in_mem()
-        .block
-        .pred_epochs
+    let pred_epochs = &state.in_mem().block.pred_epochs;
+
+    let signing_epoch_start = pred_epochs
         .get_start_height_of_epoch(signing_epoch)
         // NOTE: The only way this can fail is if validator set updates do not
         // reach a `seen` state before the relevant epoch data is purged from
         // Namada. In most scenarios, we should reach a complete proof before
         // the end of an epoch, and even if we cross an epoch boundary without
         // a complete proof, we should get one shortly after.
-        .expect("The first block height of the signing epoch should be known")
-        .next_height();
-    let voting_powers =
-        utils::get_voting_powers(state, (&ext, epoch_2nd_height))?;
-    let changed_keys = apply_update::<D, H, Gov>(
+        .ok_or_else(|| {
+            let err = StorageIntegrityError::HeightEpochDesync {
+                epoch: signing_epoch,
+                reason: "the first block height of the signing epoch of a \
+                         validator set update is unknown"
+                    .to_string(),
+            };
+            tracing::error!(%err, "Storage integrity error");
+            err
+        })?;
+
+    // The data of the epoch following the signing epoch can only be missing
+    // if that epoch has yet to begin, since the data of the signing epoch,
+    // which is older, is still around. Votes are always on time then.
+    match pred_epochs.get_start_height_of_epoch(signing_epoch.next()) {
+        Some(next_epoch_start) => {
+            let grace_window: u64 = state
+                .ethbridge_queries()
+                .get_valset_upd_grace_window()
+                .into();
+            let deadline = next_epoch_start
+                .checked_add(grace_window)
+                .unwrap_or(BlockHeight(u64::MAX));
+            let current_height = state.get_current_decision_height();
+            if current_height > deadline {
+                tracing::warn!(
+                    %signing_epoch,
+                    %current_height,
+                    %deadline,
+                    "Rejecting late validator set update votes"
+                );
+                return Err(eyre!(
+                    "Validator set update votes signed in epoch \
+                     {signing_epoch} arrived at height {current_height}, past \
+                     the grace window deadline {deadline}"
+                ));
+            }
+        }
+        None => {
+            tracing::debug!(
+                %signing_epoch,
+                "The epoch following the signing epoch has yet to begin, \
+                 skipping the grace window check"
+            );
+        }
+    }
+
+    let epoch_2nd_height =
+        signing_epoch_start.checked_add(1).ok_or_else(|| {
+            eyre!("The second block height of epoch {signing_epoch} overflows")
+        })?;
+    // votes were cast at the 2nd block height of the ext's signing epoch
+    let voters = ext
+        .signatures
+        .keys()
+        .cloned()
+        .zip(std::iter::repeat(epoch_2nd_height))
+        .collect();
+    let voting_powers = utils::get_voting_powers_for(state, voters)?;
+    let num_voters = ext.signatures.len();
+    let (changed_keys, tx_events) = apply_update::<D, H, Gov>(
         state,
         ext,
         signing_epoch,
@@ -111,8 +223,17 @@ where
         voting_powers,
     )?;
 
+    let seen = votes::storage::maybe_read_seen(
+        state,
+        &vote_tallies::Keys::from(&signing_epoch.next()),
+    )?
+    .unwrap_or(false);
+    span.record("num_voters", num_voters);
+    span.record("seen", seen);
+
     Ok(BatchedTxResult {
         changed_keys,
+        events: tx_events.into_iter().map(|event| event.into()).collect(),
         ..Default::default()
     })
 }
@@ -123,7 +244,7 @@ fn apply_update<D, H, Gov>(
     signing_epoch: Epoch,
     epoch_2nd_height: BlockHeight,
     voting_powers: HashMap<(Address, BlockHeight), Amount>,
-) -> Result<ChangedKeys>
+) -> Result<(ChangedKeys, BTreeSet<EthBridgeEvent>)>
 where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
@@ -144,12 +265,14 @@ where
         };
         if seen {
             tracing::debug!("Validator set update tally is already seen");
-            return Ok(ChangedKeys::default());
+            return Ok((ChangedKeys::default(), BTreeSet::default()));
         }
         let proof = votes::storage::read_body(state, &valset_upd_keys)?;
         Some(proof)
     };
 
+    let min_signers = state.ethbridge_queries().get_min_valset_signers();
+
     let mut seen_by = Votes::default();
     for address in ext.signatures.keys().cloned() {
         if let Some(present) = seen_by.insert(address, epoch_2nd_height) {
@@ -159,20 +282,24 @@ where
         }
     }
 
-    let (tally, proof, changed, confirmed, already_present) =
+    let (tally, proof, mut changed, confirmed, already_present) =
         if let Some(mut proof) = maybe_proof {
             tracing::debug!(
                 %valset_upd_keys.prefix,
                 "Validator set update votes already in storage",
             );
             let new_votes = NewVotes::new(seen_by, &voting_powers)?;
-            let (tally, changed) = votes::update::calculate::<_, _, Gov, _>(
-                state,
-                &valset_upd_keys,
-                new_votes,
-            )?;
+            let (mut tally, mut changed, _) =
+                votes::update::calculate::<_, _, Gov, _>(
+                    state,
+                    &valset_upd_keys,
+                    new_votes,
+                )?;
+            if withhold_seen_below_min_signers(&mut tally, min_signers) {
+                changed.remove(&valset_upd_keys.seen());
+            }
             if changed.is_empty() {
-                return Ok(changed);
+                return Ok((changed, BTreeSet::default()));
             }
             let confirmed =
                 tally.seen && changed.contains(&valset_upd_keys.seen());
@@ -197,11 +324,12 @@ where
                 ?ext.voting_powers,
                 "New validator set update vote aggregation started"
             );
-            let tally = votes::calculate_new::<D, H, Gov>(
+            let mut tally = votes::calculate_new::<D, H, Gov>(
                 state,
                 seen_by,
                 &voting_powers,
             )?;
+            withhold_seen_below_min_signers(&mut tally, min_signers);
             let mut proof = EthereumProof::new(ext.voting_powers);
             proof.attach_signature_batch(ext.signatures.into_iter().map(
                 |(addr, sig)| {
@@ -234,26 +362,193 @@ where
         &tally,
         already_present,
     )?;
+    let voting_power = tally.voting_power.fractional_stake::<D, H, Gov>(state);
+    changed.insert(append_coverage_timeline(
+        state,
+        &valset_upd_keys,
+        voting_power,
+    )?);
 
+    let mut tx_events = BTreeSet::new();
     if confirmed {
         tracing::debug!(
             %valset_upd_keys.prefix,
             "Acquired complete proof on validator set update"
         );
+        tx_events.insert(EthBridgeEvent::ValsetUpdateProofComplete {
+            epoch: next_epoch,
+            sigs: proof.signatures.len() as u64,
+            voting_power,
+        });
+    }
+
+    Ok((changed, tx_events))
+}
+
+/// Withhold the `seen` status of a validator set update [`Tally`] that
+/// reached a quorum of voting power, but which has not yet been signed
+/// by the minimum number of distinct validators.
+///
+/// Returns whether the `seen` status was withheld.
+fn withhold_seen_below_min_signers(
+    tally: &mut Tally,
+    min_signers: MinValsetSigners,
+) -> bool {
+    let min_signers: u64 = NonZeroU64::from(min_signers).get();
+    let num_signers = u64::try_from(tally.seen_by.len()).unwrap_or(u64::MAX);
+    if !tally.seen || num_signers >= min_signers {
+        return false;
+    }
+    tracing::debug!(
+        num_signers,
+        min_signers,
+        "Validator set update has a quorum of voting power, but too few \
+         signers"
+    );
+    tally.seen = false;
+    true
+}
+
+/// The maximum number of points retained in the coverage timeline of a
+/// validator set update proof.
+pub const MAX_COVERAGE_TIMELINE_POINTS: usize = 64;
+
+/// Append the current voting power coverage of a validator set update
+/// to its coverage timeline, and return the key of the timeline.
+///
+/// The timeline is a ring buffer of at most [`MAX_COVERAGE_TIMELINE_POINTS`]
+/// points: once full, the oldest point is evicted to make room for the
+/// new one. A point at the same height as the latest one replaces it.
+fn append_coverage_timeline<D, H>(
+    state: &mut WlState<D, H>,
+    valset_upd_keys: &vote_tallies::Keys<EthereumProof<VotingPowersMap>>,
+    coverage: FractionalVotingPower,
+) -> Result<StorageKey>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let timeline_key = valset_upd_keys.coverage_timeline();
+    let mut timeline: Vec<(BlockHeight, FractionalVotingPower)> =
+        state.read(&timeline_key)?.unwrap_or_default();
+    let height = state.in_mem().get_block_height().0;
+    if timeline.last().map(|(last_height, _)| *last_height) == Some(height) {
+        timeline.pop();
+    }
+    let evicted = timeline
+        .len()
+        .saturating_sub(MAX_COVERAGE_TIMELINE_POINTS.saturating_sub(1));
+    timeline.drain(..evicted);
+    timeline.push((height, coverage));
+    state.write(&timeline_key, timeline)?;
+    Ok(timeline_key)
+}
+
+/// Read the timeline of voting power coverage accumulated by the
+/// validator set update proof for the given [`Epoch`].
+///
+/// Each entry records the block height at which new votes were
+/// aggregated, and the cumulative fraction of voting power backing
+/// the proof at that height. Only the [`MAX_COVERAGE_TIMELINE_POINTS`]
+/// most recent entries are retained. If no votes have been aggregated
+/// for the given [`Epoch`], an empty timeline is returned.
+pub fn valset_coverage_timeline<D, H>(
+    state: &WlState<D, H>,
+    epoch: Epoch,
+) -> Result<Vec<(BlockHeight, FractionalVotingPower)>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let valset_upd_keys = vote_tallies::Keys::from(&epoch);
+    let timeline = state
+        .read(&valset_upd_keys.coverage_timeline())?
+        .unwrap_or_default();
+    Ok(timeline)
+}
+
+/// Return the [`Epoch`]s of all validator set update proofs which have
+/// started collecting votes, but have not been seen yet.
+pub fn incomplete_valset_epochs<D, H>(
+    state: &WlState<D, H>,
+) -> Result<Vec<Epoch>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let prefix = vote_tallies::valset_upds_prefix();
+    let mut epochs = Vec::new();
+    for (key, val, _) in votes::storage::iter_prefix(state, &prefix)? {
+        let key = StorageKey::parse(key).expect("The key should be parsable");
+        if !vote_tallies::is_seen_key(&key) {
+            continue;
+        }
+        let Some(epoch) = vote_tallies::valset_upd_epoch(&key) else {
+            continue;
+        };
+        let seen =
+            bool::try_from_slice(&val[..]).expect("Decoding boolean failed");
+        if !seen {
+            epochs.push(epoch);
+        }
     }
+    Ok(epochs)
+}
+
+/// A complete validator set update proof, along with the [`Epoch`] of the
+/// validator set it transitions to.
+pub type CompleteProof = EthereumProof<(Epoch, VotingPowersMap)>;
 
-    Ok(changed)
+/// Error yielded when a chain of validator set update proofs has a gap.
+#[derive(Error, Debug)]
+pub enum ChainGapError {
+    /// No proof has started collecting votes at the given epoch.
+    #[error("The validator set update proof of epoch {0} is missing")]
+    Missing(Epoch),
+    /// The proof at the given epoch has not been seen yet.
+    #[error("The validator set update proof of epoch {0} is incomplete")]
+    Incomplete(Epoch),
+    /// Failed to read a proof from storage.
+    #[error("Failed to read a validator set update proof: {0}")]
+    Storage(#[from] namada_storage::Error),
+}
+
+/// Export the complete validator set update proofs of all the epochs between
+/// `from_epoch` and `to_epoch` (inclusive), in order. Errors if the proof of
+/// any of these epochs is missing or incomplete.
+pub fn export_proof_chain<D, H>(
+    state: &WlState<D, H>,
+    from_epoch: Epoch,
+    to_epoch: Epoch,
+) -> std::result::Result<Vec<CompleteProof>, ChainGapError>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let mut chain = Vec::new();
+    for epoch in (from_epoch.0..=to_epoch.0).map(Epoch) {
+        let valset_upd_keys = vote_tallies::Keys::from(&epoch);
+        match state.read::<bool>(&valset_upd_keys.seen())? {
+            None => return Err(ChainGapError::Missing(epoch)),
+            Some(false) => return Err(ChainGapError::Incomplete(epoch)),
+            Some(true) => {}
+        }
+        let proof: EthereumProof<VotingPowersMap> = state
+            .read(&valset_upd_keys.body())?
+            .ok_or(ChainGapError::Missing(epoch))?;
+        chain.push(proof.map(|set| (epoch, set)));
+    }
+    Ok(chain)
 }
 
 #[cfg(test)]
 mod test_valset_upd_state_changes {
+    use assert_matches::assert_matches;
     use namada_core::address;
-    use namada_core::voting_power::FractionalVotingPower;
+    use namada_events::Event;
     use namada_proof_of_stake::queries::{
         get_total_voting_power, read_validator_stake,
     };
-    use namada_state::StorageRead;
-    use namada_vote_ext::validator_set_update::VotingPowersMap;
 
     use super::*;
     use crate::test_utils::{self, GovStore};
@@ -270,21 +565,21 @@ mod test_valset_upd_state_changes {
             .unwrap()
             .expect("The epoch of the last block height should be known");
 
+        let signed_ext = sign_validator_set_update_for::<_, _, GovStore<_>>(
+            &state,
+            &address::testing::established_address_1(),
+            &keys
+                .get(&address::testing::established_address_1())
+                .expect("Test failed")
+                .eth_bridge,
+            signing_epoch,
+        );
+        assert_eq!(signed_ext.data.signing_epoch, signing_epoch);
+        let voting_powers = signed_ext.data.voting_powers.clone();
+
         let tx_result = aggregate_votes::<_, _, GovStore<_>>(
             &mut state,
-            validator_set_update::VextDigest::singleton(
-                validator_set_update::Vext {
-                    voting_powers: VotingPowersMap::new(),
-                    validator_addr: address::testing::established_address_1(),
-                    signing_epoch,
-                }
-                .sign(
-                    &keys
-                        .get(&address::testing::established_address_1())
-                        .expect("Test failed")
-                        .eth_bridge,
-                ),
-            ),
+            validator_set_update::VextDigest::singleton(signed_ext),
             signing_epoch,
         )
         .expect("Test failed");
@@ -312,7 +607,7 @@ mod test_valset_upd_state_changes {
         // from the configured validator
         let proof = votes::storage::read_body(&state, &valset_upd_keys)
             .expect("Test failed");
-        assert_eq!(proof.data, VotingPowersMap::new());
+        assert_eq!(proof.data, voting_powers);
 
         let mut proof_sigs: Vec<_> = proof.signatures.into_keys().collect();
         assert_eq!(proof_sigs.len(), 1);
@@ -346,6 +641,15 @@ mod test_valset_upd_state_changes {
         .expect("Test failed");
 
         assert!(voting_power > FractionalVotingPower::TWO_THIRDS);
+
+        // a complete proof event should have been emitted
+        let expected_event: Event = EthBridgeEvent::ValsetUpdateProofComplete {
+            epoch: signing_epoch.next(),
+            sigs: 1,
+            voting_power: FractionalVotingPower::WHOLE,
+        }
+        .into();
+        assert_eq!(tx_result.events, BTreeSet::from([expected_event]));
     }
 
     /// Test that if a validator set update is not "seen" yet, then
@@ -446,5 +750,406 @@ mod test_valset_upd_state_changes {
         .expect("Test failed");
 
         assert!(voting_power <= FractionalVotingPower::TWO_THIRDS);
+
+        // no complete proof event should have been emitted
+        assert!(tx_result.events.is_empty());
+    }
+
+    /// Test that a validator set update signed by a single validator
+    /// with a quorum of voting power is not considered "seen", if
+    /// the minimum number of signers has not been reached.
+    #[test]
+    fn test_quorum_below_min_signers_is_not_seen() {
+        let (mut state, keys) = test_utils::setup_default_storage();
+        state
+            .write(
+                &crate::storage::min_valset_signers_key(),
+                MinValsetSigners::from(
+                    NonZeroU64::new(2).expect("Test failed"),
+                ),
+            )
+            .expect("Test failed");
+
+        let last_height = state.in_mem().get_last_block_height();
+        let signing_epoch = state
+            .get_epoch_at_height(last_height)
+            .unwrap()
+            .expect("The epoch of the last block height should be known");
+
+        let signed_ext = sign_validator_set_update_for::<_, _, GovStore<_>>(
+            &state,
+            &address::testing::established_address_1(),
+            &keys
+                .get(&address::testing::established_address_1())
+                .expect("Test failed")
+                .eth_bridge,
+            signing_epoch,
+        );
+
+        let tx_result = aggregate_votes::<_, _, GovStore<_>>(
+            &mut state,
+            validator_set_update::VextDigest::singleton(signed_ext),
+            signing_epoch,
+        )
+        .expect("Test failed");
+
+        // the sole validator holds a quorum of voting power
+        let total_voting_power =
+            get_total_voting_power::<_, GovStore<_>>(&state, signing_epoch);
+        let validator_voting_power = read_validator_stake::<_, GovStore<_>>(
+            &state,
+            &address::testing::established_address_1(),
+            signing_epoch,
+        )
+        .expect("Test failed");
+        let voting_power = FractionalVotingPower::new(
+            validator_voting_power.into(),
+            total_voting_power.into(),
+        )
+        .expect("Test failed");
+        assert!(voting_power > FractionalVotingPower::TWO_THIRDS);
+
+        // but the valset upd should not be marked as "seen"
+        let valset_upd_keys = vote_tallies::Keys::from(&signing_epoch.next());
+        let tally = votes::storage::read(&state, &valset_upd_keys)
+            .expect("Test failed");
+        assert!(!tally.seen);
+        assert_eq!(tally.seen_by.len(), 1);
+
+        // and no complete proof event should have been emitted
+        assert!(tx_result.events.is_empty());
+    }
+
+    /// Test that validator set update votes arriving after the grace
+    /// window has elapsed are rejected with an error, rather than
+    /// causing a panic.
+    #[test]
+    fn test_late_votes_past_grace_window_are_rejected() {
+        let (mut state, keys) = test_utils::setup_default_storage();
+
+        let last_height = state.in_mem().get_last_block_height();
+        let signing_epoch = state
+            .get_epoch_at_height(last_height)
+            .unwrap()
+            .expect("The epoch of the last block height should be known");
+
+        // start the next epoch, and move past the grace window
+        let next_epoch_start = last_height.next_height();
+        state
+            .in_mem_mut()
+            .block
+            .pred_epochs
+            .new_epoch(next_epoch_start);
+        let grace_window: u64 = state
+            .ethbridge_queries()
+            .get_valset_upd_grace_window()
+            .into();
+        state
+            .in_mem_mut()
+            .last_block
+            .as_mut()
+            .expect("Test failed")
+            .height = next_epoch_start
+            .checked_add(grace_window)
+            .expect("Test failed");
+
+        let result = aggregate_votes::<_, _, GovStore<_>>(
+            &mut state,
+            validator_set_update::VextDigest::singleton(
+                validator_set_update::Vext {
+                    voting_powers: VotingPowersMap::new(),
+                    validator_addr: address::testing::established_address_1(),
+                    signing_epoch,
+                }
+                .sign(
+                    &keys
+                        .get(&address::testing::established_address_1())
+                        .expect("Test failed")
+                        .eth_bridge,
+                ),
+            ),
+            signing_epoch,
+        );
+        assert!(result.is_err());
+
+        // nothing should have been written to storage
+        let valset_upd_keys = vote_tallies::Keys::from(&signing_epoch.next());
+        let maybe_seen =
+            votes::storage::maybe_read_seen(&state, &valset_upd_keys)
+                .expect("Test failed");
+        assert!(maybe_seen.is_none());
+    }
+
+    /// Test that votes whose signing epoch is unknown to storage, e.g.
+    /// because it has been purged, result in a storage integrity error
+    /// rather than a panic.
+    #[test]
+    fn test_unknown_signing_epoch_is_integrity_error() {
+        let (mut state, keys) = test_utils::setup_default_storage();
+        let signing_epoch = Epoch(100);
+
+        let result = aggregate_votes::<_, _, GovStore<_>>(
+            &mut state,
+            validator_set_update::VextDigest::singleton(
+                validator_set_update::Vext {
+                    voting_powers: VotingPowersMap::new(),
+                    validator_addr: address::testing::established_address_1(),
+                    signing_epoch,
+                }
+                .sign(
+                    &keys
+                        .get(&address::testing::established_address_1())
+                        .expect("Test failed")
+                        .eth_bridge,
+                ),
+            ),
+            signing_epoch,
+        );
+        let err = result.expect_err("Test failed");
+        assert_matches!(
+            err.downcast_ref::<StorageIntegrityError>(),
+            Some(StorageIntegrityError::HeightEpochDesync { epoch, .. })
+                if *epoch == signing_epoch
+        );
+    }
+
+    /// Test that aggregating votes across different block heights
+    /// records a point in the coverage timeline for each height.
+    #[test]
+    fn test_coverage_timeline_across_heights() {
+        let (mut state, keys) =
+            test_utils::setup_storage_with_validators(HashMap::from_iter([
+                // the first validator has exactly 2/3 of the total stake
+                (
+                    address::testing::established_address_1(),
+                    Amount::native_whole(50_000),
+                ),
+                (
+                    address::testing::established_address_2(),
+                    Amount::native_whole(25_000),
+                ),
+            ]));
+
+        let last_height = state.in_mem().get_last_block_height();
+        let signing_epoch = state
+            .get_epoch_at_height(last_height)
+            .unwrap()
+            .expect("The epoch of the last block height should be known");
+
+        let sign_vext = |validator_addr: Address| {
+            let eth_bridge_key =
+                &keys.get(&validator_addr).expect("Test failed").eth_bridge;
+            validator_set_update::Vext {
+                voting_powers: VotingPowersMap::new(),
+                validator_addr,
+                signing_epoch,
+            }
+            .sign(eth_bridge_key)
+        };
+
+        // aggregate the first validator's vote
+        let first_height = state.in_mem().get_block_height().0;
+        aggregate_votes::<_, _, GovStore<_>>(
+            &mut state,
+            validator_set_update::VextDigest::singleton(sign_vext(
+                address::testing::established_address_1(),
+            )),
+            signing_epoch,
+        )
+        .expect("Test failed");
+
+        // aggregate the second validator's vote at a later height
+        state.in_mem_mut().block.height += 1;
+        let second_height = state.in_mem().get_block_height().0;
+        aggregate_votes::<_, _, GovStore<_>>(
+            &mut state,
+            validator_set_update::VextDigest::singleton(sign_vext(
+                address::testing::established_address_2(),
+            )),
+            signing_epoch,
+        )
+        .expect("Test failed");
+
+        let timeline = valset_coverage_timeline(&state, signing_epoch.next())
+            .expect("Test failed");
+        assert_eq!(
+            timeline,
+            vec![
+                (first_height, FractionalVotingPower::TWO_THIRDS),
+                (second_height, FractionalVotingPower::WHOLE),
+            ]
+        );
+    }
+
+    /// Test that the coverage timeline of a validator set update is
+    /// bounded, and only retains its most recent points.
+    #[test]
+    fn test_coverage_timeline_is_bounded() {
+        let (mut state, _) = test_utils::setup_default_storage();
+        let valset_upd_keys = vote_tallies::Keys::from(&Epoch(1));
+
+        let first_height = state.in_mem().get_block_height().0;
+        for _ in 0..=MAX_COVERAGE_TIMELINE_POINTS {
+            append_coverage_timeline(
+                &mut state,
+                &valset_upd_keys,
+                FractionalVotingPower::ONE_THIRD,
+            )
+            .expect("Test failed");
+            state.in_mem_mut().block.height += 1;
+        }
+        // a point at the same height replaces the latest one
+        state.in_mem_mut().block.height = state
+            .in_mem()
+            .block
+            .height
+            .prev_height()
+            .expect("Test failed");
+        append_coverage_timeline(
+            &mut state,
+            &valset_upd_keys,
+            FractionalVotingPower::WHOLE,
+        )
+        .expect("Test failed");
+
+        let timeline =
+            valset_coverage_timeline(&state, Epoch(1)).expect("Test failed");
+        assert_eq!(timeline.len(), MAX_COVERAGE_TIMELINE_POINTS);
+        assert_eq!(timeline[0].0, first_height + 1);
+        assert_eq!(
+            timeline.last(),
+            Some(&(
+                first_height + MAX_COVERAGE_TIMELINE_POINTS as u64,
+                FractionalVotingPower::WHOLE
+            ))
+        );
+    }
+
+    /// Test that a [`validator_set_update::VextDigest`] built from
+    /// multiple signers can be aggregated in one go.
+    #[test]
+    fn test_aggregate_digest_from_multiple_signers() {
+        let (mut state, keys) =
+            test_utils::setup_storage_with_validators(HashMap::from_iter([
+                (
+                    address::testing::established_address_1(),
+                    Amount::native_whole(50_000),
+                ),
+                (
+                    address::testing::established_address_2(),
+                    Amount::native_whole(25_000),
+                ),
+            ]));
+
+        let last_height = state.in_mem().get_last_block_height();
+        let signing_epoch = state
+            .get_epoch_at_height(last_height)
+            .unwrap()
+            .expect("The epoch of the last block height should be known");
+
+        let signed_vexts = [
+            address::testing::established_address_1(),
+            address::testing::established_address_2(),
+        ]
+        .into_iter()
+        .map(|validator_addr| {
+            let eth_bridge_key =
+                &keys.get(&validator_addr).expect("Test failed").eth_bridge;
+            validator_set_update::Vext {
+                voting_powers: VotingPowersMap::new(),
+                validator_addr,
+                signing_epoch,
+            }
+            .sign(eth_bridge_key)
+        })
+        .collect();
+        let (digest, digest_epoch) =
+            validator_set_update::VextDigest::from_signed_vexts(signed_vexts)
+                .expect("Test failed");
+        assert_eq!(digest_epoch, signing_epoch);
+        assert_eq!(digest.signatures.len(), 2);
+
+        aggregate_votes::<_, _, GovStore<_>>(&mut state, digest, signing_epoch)
+            .expect("Test failed");
+
+        // both validators combined hold all of the voting power
+        let valset_upd_keys = vote_tallies::Keys::from(&signing_epoch.next());
+        let tally = votes::storage::read(&state, &valset_upd_keys)
+            .expect("Test failed");
+        assert!(tally.seen);
+        assert_eq!(tally.seen_by.len(), 2);
+
+        let proof = votes::storage::read_body(&state, &valset_upd_keys)
+            .expect("Test failed");
+        assert_eq!(proof.signatures.len(), 2);
+    }
+
+    /// Test that only the epochs of validator set update proofs
+    /// which have not been seen are reported as incomplete.
+    #[test]
+    fn test_incomplete_valset_epochs() {
+        let (mut state, _) = test_utils::setup_default_storage();
+
+        for (epoch, seen) in [(1, true), (2, false), (3, true), (4, false)] {
+            let valset_upd_keys = vote_tallies::Keys::from(&Epoch(epoch));
+            state
+                .write(&valset_upd_keys.seen(), seen)
+                .expect("Test failed");
+            state
+                .write(&valset_upd_keys.voting_started_epoch(), Epoch(epoch))
+                .expect("Test failed");
+        }
+
+        let incomplete = incomplete_valset_epochs(&state).expect("Test failed");
+        assert_eq!(incomplete, vec![Epoch(2), Epoch(4)]);
+    }
+
+    /// Write a validator set update proof of the given epoch to storage.
+    fn write_valset_upd_proof(
+        state: &mut namada_state::testing::TestState,
+        epoch: Epoch,
+        seen: bool,
+    ) {
+        let valset_upd_keys = vote_tallies::Keys::from(&epoch);
+        state
+            .write(
+                &valset_upd_keys.body(),
+                EthereumProof::new(VotingPowersMap::new()),
+            )
+            .expect("Test failed");
+        state
+            .write(&valset_upd_keys.seen(), seen)
+            .expect("Test failed");
+    }
+
+    #[test]
+    fn test_export_proof_chain() {
+        let (mut state, _) = test_utils::setup_default_storage();
+        for epoch in 1..=3 {
+            write_valset_upd_proof(&mut state, Epoch(epoch), true);
+        }
+
+        let chain = export_proof_chain(&state, Epoch(1), Epoch(3))
+            .expect("Test failed");
+        let epochs: Vec<_> =
+            chain.into_iter().map(|proof| proof.data.0).collect();
+        assert_eq!(epochs, vec![Epoch(1), Epoch(2), Epoch(3)]);
+    }
+
+    #[test]
+    fn test_export_proof_chain_with_gaps() {
+        let (mut state, _) = test_utils::setup_default_storage();
+        write_valset_upd_proof(&mut state, Epoch(1), true);
+        write_valset_upd_proof(&mut state, Epoch(3), true);
+        write_valset_upd_proof(&mut state, Epoch(4), false);
+
+        assert_matches!(
+            export_proof_chain(&state, Epoch(1), Epoch(3)),
+            Err(ChainGapError::Missing(Epoch(2)))
+        );
+        assert_matches!(
+            export_proof_chain(&state, Epoch(3), Epoch(4)),
+            Err(ChainGapError::Incomplete(Epoch(4)))
+        );
     }
 }
@@ -16,12 +16,15 @@ use namada_migrations::*;
 use namada_proof_of_stake::queries::get_total_voting_power;
 use namada_state::{DBIter, StorageHasher, StorageRead, WlState, DB};
 use namada_systems::governance;
+use thiserror::Error;
 
 use super::{read, ChangedKeys};
 
 pub(super) mod storage;
 pub(super) mod update;
 
+pub use storage::{iter_seen_by, iter_tallies, prune_seen};
+
 /// The addresses of validators that voted for something, and the block
 /// heights at which they voted.
 ///
@@ -35,6 +38,48 @@ pub type Votes = BTreeMap<Address, BlockHeight>;
 /// The voting power behind a tally aggregated over multiple epochs.
 pub type EpochedVotingPower = BTreeMap<Epoch, token::Amount>;
 
+/// Errors that may arise while tallying validators' votes.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum TallyError {
+    /// Adding the voting power of a validator to a tally overflowed.
+    #[error(
+        "Aggregated voting power overflowed when tallying the vote of \
+         {validator} in epoch {epoch}"
+    )]
+    VotingPowerOverflow {
+        /// The validator whose vote overflowed the tally.
+        validator: Address,
+        /// The epoch whose aggregated voting power overflowed.
+        epoch: Epoch,
+    },
+    /// Summing the voting power of a tally across all epochs overflowed.
+    #[error("Aggregated voting power overflowed across all tallied epochs")]
+    TalliedStakeOverflow,
+}
+
+/// Add the voting power of `validator` at `epoch` to `voting_power`.
+fn accumulate_voting_power(
+    voting_power: &mut EpochedVotingPower,
+    validator: &Address,
+    epoch: Epoch,
+    amount: token::Amount,
+) -> std::result::Result<(), TallyError> {
+    let aggregated = voting_power
+        .entry(epoch)
+        .or_insert_with(token::Amount::zero);
+    *aggregated = aggregated.checked_add(amount).ok_or_else(|| {
+        TallyError::VotingPowerOverflow {
+            validator: validator.clone(),
+            epoch,
+        }
+    })?;
+    // the sum across all epochs is compared against the quorum threshold
+    // later on, so it must not overflow either
+    token::Amount::sum(voting_power.values().copied())
+        .ok_or(TallyError::TalliedStakeOverflow)?;
+    Ok(())
+}
+
 /// Extension methods for [`EpochedVotingPower`] instances.
 pub trait EpochedVotingPowerExt {
     /// Query the stake of the most secure [`Epoch`] referenced by an
@@ -178,12 +223,12 @@ where
                     .get_epoch_at_height(*block_height)
                     .unwrap()
                     .expect("The queried epoch should be known");
-                let aggregated = seen_by_voting_power
-                    .entry(epoch)
-                    .or_insert_with(token::Amount::zero);
-                *aggregated = aggregated
-                    .checked_add(voting_power)
-                    .ok_or_else(|| eyre!("Aggregated voting power overflow"))?;
+                accumulate_voting_power(
+                    &mut seen_by_voting_power,
+                    validator,
+                    epoch,
+                    voting_power,
+                )?;
             }
             None => {
                 return Err(eyre!(
@@ -308,6 +353,49 @@ mod tests {
         );
     }
 
+    /// Test that tallying voting powers whose sum overflows results in
+    /// an error, rather than a panic or a wrapped tally.
+    #[test]
+    fn test_calculate_new_voting_power_overflow() {
+        let (state, _) = test_utils::setup_default_storage();
+        let height = state.in_mem().get_last_block_height();
+        let validator_1 = address::testing::established_address_1();
+        let validator_2 = address::testing::established_address_2();
+        let seen_by = Votes::from([
+            (validator_1.clone(), height),
+            (validator_2.clone(), height),
+        ]);
+        let almost_max = token::Amount::max()
+            .checked_sub(token::Amount::from(1u64))
+            .expect("Test failed");
+
+        // summing up to the max amount is fine
+        let voting_powers = HashMap::from([
+            ((validator_1.clone(), height), almost_max),
+            ((validator_2.clone(), height), token::Amount::from(1u64)),
+        ]);
+        let tally = calculate_new::<_, _, GovStore<_>>(
+            &state,
+            seen_by.clone(),
+            &voting_powers,
+        )
+        .expect("Test failed");
+        assert_eq!(tally.voting_power.tallied_stake(), token::Amount::max());
+
+        // but going past it is an error
+        let voting_powers = HashMap::from([
+            ((validator_1, height), almost_max),
+            ((validator_2, height), token::Amount::from(2u64)),
+        ]);
+        let error =
+            calculate_new::<_, _, GovStore<_>>(&state, seen_by, &voting_powers)
+                .expect_err("Test failed");
+        assert!(matches!(
+            error.downcast_ref::<TallyError>(),
+            Some(TallyError::VotingPowerOverflow { .. })
+        ));
+    }
+
     /// Test that voting on a tally during a single epoch does
     /// not require any storage reads, and goes through the
     /// fast path of the algorithm.
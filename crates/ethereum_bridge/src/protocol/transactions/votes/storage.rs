@@ -1,5 +1,7 @@
 use eyre::{Result, WrapErr};
-use namada_core::borsh::{BorshDeserialize, BorshSerialize};
+use namada_core::address::Address;
+use namada_core::borsh::{BorshDeserialize, BorshSerialize, BorshSerializeExt};
+use namada_core::chain::{BlockHeight, Epoch};
 use namada_core::hints;
 use namada_core::storage::Key;
 use namada_core::voting_power::FractionalVotingPower;
@@ -22,18 +24,45 @@ where
     H: 'static + StorageHasher + Sync,
     T: BorshSerialize,
 {
-    state.write(&keys.body(), body)?;
-    state.write(&keys.seen(), tally.seen)?;
-    state.write(&keys.seen_by(), tally.seen_by.clone())?;
-    state.write(&keys.voting_power(), tally.voting_power.clone())?;
+    let write_set =
+        compute_write_set(state, keys, body, tally, already_present);
+    for (key, value) in write_set {
+        state.write_bytes(&key, value)?;
+    }
+    Ok(())
+}
+
+/// Compute the storage writes that [`write`] would perform, without
+/// mutating storage.
+///
+/// The writes are returned as pairs of storage keys and their
+/// Borsh encoded values, in the order they would be applied.
+pub fn compute_write_set<D, H, T>(
+    state: &WlState<D, H>,
+    keys: &vote_tallies::Keys<T>,
+    body: &T,
+    tally: &Tally,
+    already_present: bool,
+) -> Vec<(Key, Vec<u8>)>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+    T: BorshSerialize,
+{
+    let mut write_set = vec![
+        (keys.body(), body.serialize_to_vec()),
+        (keys.seen(), tally.seen.serialize_to_vec()),
+        (keys.seen_by(), tally.seen_by.serialize_to_vec()),
+        (keys.voting_power(), tally.voting_power.serialize_to_vec()),
+    ];
     if !already_present {
         // add the current epoch for the inserted event
-        state.write(
-            &keys.voting_started_epoch(),
-            state.in_mem().get_current_epoch().0,
-        )?;
+        write_set.push((
+            keys.voting_started_epoch(),
+            state.in_mem().get_current_epoch().0.serialize_to_vec(),
+        ));
     }
-    Ok(())
+    write_set
 }
 
 /// Delete a tally from storage, and return the associated value of
@@ -72,6 +101,49 @@ where
     Ok(opt_body)
 }
 
+/// Delete the tallies and proofs of the validator set updates of epochs
+/// older than `older_than`, which have been seen by a quorum of validators.
+/// Returns the number of validator set updates that were pruned.
+///
+/// Validator set updates that have not been seen yet are never pruned.
+pub fn prune_seen<D, H>(
+    state: &mut WlState<D, H>,
+    older_than: Epoch,
+) -> Result<usize>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let mut prunable = vec![];
+    for (key, value, _) in
+        iter_prefix(state, &vote_tallies::valset_upds_prefix())?
+    {
+        let key = Key::parse(key)
+            .wrap_err("Failed to parse a validator set update tally key")?;
+        let Some(epoch) = vote_tallies::valset_upd_epoch(&key) else {
+            continue;
+        };
+        if !vote_tallies::is_seen_key(&key) || epoch >= older_than {
+            continue;
+        }
+        let seen = bool::try_from_slice(&value).wrap_err_with(|| {
+            format!("Failed to decode the seen flag stored at {key}")
+        })?;
+        if seen {
+            prunable.push(vote_tallies::Keys::from(&epoch));
+        }
+    }
+    for keys in &prunable {
+        state.delete(&keys.body())?;
+        state.delete(&keys.seen())?;
+        state.delete(&keys.seen_by())?;
+        state.delete(&keys.voting_power())?;
+        state.delete(&keys.voting_started_epoch())?;
+        state.delete(&keys.coverage_timeline())?;
+    }
+    Ok(prunable.len())
+}
+
 pub fn read<D, H, T>(
     state: &WlState<D, H>,
     keys: &vote_tallies::Keys<T>,
@@ -105,6 +177,55 @@ where
         .context("Failed to iterate over the given storage prefix")
 }
 
+/// Iterate over all the vote tallies in storage, i.e. those of Ethereum
+/// events, bridge pool roots and validator set updates, yielding the keys
+/// of each tally along with its current value.
+pub fn iter_tallies<'a, D, H>(
+    state: &'a WlState<D, H>,
+) -> Result<impl Iterator<Item = Result<(vote_tallies::Keys<()>, Tally)>> + 'a>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let prefixes = [
+        vote_tallies::eth_msgs_prefix(),
+        vote_tallies::bridge_pool_roots_prefix(),
+        vote_tallies::valset_upds_prefix(),
+    ];
+    let iters = prefixes
+        .iter()
+        .map(|prefix| iter_prefix(state, prefix))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(iters
+        .into_iter()
+        .flatten()
+        .filter_map(|(key, _, _)| {
+            let key = Key::parse(key).expect("The key should be parsable");
+            vote_tallies::tally_keys_from_seen_key(&key)
+        })
+        .map(move |keys| {
+            let tally = read(state, &keys)?;
+            Ok((keys, tally))
+        }))
+}
+
+/// Iterate over the validators that have voted on a tally, along with the
+/// block height of their vote, in ascending order of their addresses.
+///
+/// The `seen_by` map of a tally is stored under a single key, so it is
+/// decoded in full before being iterated over.
+pub fn iter_seen_by<D, H, T>(
+    state: &WlState<D, H>,
+    keys: &vote_tallies::Keys<T>,
+) -> Result<impl Iterator<Item = (Address, BlockHeight)>>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
+    H: 'static + StorageHasher + Sync,
+{
+    let seen_by: Votes = super::read::value(state, &keys.seen_by())?;
+    Ok(seen_by.into_iter())
+}
+
 #[inline]
 pub fn read_body<D, H, T>(
     state: &WlState<D, H>,
@@ -136,9 +257,12 @@ mod tests {
     use std::collections::BTreeMap;
 
     use assert_matches::assert_matches;
+    use namada_core::address;
     use namada_core::ethereum_events::EthereumEvent;
+    use namada_vote_ext::validator_set_update::VotingPowersMap;
 
     use super::*;
+    use crate::storage::proof::EthereumProof;
     use crate::test_utils::{self, GovStore};
 
     #[test]
@@ -253,4 +377,170 @@ mod tests {
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), tally);
     }
+
+    #[test]
+    fn test_iter_tallies() {
+        let (mut state, _) = test_utils::setup_default_storage();
+        let (validator, validator_voting_power) =
+            test_utils::default_validator();
+        let tally = Tally {
+            voting_power: EpochedVotingPower::from([(
+                0.into(),
+                validator_voting_power,
+            )]),
+            seen_by: BTreeMap::from([(validator, 10.into())]),
+            seen: false,
+        };
+
+        let event = EthereumEvent::TransfersToNamada {
+            nonce: 0.into(),
+            transfers: vec![],
+        };
+        let event_keys = vote_tallies::Keys::from(&event);
+        write(&mut state, &event_keys, &event, &tally, false).unwrap();
+
+        let proof = EthereumProof::new(VotingPowersMap::new());
+        let valset_upd_keys = vote_tallies::Keys::from(&Epoch(1));
+        write(&mut state, &valset_upd_keys, &proof, &tally, false).unwrap();
+
+        let tallies: Vec<_> = iter_tallies(&state)
+            .unwrap()
+            .map(|result| {
+                let (keys, tally) = result.unwrap();
+                (keys.prefix, tally)
+            })
+            .collect();
+        assert_eq!(
+            tallies,
+            vec![
+                (event_keys.prefix, tally.clone()),
+                (valset_upd_keys.prefix, tally),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_iter_seen_by() {
+        let (mut state, _) = test_utils::setup_default_storage();
+        let (_, validator_voting_power) = test_utils::default_validator();
+        let tally = Tally {
+            voting_power: EpochedVotingPower::from([(
+                0.into(),
+                validator_voting_power,
+            )]),
+            seen_by: BTreeMap::from([
+                (address::testing::established_address_1(), 10.into()),
+                (address::testing::established_address_2(), 11.into()),
+                (address::testing::established_address_3(), 12.into()),
+            ]),
+            seen: false,
+        };
+        let event = EthereumEvent::TransfersToNamada {
+            nonce: 0.into(),
+            transfers: vec![],
+        };
+        let keys = vote_tallies::Keys::from(&event);
+        write(&mut state, &keys, &event, &tally, false).unwrap();
+
+        let seen_by: Vec<_> = iter_seen_by(&state, &keys).unwrap().collect();
+        assert_eq!(seen_by, tally.seen_by.into_iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_compute_write_set() {
+        let (mut state, _) = test_utils::setup_default_storage();
+        let (validator, validator_voting_power) =
+            test_utils::default_validator();
+        let proof = EthereumProof::new(VotingPowersMap::new());
+        let keys = vote_tallies::Keys::from(&Epoch(1));
+        let tally = Tally {
+            voting_power: EpochedVotingPower::from([(
+                0.into(),
+                validator_voting_power,
+            )]),
+            seen_by: BTreeMap::from([(validator, 10.into())]),
+            seen: false,
+        };
+
+        let write_set = compute_write_set(&state, &keys, &proof, &tally, false);
+
+        // computing the write set does not touch storage
+        assert!(!state.has_key(&keys.body()).unwrap());
+        assert_eq!(
+            write_set.iter().map(|(key, _)| key).collect::<Vec<_>>(),
+            vec![
+                &keys.body(),
+                &keys.seen(),
+                &keys.seen_by(),
+                &keys.voting_power(),
+                &keys.voting_started_epoch(),
+            ]
+        );
+
+        // the write set matches what is persisted in storage
+        write(&mut state, &keys, &proof, &tally, false).unwrap();
+        for (key, value) in &write_set {
+            assert_eq!(state.read_bytes(key).unwrap().as_ref(), Some(value));
+        }
+
+        // the voting start epoch is not rewritten for existing tallies
+        let write_set = compute_write_set(&state, &keys, &proof, &tally, true);
+        assert!(
+            write_set
+                .iter()
+                .all(|(key, _)| key != &keys.voting_started_epoch())
+        );
+    }
+
+    #[test]
+    fn test_prune_seen_valset_upds() {
+        let (mut state, _) = test_utils::setup_default_storage();
+        let (validator, validator_voting_power) =
+            test_utils::default_validator();
+        let proof = EthereumProof::new(VotingPowersMap::new());
+        let tally = |seen| Tally {
+            voting_power: EpochedVotingPower::from([(
+                0.into(),
+                validator_voting_power,
+            )]),
+            seen_by: BTreeMap::from([(validator.clone(), 10.into())]),
+            seen,
+        };
+        for (epoch, seen) in
+            [(Epoch(1), true), (Epoch(2), false), (Epoch(3), true)]
+        {
+            let keys = vote_tallies::Keys::from(&epoch);
+            write(&mut state, &keys, &proof, &tally(seen), false).unwrap();
+        }
+
+        let pruned = prune_seen(&mut state, Epoch(3)).unwrap();
+        assert_eq!(pruned, 1);
+
+        // only the seen valset update older than the bound was pruned
+        let keys = vote_tallies::Keys::from(&Epoch(1));
+        for key in &keys {
+            assert!(!state.has_key(&key).unwrap());
+        }
+        for epoch in [Epoch(2), Epoch(3)] {
+            let keys = vote_tallies::Keys::from(&epoch);
+            for key in &keys {
+                assert!(state.has_key(&key).unwrap());
+            }
+        }
+
+        // pruning again is a no-op
+        assert_eq!(prune_seen(&mut state, Epoch(3)).unwrap(), 0);
+    }
+
+    /// Test that pruning fails, rather than panics, if the seen flag of a
+    /// validator set update cannot be decoded.
+    #[test]
+    fn test_prune_seen_invalid_flag() {
+        let (mut state, _) = test_utils::setup_default_storage();
+        let keys = vote_tallies::Keys::from(&Epoch(1));
+        state.write_bytes(&keys.seen(), [2u8, 3]).unwrap();
+
+        assert!(prune_seen(&mut state, Epoch(3)).is_err());
+        assert!(state.has_key(&keys.seen()).unwrap());
+    }
 }
@@ -9,7 +9,9 @@ use namada_core::token;
 use namada_state::{DBIter, StorageHasher, StorageRead, WlState, DB};
 use namada_systems::governance;
 
-use super::{ChangedKeys, EpochedVotingPowerExt, Tally, Votes};
+use super::{
+    accumulate_voting_power, ChangedKeys, EpochedVotingPowerExt, Tally, Votes,
+};
 use crate::storage::vote_tallies;
 
 /// Wraps all the information about new votes to be applied to some existing
@@ -28,20 +30,42 @@ impl NewVotes {
         votes: Votes,
         voting_powers: &HashMap<(Address, BlockHeight), token::Amount>,
     ) -> Result<Self> {
-        let mut inner = HashMap::default();
-        for vote in votes {
-            let voting_power = match voting_powers.get(&vote) {
-                Some(voting_power) => voting_power,
-                None => {
+        let weighted_votes = votes
+            .into_iter()
+            .map(|vote| {
+                let Some(voting_power) = voting_powers.get(&vote) else {
                     let (address, block_height) = vote;
                     return Err(eyre!(
                         "No voting power provided for vote by validator \
                          {address} at block height {block_height}"
                     ));
-                }
-            };
-            let (address, block_height) = vote;
-            _ = inner.insert(address, (block_height, voting_power.to_owned()));
+                };
+                let (address, block_height) = vote;
+                Ok((address, block_height, voting_power.to_owned()))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Self::from_weighted(weighted_votes)
+    }
+
+    /// Constructs a new [`NewVotes`] from votes which have already been
+    /// weighted by the voting power of their respective validators.
+    ///
+    /// An error will be returned if a validator votes more than once.
+    /// Like with [`NewVotes::new`], votes with zero voting power are
+    /// accepted, and simply add nothing to a tally.
+    pub fn from_weighted(
+        votes: impl IntoIterator<Item = (Address, BlockHeight, token::Amount)>,
+    ) -> Result<Self> {
+        let mut inner = HashMap::default();
+        for (address, block_height, voting_power) in votes {
+            if let Some((already_voted_height, _)) =
+                inner.insert(address.clone(), (block_height, voting_power))
+            {
+                return Err(eyre!(
+                    "Validator {address} voted at block heights \
+                     {already_voted_height} and {block_height}"
+                ));
+            }
         }
         Ok(Self { inner })
     }
@@ -87,14 +111,15 @@ impl IntoIterator for NewVotes {
 
 /// Calculate an updated [`Tally`] based on one that is in storage under `keys`,
 /// with new votes from `vote_info` applied, as well as the storage keys that
-/// would change. If [`Tally`] is already `seen = true` in storage, then no
-/// votes from `vote_info` should be applied, and the returned changed keys will
-/// be empty.
+/// would change and the validators whose votes were first recorded by this
+/// update. If [`Tally`] is already `seen = true` in storage, then no votes
+/// from `vote_info` should be applied, and the returned changed keys and new
+/// voters will be empty.
 pub(in super::super) fn calculate<D, H, Gov, T>(
     state: &mut WlState<D, H>,
     keys: &vote_tallies::Keys<T>,
     vote_info: NewVotes,
-) -> Result<(Tally, ChangedKeys)>
+) -> Result<(Tally, ChangedKeys, BTreeSet<Address>)>
 where
     D: 'static + DB + for<'iter> DBIter<'iter> + Sync,
     H: 'static + StorageHasher + Sync,
@@ -108,7 +133,7 @@ where
     );
     let tally_pre = super::storage::read(state, keys)?;
     if tally_pre.seen {
-        return Ok((tally_pre, ChangedKeys::default()));
+        return Ok((tally_pre, ChangedKeys::default(), BTreeSet::new()));
     }
 
     let (vote_info, duplicate_voters) =
@@ -120,8 +145,15 @@ where
             "Ignoring duplicate voter"
         );
     }
-    let tally_post = apply::<D, H, Gov>(state, &tally_pre, vote_info)
-        .expect("We deduplicated voters already, so this should never error");
+    let new_voters = vote_info.voters();
+    // NB: we deduplicated voters already, so this can only error if the
+    // aggregated voting power overflows
+    let tally_post = apply::<D, H, Gov>(state, &tally_pre, vote_info)?;
+    let monotonicity = check_monotonicity(&tally_pre, &tally_post);
+    debug_assert!(
+        monotonicity.is_ok(),
+        "Vote tally update is not monotonic: {monotonicity:?}"
+    );
 
     let changed_keys = keys_changed(keys, &tally_pre, &tally_post);
 
@@ -142,7 +174,7 @@ where
         ?tally_post,
         "Calculated and validated vote tracking updates",
     );
-    Ok((tally_post, changed_keys))
+    Ok((tally_post, changed_keys, new_voters))
 }
 
 /// Takes an existing [`Tally`] and calculates the new [`Tally`] based on new
@@ -174,12 +206,12 @@ where
             .get_epoch_at_height(vote_height)
             .unwrap()
             .expect("The queried epoch should be known");
-        let aggregated = voting_power_post
-            .entry(epoch)
-            .or_insert_with(token::Amount::zero);
-        *aggregated = aggregated
-            .checked_add(voting_power)
-            .ok_or_else(|| eyre!("Aggregated voting power overflow"))?;
+        accumulate_voting_power(
+            &mut voting_power_post,
+            &validator,
+            epoch,
+            voting_power,
+        )?;
     }
 
     let seen_post = voting_power_post.has_majority_quorum::<D, H, Gov>(state);
@@ -191,6 +223,26 @@ where
     })
 }
 
+/// Checks that the [`Tally`] `post` does not regress from `pre`, i.e. that the
+/// voting power aggregated in each epoch does not decrease, and that a tally
+/// which has been seen is still seen.
+fn check_monotonicity(pre: &Tally, post: &Tally) -> Result<()> {
+    for (epoch, voting_power_pre) in &pre.voting_power {
+        let voting_power_post =
+            post.voting_power.get(epoch).copied().unwrap_or_default();
+        if voting_power_post < *voting_power_pre {
+            return Err(eyre!(
+                "Voting power at epoch {epoch} decreased from \
+                 {voting_power_pre} to {voting_power_post}"
+            ));
+        }
+    }
+    if pre.seen && !post.seen {
+        return Err(eyre!("Tally was seen, but is no longer seen"));
+    }
+    Ok(())
+}
+
 /// Straightforwardly calculates the keys that changed between `pre` and `post`.
 fn keys_changed<T>(
     keys: &vote_tallies::Keys<T>,
@@ -343,6 +395,71 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_vote_info_new_zero_voting_power() -> Result<()> {
+        let vote =
+            (address::testing::established_address_1(), BlockHeight(100));
+        let votes = Votes::from([vote.clone()]);
+        let voting_powers = HashMap::from([(vote, token::Amount::zero())]);
+
+        let vote_info = NewVotes::new(votes, &voting_powers)?;
+
+        assert_eq!(
+            vote_info.voters(),
+            BTreeSet::from([address::testing::established_address_1()])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_vote_info_from_weighted() -> Result<()> {
+        let validator_1 = address::testing::established_address_1();
+        let validator_2 = address::testing::established_address_2();
+        let vote_height = BlockHeight(100);
+        let voting_power =
+            FractionalVotingPower::ONE_THIRD * default_total_stake();
+
+        // pre-weighted votes match those looked up from a map
+        let votes = Votes::from([
+            (validator_1.clone(), vote_height),
+            (validator_2.clone(), vote_height),
+        ]);
+        let voting_powers = HashMap::from([
+            ((validator_1.clone(), vote_height), voting_power),
+            ((validator_2.clone(), vote_height), voting_power),
+        ]);
+        let expected: BTreeSet<_> =
+            NewVotes::new(votes, &voting_powers)?.into_iter().collect();
+        let vote_info = NewVotes::from_weighted([
+            (validator_1.clone(), vote_height, voting_power),
+            (validator_2.clone(), vote_height, voting_power),
+        ])?;
+        assert_eq!(
+            vote_info.voters(),
+            BTreeSet::from([validator_1.clone(), validator_2.clone()])
+        );
+        let votes: BTreeSet<_> = vote_info.into_iter().collect();
+        assert_eq!(votes, expected);
+
+        // zero voting power is accepted, like when looked up from a map
+        let vote_info = NewVotes::from_weighted([
+            (validator_1.clone(), vote_height, voting_power),
+            (validator_2.clone(), vote_height, token::Amount::zero()),
+        ])?;
+        assert_eq!(
+            vote_info.voters(),
+            BTreeSet::from([validator_1.clone(), validator_2])
+        );
+
+        // duplicate voters are rejected
+        let result = NewVotes::from_weighted([
+            (validator_1.clone(), vote_height, voting_power),
+            (validator_1, BlockHeight(101), voting_power),
+        ]);
+        assert!(result.is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_vote_info_without_voters() -> Result<()> {
         let validator = address::testing::established_address_1();
@@ -445,11 +562,12 @@ mod tests {
         let voting_powers = HashMap::from([(vote, voting_power)]);
         let vote_info = NewVotes::new(votes, &voting_powers)?;
 
-        let (tally_post, changed_keys) =
+        let (tally_post, changed_keys, new_voters) =
             calculate::<_, _, GovStore<_>, _>(&mut state, &keys, vote_info)?;
 
         assert_eq!(tally_post, tally_pre);
         assert!(changed_keys.is_empty());
+        assert!(new_voters.is_empty());
         Ok(())
     }
 
@@ -472,11 +590,12 @@ mod tests {
         .setup()?;
         let vote_info = NewVotes::new(Votes::default(), &HashMap::default())?;
 
-        let (tally_post, changed_keys) =
+        let (tally_post, changed_keys, new_voters) =
             calculate::<_, _, GovStore<_>, _>(&mut state, &keys, vote_info)?;
 
         assert_eq!(tally_post, tally_pre);
         assert!(changed_keys.is_empty());
+        assert!(new_voters.is_empty());
         Ok(())
     }
 
@@ -509,7 +628,7 @@ mod tests {
         let voting_powers = HashMap::from([(vote.clone(), voting_power)]);
         let vote_info = NewVotes::new(votes, &voting_powers)?;
 
-        let (tally_post, changed_keys) =
+        let (tally_post, changed_keys, new_voters) =
             calculate::<_, _, GovStore<_>, _>(&mut state, &keys, vote_info)?;
 
         assert_eq!(
@@ -529,6 +648,10 @@ mod tests {
             changed_keys,
             BTreeSet::from([keys.voting_power(), keys.seen_by()])
         );
+        assert_eq!(
+            new_voters,
+            BTreeSet::from([address::testing::established_address_2()])
+        );
         Ok(())
     }
 
@@ -565,7 +688,7 @@ mod tests {
         let voting_powers = HashMap::from([(vote.clone(), second_vote_stake)]);
         let vote_info = NewVotes::new(votes, &voting_powers)?;
 
-        let (tally_post, changed_keys) =
+        let (tally_post, changed_keys, new_voters) =
             calculate::<_, _, GovStore<_>, _>(&mut state, &keys, vote_info)?;
 
         assert_eq!(
@@ -583,6 +706,53 @@ mod tests {
             changed_keys,
             BTreeSet::from([keys.voting_power(), keys.seen_by(), keys.seen()])
         );
+        assert_eq!(
+            new_voters,
+            BTreeSet::from([address::testing::established_address_2()])
+        );
+        Ok(())
+    }
+
+    /// Tests that validators which had already voted on a tally are not
+    /// reported as new voters.
+    #[test]
+    fn test_calculate_new_voters_exclude_duplicates() -> Result<()> {
+        let (mut state, _) = test_utils::setup_default_storage();
+
+        let event = default_event();
+        let keys = vote_tallies::Keys::from(&event);
+        let _tally_pre = TallyParams {
+            total_stake: default_total_stake(),
+            state: &mut state,
+            event: &event,
+            votes: HashSet::from([(
+                address::testing::established_address_1(),
+                BlockHeight(10),
+                FractionalVotingPower::ONE_THIRD * default_total_stake(),
+            )]),
+        }
+        .setup()?;
+
+        let voting_power =
+            FractionalVotingPower::new_u64(1, 4)? * default_total_stake();
+        let duplicate_vote =
+            (address::testing::established_address_1(), BlockHeight(100));
+        let new_vote =
+            (address::testing::established_address_2(), BlockHeight(100));
+        let votes = Votes::from([duplicate_vote.clone(), new_vote.clone()]);
+        let voting_powers = HashMap::from([
+            (duplicate_vote, voting_power),
+            (new_vote, voting_power),
+        ]);
+        let vote_info = NewVotes::new(votes, &voting_powers)?;
+
+        let (_, _, new_voters) =
+            calculate::<_, _, GovStore<_>, _>(&mut state, &keys, vote_info)?;
+
+        assert_eq!(
+            new_voters,
+            BTreeSet::from([address::testing::established_address_2()])
+        );
         Ok(())
     }
 
@@ -654,4 +824,44 @@ mod tests {
     fn get_epoched_voting_power(thus_far: token::Amount) -> EpochedVotingPower {
         EpochedVotingPower::from([(0.into(), thus_far)])
     }
+
+    /// Test that tally updates which decrease the voting power or unset the
+    /// seen flag are caught, while those which increase it are not.
+    #[test]
+    fn test_check_monotonicity() {
+        let pre = Tally {
+            voting_power: get_epoched_voting_power(
+                FractionalVotingPower::ONE_THIRD * default_total_stake(),
+            ),
+            seen_by: BTreeMap::from([(
+                address::testing::established_address_1(),
+                BlockHeight(10),
+            )]),
+            seen: false,
+        };
+
+        let increased = Tally {
+            voting_power: get_epoched_voting_power(
+                FractionalVotingPower::TWO_THIRDS * default_total_stake(),
+            ),
+            ..pre.clone()
+        };
+        assert!(check_monotonicity(&pre, &increased).is_ok());
+
+        let decreased = Tally {
+            voting_power: get_epoched_voting_power(token::Amount::zero()),
+            ..pre.clone()
+        };
+        assert!(check_monotonicity(&pre, &decreased).is_err());
+
+        let seen = Tally {
+            seen: true,
+            ..increased.clone()
+        };
+        let unseen = Tally {
+            seen: false,
+            ..increased
+        };
+        assert!(check_monotonicity(&seen, &unseen).is_err());
+    }
 }
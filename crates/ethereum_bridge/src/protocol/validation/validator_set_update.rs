@@ -1,10 +1,13 @@
 //! Validator set update validation.
 
+use namada_core::address::Address;
 use namada_core::chain::Epoch;
 use namada_proof_of_stake::queries::get_validator_eth_hot_key;
 use namada_state::{DBIter, StorageHasher, WlState, DB};
 use namada_systems::governance;
+use namada_tx::Signed;
 use namada_vote_ext::validator_set_update;
+use thiserror::Error;
 
 use super::VoteExtensionError;
 use crate::storage::eth_bridge_queries::{
@@ -149,6 +152,84 @@ where
     Ok(())
 }
 
+/// Errors from verifying the signatures of a
+/// [`validator_set_update::VextDigest`].
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum VextVerifyError {
+    /// The Ethereum hot key of a signer is not in storage
+    #[error(
+        "The Ethereum hot key of validator {0} could not be found in storage"
+    )]
+    PubKeyNotInStorage(Address),
+    /// The signature of a signer is invalid
+    #[error(
+        "The signature of validator {0} over the validator set update is \
+         invalid"
+    )]
+    InvalidSignature(Address),
+}
+
+/// Extension methods for [`validator_set_update::VextDigest`].
+pub trait VextDigestExt {
+    /// Verify every signature in the digest against the Ethereum hot key
+    /// of its signer at `signing_epoch`, over the voting powers of the
+    /// digest. This allows rejecting a bad digest before mutating any
+    /// vote tally in storage.
+    fn verify_signatures<D, H, Gov>(
+        &self,
+        state: &WlState<D, H>,
+        signing_epoch: Epoch,
+    ) -> Result<(), VextVerifyError>
+    where
+        D: 'static + DB + for<'iter> DBIter<'iter>,
+        H: 'static + StorageHasher,
+        Gov: governance::Read<WlState<D, H>>;
+}
+
+impl VextDigestExt for validator_set_update::VextDigest {
+    fn verify_signatures<D, H, Gov>(
+        &self,
+        state: &WlState<D, H>,
+        signing_epoch: Epoch,
+    ) -> Result<(), VextVerifyError>
+    where
+        D: 'static + DB + for<'iter> DBIter<'iter>,
+        H: 'static + StorageHasher,
+        Gov: governance::Read<WlState<D, H>>,
+    {
+        for (validator, sig) in self.signatures.iter() {
+            let pk = get_validator_eth_hot_key::<_, Gov>(
+                state,
+                validator,
+                signing_epoch,
+            )
+            .ok()
+            .flatten()
+            .ok_or_else(|| {
+                VextVerifyError::PubKeyNotInStorage(validator.clone())
+            })?;
+            let ext = validator_set_update::SignedVext(Signed::new_from(
+                validator_set_update::Vext {
+                    voting_powers: self.voting_powers.clone(),
+                    validator_addr: validator.clone(),
+                    signing_epoch,
+                },
+                sig.clone(),
+            ));
+            ext.verify(&pk).map_err(|err| {
+                tracing::debug!(
+                    ?err,
+                    %validator,
+                    "Failed to verify the signature of a validator in a \
+                     valset upd digest"
+                );
+                VextVerifyError::InvalidSignature(validator.clone())
+            })?;
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use assert_matches::assert_matches;
@@ -231,4 +312,63 @@ mod tests {
             Err(VoteExtensionError::ExtraValidatorsInExtension)
         );
     }
+
+    /// Test that the signatures of a validator set update digest are
+    /// verified against the Ethereum hot keys of its signers.
+    #[test]
+    fn test_verify_valset_upd_digest_signatures() {
+        let (state, keys) = test_utils::setup_default_storage();
+        let (validator, _) = test_utils::default_validator();
+        let signing_epoch = Epoch(0);
+        let eth_bridge_key =
+            &keys.get(&validator).expect("Test failed").eth_bridge;
+
+        let ext = validator_set_update::Vext {
+            voting_powers: VotingPowersMap::new(),
+            signing_epoch,
+            validator_addr: validator.clone(),
+        };
+        let digest = validator_set_update::VextDigest::singleton(
+            ext.sign(eth_bridge_key),
+        );
+        assert_eq!(
+            digest
+                .verify_signatures::<_, _, GovStore<_>>(&state, signing_epoch),
+            Ok(())
+        );
+
+        // a signature over a different epoch is invalid
+        assert_eq!(
+            digest.verify_signatures::<_, _, GovStore<_>>(
+                &state,
+                signing_epoch.next()
+            ),
+            Err(VextVerifyError::InvalidSignature(validator.clone()))
+        );
+
+        // a signature with a key other than the validator's hot key is
+        // invalid
+        let bad_digest = validator_set_update::VextDigest::singleton(
+            ext.sign(&keys.get(&validator).expect("Test failed").eth_gov),
+        );
+        assert_eq!(
+            bad_digest
+                .verify_signatures::<_, _, GovStore<_>>(&state, signing_epoch),
+            Err(VextVerifyError::InvalidSignature(validator.clone()))
+        );
+
+        // signers must have an Ethereum hot key in storage
+        let stranger = namada_core::address::testing::established_address_2();
+        let mut unknown_digest = digest.clone();
+        let sig = unknown_digest
+            .signatures
+            .swap_remove(&validator)
+            .expect("Test failed");
+        unknown_digest.signatures.insert(stranger.clone(), sig);
+        assert_eq!(
+            unknown_digest
+                .verify_signatures::<_, _, GovStore<_>>(&state, signing_epoch),
+            Err(VextVerifyError::PubKeyNotInStorage(stranger))
+        );
+    }
 }
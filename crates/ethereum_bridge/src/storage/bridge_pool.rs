@@ -1,13 +1,21 @@
 //! Tools for accessing the storage subspaces of the Ethereum
 //! bridge pool
 
+use namada_core::chain::BlockHeight;
 use namada_core::eth_bridge_pool::Segments;
 pub use namada_core::eth_bridge_pool::{
     get_key_from_hash, get_pending_key, is_pending_transfer_key,
     BRIDGE_POOL_ADDRESS,
 };
+use namada_core::keccak::KeccakHash;
 use namada_core::storage::{DbKeySeg, Key};
-pub use namada_state::merkle_tree::eth_bridge_pool::BridgePoolTree;
+pub use namada_state::merkle_tree::eth_bridge_pool::{
+    BridgePoolProof, BridgePoolTree,
+};
+use namada_state::{
+    DBIter, MembershipProof, ResultExt, StorageHasher, StorageRead, StoreType,
+    WlState, DB,
+};
 
 /// Get the storage key for the root of the Merkle tree
 /// containing the transfers in the pool
@@ -35,3 +43,149 @@ pub fn get_nonce_key() -> Key {
 pub fn is_bridge_pool_key(key: &Key) -> bool {
     matches!(&key.segments[0], DbKeySeg::AddressSeg(addr) if addr == &BRIDGE_POOL_ADDRESS)
 }
+
+/// Generate a Merkle proof for the inclusion of the transfers with
+/// the given hashes in the Ethereum bridge pool, as committed to by
+/// the pool root at `root_height`. The transfers must still be
+/// pending in the pool, otherwise no proof is generated for them.
+///
+/// The returned [`BridgePoolProof`] can be ABI encoded, with
+/// [`namada_core::eth_abi::Encode`], into the form verified by
+/// the Ethereum bridge smart contracts.
+pub fn generate_bridge_pool_proof<D, H>(
+    state: &WlState<D, H>,
+    transfers: &[KeccakHash],
+    root_height: BlockHeight,
+) -> namada_storage::Result<BridgePoolProof>
+where
+    D: 'static + DB + for<'iter> DBIter<'iter>,
+    H: 'static + StorageHasher,
+{
+    // get the merkle tree of the pool at the requested height
+    let tree =
+        state.get_merkle_tree(root_height, Some(StoreType::BridgePool))?;
+
+    // from the hashes of the transfers, get the actual values
+    let mut keys = Vec::with_capacity(transfers.len());
+    let mut values = Vec::with_capacity(transfers.len());
+    let mut missing_hashes = vec![];
+    for hash in transfers {
+        let key = get_key_from_hash(hash);
+        match StorageRead::read_bytes(state, &key)? {
+            Some(bytes) => {
+                keys.push(key);
+                values.push(bytes);
+            }
+            None => missing_hashes.push(hash),
+        }
+    }
+    if !missing_hashes.is_empty() {
+        return Err(namada_storage::Error::new_alloc(format!(
+            "One or more of the provided hashes had no corresponding transfer \
+             in the Bridge pool: {missing_hashes:?}"
+        )));
+    }
+
+    // get the membership proof
+    match tree
+        .get_sub_tree_existence_proof(
+            &keys,
+            values.iter().map(|v| v.as_slice()).collect(),
+        )
+        .into_storage_result()?
+    {
+        MembershipProof::BridgePool(proof) => Ok(proof),
+        MembershipProof::ICS23(_) => Err(namada_storage::Error::new_const(
+            "Expected a Bridge pool membership proof",
+        )),
+    }
+}
+
+#[cfg(test)]
+mod test_bridge_pool_proof {
+    use std::collections::BTreeMap;
+
+    use namada_core::address::testing::{established_address_1, nam};
+    use namada_core::eth_abi::Encode;
+    use namada_core::eth_bridge_pool::{
+        GasFee, PendingTransfer, TransferToEthereum, TransferToEthereumKind,
+    };
+    use namada_core::ethereum_events::EthAddress;
+    use namada_storage::StorageWrite;
+
+    use super::*;
+    use crate::test_utils;
+
+    /// Build a dummy [`PendingTransfer`] of the given amount.
+    fn dummy_transfer(amount: u64) -> PendingTransfer {
+        PendingTransfer {
+            transfer: TransferToEthereum {
+                kind: TransferToEthereumKind::Erc20,
+                asset: EthAddress([0; 20]),
+                recipient: EthAddress([1; 20]),
+                sender: established_address_1(),
+                amount: amount.into(),
+            },
+            gas_fee: GasFee {
+                token: nam(),
+                amount: 0.into(),
+                payer: established_address_1(),
+            },
+        }
+    }
+
+    /// Test that we can generate a proof for the only transfer
+    /// in a single-element Bridge pool, and that it matches the
+    /// ABI encoded proof expected by the smart contracts.
+    #[test]
+    fn test_single_element_pool_proof() {
+        let (mut state, _) = test_utils::setup_default_storage();
+        let transfer = dummy_transfer(1);
+        state
+            .write(&get_pending_key(&transfer), &transfer)
+            .expect("Test failed");
+        let root_height = state.in_mem().block.height;
+        state.commit_block().expect("Test failed");
+
+        let proof = generate_bridge_pool_proof(
+            &state,
+            &[transfer.keccak256()],
+            root_height,
+        )
+        .expect("Test failed");
+
+        // the root of a single-element pool is the hash of its leaf
+        assert!(proof.verify(transfer.keccak256()));
+        assert!(proof.proof.is_empty());
+        assert!(proof.flags.is_empty());
+
+        let expected = BridgePoolTree::new(
+            transfer.keccak256(),
+            BTreeMap::from([(transfer.keccak256(), root_height)]),
+        )
+        .get_membership_proof(vec![transfer])
+        .expect("Test failed");
+        assert_eq!(proof.encode().into_inner(), expected.encode().into_inner());
+    }
+
+    /// Test that generating a proof for a transfer that is not
+    /// in the Bridge pool fails.
+    #[test]
+    fn test_cannot_prove_transfer_not_in_pool() {
+        let (mut state, _) = test_utils::setup_default_storage();
+        let transfer = dummy_transfer(1);
+        state
+            .write(&get_pending_key(&transfer), &transfer)
+            .expect("Test failed");
+        let root_height = state.in_mem().block.height;
+        state.commit_block().expect("Test failed");
+
+        let missing = dummy_transfer(2);
+        let result = generate_bridge_pool_proof(
+            &state,
+            &[missing.keccak256()],
+            root_height,
+        );
+        assert!(result.is_err());
+    }
+}
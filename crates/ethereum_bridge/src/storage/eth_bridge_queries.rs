@@ -3,11 +3,13 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use namada_core::address::Address;
 use namada_core::chain::{BlockHeight, Epoch};
+use namada_core::collections::HashSet;
 use namada_core::eth_abi::Encode;
 use namada_core::eth_bridge_pool::PendingTransfer;
 use namada_core::ethereum_events::{
     EthAddress, EthereumEvent, GetEventNonce, TransferToEthereum, Uint,
 };
+use namada_core::hash::Hash;
 use namada_core::keccak::KeccakHash;
 use namada_core::storage::Key as StorageKey;
 use namada_core::voting_power::{EthBridgeVotingPower, FractionalVotingPower};
@@ -27,8 +29,15 @@ use namada_vote_ext::validator_set_update::{
     EthAddrBook, ValidatorSetArgs, VotingPowersMap, VotingPowersMapExt,
 };
 
-use crate::storage::proof::BridgePoolRootProof;
-use crate::storage::{active_key, bridge_pool, vote_tallies, whitelist};
+use crate::protocol::transactions::votes::{
+    EpochedVotingPower, EpochedVotingPowerExt,
+};
+use crate::storage::parameters::{MinValsetSigners, ValsetUpdGraceWindow};
+use crate::storage::proof::{BridgePoolRootProof, EthereumProof};
+use crate::storage::{
+    active_key, bridge_pool, min_valset_signers_key,
+    valset_upd_grace_window_key, vote_tallies, whitelist,
+};
 
 /// Check if the Ethereum Bridge has been enabled at compile time.
 pub const fn is_bridge_comptime_enabled() -> bool {
@@ -76,6 +85,27 @@ pub enum SendValsetUpd {
     AtPrevHeight,
 }
 
+/// The outcome of [`EthBridgeQueriesHook::must_send_valset_upd_reason`],
+/// detailing why a validator set update vote extension must or must not
+/// be sent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SendValsetUpdDecision {
+    /// A validator set update vote extension must be sent.
+    Send,
+    /// A validator set update vote extension must not be sent yet.
+    NotYet {
+        /// The block height at which the vote extension must be sent,
+        /// if known.
+        next_height: Option<BlockHeight>,
+    },
+    /// The block height at which the validator set update vote extension
+    /// of the current epoch had to be sent has passed.
+    WindowPassed,
+    /// The bridge is disabled, therefore validator set updates must
+    /// never be sent.
+    BridgeDisabled,
+}
+
 #[derive(
     Debug,
     Clone,
@@ -184,6 +214,42 @@ where
             .unwrap_or(false)
     }
 
+    /// Check if the [`EthereumEvent`] with the given hash has been
+    /// seen by a quorum of validators.
+    ///
+    /// Returns [`None`] if no votes have been cast on the event yet.
+    pub fn ethereum_event_seen(self, event_hash: &Hash) -> Option<bool> {
+        let eth_msg_keys =
+            vote_tallies::Keys::<EthereumEvent>::from(event_hash);
+        self.state
+            .read(&eth_msg_keys.seen())
+            .expect("Reading a value from storage should not fail")
+    }
+
+    /// Query the fraction of the voting power backing the
+    /// [`EthereumEvent`] with the given hash.
+    ///
+    /// Returns [`FractionalVotingPower::NULL`] if no votes have been
+    /// cast on the event yet.
+    pub fn ethereum_event_voting_power<Gov>(
+        self,
+        event_hash: &Hash,
+    ) -> FractionalVotingPower
+    where
+        D: Sync,
+        H: Sync,
+        Gov: governance::Read<WlState<D, H>>,
+    {
+        let eth_msg_keys =
+            vote_tallies::Keys::<EthereumEvent>::from(event_hash);
+        self.state
+            .read::<EpochedVotingPower>(&eth_msg_keys.voting_power())
+            .expect("Reading a value from storage should not fail")
+            .map_or(FractionalVotingPower::NULL, |voting_power| {
+                voting_power.fractional_stake::<_, _, Gov>(self.state)
+            })
+    }
+
     /// Check if the bridge is disabled, enabled, or
     /// scheduled to be enabled at a specified epoch.
     #[inline]
@@ -293,19 +359,92 @@ where
     /// extension at the provided [`BlockHeight`] in [`SendValsetUpd`].
     #[inline]
     pub fn must_send_valset_upd(self, can_send: SendValsetUpd) -> bool {
+        self.must_send_valset_upd_reason(can_send)
+            == SendValsetUpdDecision::Send
+    }
+
+    /// Determines if it is possible to send a validator set update vote
+    /// extension at the provided [`BlockHeight`] in [`SendValsetUpd`],
+    /// along with the reason why.
+    pub fn must_send_valset_upd_reason(
+        self,
+        can_send: SendValsetUpd,
+    ) -> SendValsetUpdDecision {
         if !is_bridge_comptime_enabled() {
             // the bridge is disabled at compile time, therefore
             // we must never submit validator set updates
-            false
-        } else if matches!(can_send, SendValsetUpd::AtPrevHeight) {
+            return SendValsetUpdDecision::BridgeDisabled;
+        }
+        if matches!(can_send, SendValsetUpd::AtPrevHeight) {
             // when checking vote extensions in Prepare
             // and ProcessProposal, we simply return true
-            true
-        } else {
-            // offset of 1 => are we at the 2nd
-            // block within the epoch?
-            self.state.is_deciding_offset_within_epoch(1)
+            return SendValsetUpdDecision::Send;
+        }
+        // offset of 1 => are we at the 2nd
+        // block within the epoch?
+        if self.state.is_deciding_offset_within_epoch(1) {
+            return SendValsetUpdDecision::Send;
         }
+        match self.state.get_offset_height_within_epoch(1) {
+            Some(send_height)
+                if self.state.get_current_decision_height() < send_height =>
+            {
+                SendValsetUpdDecision::NotYet {
+                    next_height: Some(send_height),
+                }
+            }
+            Some(_) => SendValsetUpdDecision::WindowPassed,
+            None => SendValsetUpdDecision::NotYet { next_height: None },
+        }
+    }
+
+    /// Determines if a validator set update vote extension was required to
+    /// be sent during the given [`Epoch`], as per
+    /// [`Self::must_send_valset_upd`].
+    ///
+    /// This is the case for any epoch whose 2nd block has already been
+    /// decided. Epochs that ended before reaching their 2nd block, as well
+    /// as epochs yet to reach it, legitimately had no validator set update.
+    pub fn was_valset_upd_required(self, epoch: Epoch) -> bool {
+        if !is_bridge_comptime_enabled() {
+            // the bridge is disabled at compile time, therefore
+            // we never submitted validator set updates
+            return false;
+        }
+        let pred_epochs = &self.state.in_mem().block.pred_epochs;
+        let Some(second_height) = pred_epochs
+            .get_start_height_of_epoch(epoch)
+            .and_then(|start_height| start_height.checked_add(1))
+        else {
+            return false;
+        };
+        second_height <= self.state.get_current_decision_height()
+            && pred_epochs.get_epoch(second_height) == Some(epoch)
+    }
+
+    /// Get the number of blocks past the start of an epoch during which
+    /// late validator set update votes signed in the previous epoch are
+    /// still accepted.
+    ///
+    /// If the parameter has not been written to storage, the default
+    /// [`ValsetUpdGraceWindow`] is returned.
+    pub fn get_valset_upd_grace_window(self) -> ValsetUpdGraceWindow {
+        self.state
+            .read(&valset_upd_grace_window_key())
+            .expect("Reading from storage should not fail")
+            .unwrap_or_default()
+    }
+
+    /// Get the minimum number of distinct validators that must sign a
+    /// validator set update before its proof can be considered complete.
+    ///
+    /// If the parameter has not been written to storage, the default
+    /// [`MinValsetSigners`] is returned.
+    pub fn get_min_valset_signers(self) -> MinValsetSigners {
+        self.state
+            .read(&min_valset_signers_key())
+            .expect("Reading from storage should not fail")
+            .unwrap_or_default()
     }
 
     /// For a given Namada validator, return its corresponding Ethereum bridge
@@ -392,6 +531,72 @@ where
             })
     }
 
+    /// Query which consensus validators have signed the validator set
+    /// update proof of the given [`Epoch`], and which have not.
+    ///
+    /// The proof of `epoch` is signed by the consensus validators of its
+    /// predecessor epoch. Returns the addresses of the validators that
+    /// signed the proof, followed by those missing from it. If no proof
+    /// exists in storage yet, all validators are missing.
+    pub fn valset_upd_signers<Gov>(
+        self,
+        epoch: Epoch,
+    ) -> (HashSet<Address>, HashSet<Address>)
+    where
+        Gov: governance::Read<WlState<D, H>>,
+    {
+        let mut signed = HashSet::new();
+        let mut missing = HashSet::new();
+        let Some(signing_epoch) = epoch.prev() else {
+            // there are no validator set update proofs for the first epoch
+            return (signed, missing);
+        };
+        let proof: Option<EthereumProof<VotingPowersMap>> = self
+            .state
+            .read(&vote_tallies::Keys::from(&epoch).body())
+            .expect("Reading a value from storage should not fail");
+        for (eth_addr_book, validator, _) in
+            self.get_consensus_eth_addresses::<Gov>(signing_epoch)
+        {
+            let has_signed = proof.as_ref().is_some_and(|proof| {
+                proof.signatures.contains_key(&eth_addr_book)
+            });
+            if has_signed {
+                signed.insert(validator);
+            } else {
+                missing.insert(validator);
+            }
+        }
+        (signed, missing)
+    }
+
+    /// Query the consensus validators at `to_epoch` whose Ethereum address
+    /// book differs from the one they had at `from_epoch`, returning their
+    /// old and new address books.
+    ///
+    /// Validators that had no Ethereum keys at `from_epoch` are not
+    /// considered to have rotated their keys.
+    pub fn eth_key_rotations_between<Gov>(
+        self,
+        from_epoch: Epoch,
+        to_epoch: Epoch,
+    ) -> Vec<(Address, EthAddrBook, EthAddrBook)>
+    where
+        Gov: governance::Read<WlState<D, H>>,
+    {
+        self.get_consensus_eth_addresses::<Gov>(to_epoch)
+            .filter_map(|(new_addr_book, validator, _)| {
+                let old_addr_book = self
+                    .get_eth_addr_book::<Gov>(&validator, Some(from_epoch))?;
+                (old_addr_book != new_addr_book).then_some((
+                    validator,
+                    old_addr_book,
+                    new_addr_book,
+                ))
+            })
+            .collect()
+    }
+
     /// Query a chosen [`ValidatorSetArgs`] at the given [`Epoch`].
     /// Also returns a map of each validator's voting power.
     fn get_validator_set_args<Gov, F>(
@@ -655,3 +860,245 @@ impl EthAssetMint {
         !self.erc20_amount.is_zero()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use namada_core::address::testing::{
+        established_address_1, established_address_2,
+    };
+    use namada_core::collections::HashMap;
+    use namada_core::key::RefTo;
+    use namada_core::time::DateTimeUtc;
+    use namada_state::{Epochs, LastBlock};
+    use namada_storage::StorageWrite;
+    use namada_tx::Signed;
+
+    use super::*;
+    use crate::test_utils::{self, GovStore, TestValidatorKeys};
+
+    /// Test that only the validators which rotated their Ethereum keys
+    /// between two epochs are reported.
+    #[test]
+    fn test_eth_key_rotations_between() {
+        let rotating = established_address_1();
+        let unchanged = established_address_2();
+        let (mut state, keys) =
+            test_utils::setup_storage_with_validators(HashMap::from_iter([
+                (rotating.clone(), token::Amount::native_whole(100)),
+                (unchanged.clone(), token::Amount::native_whole(100)),
+            ]));
+
+        // rotate the eth hot key of one of the validators at epoch 1
+        let new_keys = TestValidatorKeys::generate();
+        validator_eth_hot_key_handle(&rotating)
+            .set::<_, GovStore<_>>(
+                &mut state,
+                new_keys.eth_bridge.ref_to(),
+                Epoch(0),
+                1,
+            )
+            .expect("Test failed");
+
+        let old_addr_book = EthAddrBook {
+            hot_key_addr: (&keys[&rotating].eth_bridge.ref_to())
+                .try_into()
+                .expect("Test failed"),
+            cold_key_addr: (&keys[&rotating].eth_gov.ref_to())
+                .try_into()
+                .expect("Test failed"),
+        };
+        let new_addr_book = EthAddrBook {
+            hot_key_addr: (&new_keys.eth_bridge.ref_to())
+                .try_into()
+                .expect("Test failed"),
+            ..old_addr_book.clone()
+        };
+
+        let rotations = state
+            .ethbridge_queries()
+            .eth_key_rotations_between::<GovStore<_>>(Epoch(0), Epoch(1));
+        assert_eq!(rotations, vec![(rotating, old_addr_book, new_addr_book)]);
+    }
+
+    /// Test that we can tell which past epochs required a validator set
+    /// update.
+    #[test]
+    fn test_was_valset_upd_required() {
+        let (mut state, _) = test_utils::setup_default_storage();
+        // epoch 1 only lasted a single block, and we are currently
+        // deciding the 1st block of epoch 3
+        state.in_mem_mut().block.pred_epochs = Epochs {
+            first_block_heights: vec![
+                BlockHeight(1),
+                BlockHeight(3),
+                BlockHeight(4),
+                BlockHeight(6),
+            ],
+        };
+        state.in_mem_mut().last_block = Some(LastBlock {
+            height: BlockHeight(5),
+            time: DateTimeUtc::now(),
+        });
+
+        let required = |required| is_bridge_comptime_enabled() && required;
+        for (epoch, expected) in [
+            (Epoch(0), required(true)),
+            (Epoch(1), required(false)),
+            (Epoch(2), required(true)),
+            (Epoch(3), required(false)),
+            (Epoch(4), required(false)),
+        ] {
+            assert_eq!(
+                state.ethbridge_queries().was_valset_upd_required(epoch),
+                expected,
+                "Unexpected result for {epoch:?}"
+            );
+        }
+    }
+
+    /// Test querying the tally of the votes cast on an Ethereum event.
+    #[test]
+    fn test_ethereum_event_tally_queries() {
+        let (_, dummy_validator_stake) = test_utils::default_validator();
+        let (mut state, _) = test_utils::setup_default_storage();
+        let event = EthereumEvent::TransfersToNamada {
+            nonce: 0.into(),
+            transfers: vec![],
+        };
+        let event_hash = event.hash().expect("Test failed");
+
+        // no votes have been cast on the event yet
+        assert_eq!(
+            state.ethbridge_queries().ethereum_event_seen(&event_hash),
+            None
+        );
+        assert_eq!(
+            state
+                .ethbridge_queries()
+                .ethereum_event_voting_power::<GovStore<_>>(&event_hash),
+            FractionalVotingPower::NULL
+        );
+
+        // half of the stake votes on the event
+        let eth_msg_keys = vote_tallies::Keys::from(&event);
+        state
+            .write(
+                &eth_msg_keys.voting_power(),
+                EpochedVotingPower::from([(
+                    0.into(),
+                    FractionalVotingPower::HALF * dummy_validator_stake,
+                )]),
+            )
+            .expect("Test failed");
+        state
+            .write(&eth_msg_keys.seen(), false)
+            .expect("Test failed");
+
+        assert_eq!(
+            state.ethbridge_queries().ethereum_event_seen(&event_hash),
+            Some(false)
+        );
+        assert_eq!(
+            state
+                .ethbridge_queries()
+                .ethereum_event_voting_power::<GovStore<_>>(&event_hash),
+            FractionalVotingPower::HALF
+        );
+    }
+
+    /// Test the reasons reported for (not) sending a validator set update
+    /// at different block heights of an epoch.
+    #[test]
+    fn test_must_send_valset_upd_reason() {
+        let (mut state, _) = test_utils::setup_default_storage();
+        // the current epoch started at height 4
+        state.in_mem_mut().block.pred_epochs = Epochs {
+            first_block_heights: vec![BlockHeight(1), BlockHeight(4)],
+        };
+
+        for (last_height, expected) in [
+            (
+                BlockHeight(3),
+                SendValsetUpdDecision::NotYet {
+                    next_height: Some(BlockHeight(5)),
+                },
+            ),
+            (BlockHeight(4), SendValsetUpdDecision::Send),
+            (BlockHeight(5), SendValsetUpdDecision::WindowPassed),
+        ] {
+            state.in_mem_mut().last_block = Some(LastBlock {
+                height: last_height,
+                time: DateTimeUtc::now(),
+            });
+            let expected = if is_bridge_comptime_enabled() {
+                expected
+            } else {
+                SendValsetUpdDecision::BridgeDisabled
+            };
+            assert_eq!(
+                state
+                    .ethbridge_queries()
+                    .must_send_valset_upd_reason(SendValsetUpd::Now),
+                expected,
+                "Unexpected result at last height {last_height}"
+            );
+            assert_eq!(
+                state
+                    .ethbridge_queries()
+                    .must_send_valset_upd(SendValsetUpd::Now),
+                expected == SendValsetUpdDecision::Send,
+            );
+        }
+    }
+
+    /// Test that we can tell which validators have signed a validator set
+    /// update proof.
+    #[test]
+    fn test_valset_upd_signers() {
+        let signer = established_address_1();
+        let laggard = established_address_2();
+        let (mut state, keys) =
+            test_utils::setup_storage_with_validators(HashMap::from_iter([
+                (signer.clone(), token::Amount::native_whole(100)),
+                (laggard.clone(), token::Amount::native_whole(100)),
+            ]));
+        let signing_epoch = Epoch(0);
+        let epoch = signing_epoch.next();
+
+        // no proof exists in storage yet
+        let (signed, missing) = state
+            .ethbridge_queries()
+            .valset_upd_signers::<GovStore<_>>(epoch);
+        assert!(signed.is_empty());
+        assert_eq!(
+            missing,
+            HashSet::from_iter([signer.clone(), laggard.clone()])
+        );
+
+        // one of the validators signs the proof
+        let addr_book = state
+            .ethbridge_queries()
+            .get_eth_addr_book::<GovStore<_>>(&signer, Some(signing_epoch))
+            .expect("Test failed");
+        let signed_data =
+            Signed::<&'static str>::new(&keys[&signer].eth_bridge, "valset");
+        let mut proof = EthereumProof::new(VotingPowersMap::new());
+        proof.attach_signature(addr_book, signed_data.sig);
+        state
+            .write(&vote_tallies::Keys::from(&epoch).body(), proof)
+            .expect("Test failed");
+
+        let (signed, missing) = state
+            .ethbridge_queries()
+            .valset_upd_signers::<GovStore<_>>(epoch);
+        assert_eq!(signed, HashSet::from_iter([signer]));
+        assert_eq!(missing, HashSet::from_iter([laggard]));
+
+        // there are no proofs for the first epoch
+        let (signed, missing) = state
+            .ethbridge_queries()
+            .valset_upd_signers::<GovStore<_>>(Epoch(0));
+        assert!(signed.is_empty());
+        assert!(missing.is_empty());
+    }
+}
@@ -66,6 +66,16 @@ pub fn bridge_contract_key() -> Key {
     get_bridge_contract_address_key_at_addr(PARAM_ADDRESS)
 }
 
+/// Storage key for the validator set update grace window parameter.
+pub fn valset_upd_grace_window_key() -> Key {
+    get_valset_upd_grace_window_key_at_addr(PARAM_ADDRESS)
+}
+
+/// Storage key for the minimum validator set update signers parameter.
+pub fn min_valset_signers_key() -> Key {
+    get_min_valset_signers_key_at_addr(PARAM_ADDRESS)
+}
+
 #[cfg(test)]
 mod test {
     use namada_core::address;
@@ -77,6 +77,78 @@ impl From<MinimumConfirmations> for NonZeroU64 {
     }
 }
 
+/// Represents a configuration value for the number of blocks past the
+/// start of the next epoch during which late validator set update votes,
+/// signed in the previous epoch, are still accepted.
+#[derive(
+    Clone,
+    Copy,
+    Eq,
+    PartialEq,
+    Debug,
+    Deserialize,
+    Serialize,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+)]
+#[repr(transparent)]
+pub struct ValsetUpdGraceWindow(u64);
+
+impl Default for ValsetUpdGraceWindow {
+    fn default() -> Self {
+        Self(100)
+    }
+}
+
+impl From<u64> for ValsetUpdGraceWindow {
+    fn from(value: u64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<ValsetUpdGraceWindow> for u64 {
+    fn from(value: ValsetUpdGraceWindow) -> Self {
+        value.0
+    }
+}
+
+/// Represents a configuration value for the minimum number of distinct
+/// validators that must sign a validator set update, on top of the
+/// voting power quorum, before its proof can be considered complete.
+#[derive(
+    Clone,
+    Copy,
+    Eq,
+    PartialEq,
+    Debug,
+    Deserialize,
+    Serialize,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+)]
+#[repr(transparent)]
+pub struct MinValsetSigners(NonZeroU64);
+
+impl Default for MinValsetSigners {
+    fn default() -> Self {
+        Self(NonZeroU64::MIN)
+    }
+}
+
+impl From<NonZeroU64> for MinValsetSigners {
+    fn from(value: NonZeroU64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<MinValsetSigners> for NonZeroU64 {
+    fn from(value: MinValsetSigners) -> Self {
+        value.0
+    }
+}
+
 /// Represents a configuration value for the version of a contract that can be
 /// upgraded. Starts from 1.
 #[derive(
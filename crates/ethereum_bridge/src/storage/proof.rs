@@ -8,7 +8,7 @@ use namada_core::eth_abi::Encode;
 use namada_core::ethereum_events::Uint;
 use namada_core::keccak::KeccakHash;
 use namada_core::key::{common, secp256k1};
-use namada_core::{eth_abi, ethereum_structs};
+use namada_core::{eth_abi, ethereum_structs, token};
 use namada_vote_ext::validator_set_update::{
     valset_upd_toks_to_hashes, EthAddrBook, VotingPowersMap, VotingPowersMapExt,
 };
@@ -74,6 +74,59 @@ impl<T> EthereumProof<T> {
     }
 }
 
+/// Proofs over data that embeds the nonce expected by the Ethereum bridge
+/// smart contracts they are relayed to.
+///
+/// The nonce is part of the data signed by validators, such that relayers
+/// can refuse to relay a proof against stale contract state without trusting
+/// any unsigned metadata. It is read from the signed data, rather than kept
+/// in a field of its own, so the Borsh encoding of proofs is unchanged.
+pub trait SignedNonce {
+    /// Get the nonce signed by the validators in this proof.
+    fn nonce(&self) -> Uint;
+
+    /// Check if the signed nonce of this proof matches the nonce expected
+    /// by the Ethereum bridge smart contracts.
+    #[inline]
+    fn matches_nonce(&self, expected: Uint) -> bool {
+        self.nonce() == expected
+    }
+}
+
+impl SignedNonce for BridgePoolRootProof {
+    #[inline]
+    fn nonce(&self) -> Uint {
+        self.data.1
+    }
+}
+
+impl SignedNonce for EthereumProof<(Epoch, VotingPowersMap)> {
+    /// The nonce of a validator set update is the epoch of the new
+    /// validator set.
+    #[inline]
+    fn nonce(&self) -> Uint {
+        let Epoch(epoch) = self.data.0;
+        epoch.into()
+    }
+}
+
+impl EthereumProof<VotingPowersMap> {
+    /// Return the voting power covered by the signatures attached to this
+    /// [`EthereumProof`], according to its signed [`VotingPowersMap`].
+    ///
+    /// Signatures from validators not present in the signed voting powers
+    /// do not contribute any voting power. The returned amount saturates
+    /// at [`token::Amount::max`].
+    pub fn signature_coverage(&self) -> token::Amount {
+        token::Amount::sum(
+            self.signatures
+                .keys()
+                .filter_map(|addr_book| self.data.get(addr_book).copied()),
+        )
+        .unwrap_or_else(token::Amount::max)
+    }
+}
+
 /// Sort signatures based on voting powers in descending order.
 /// Puts a dummy signature in place of invalid or missing signatures.
 pub fn sort_sigs(
@@ -99,6 +152,28 @@ pub fn sort_sigs(
         .collect()
 }
 
+/// Estimate the number of bytes occupied in storage by a complete validator
+/// set update proof (i.e. an [`EthereumProof<VotingPowersMap>`]) signed by
+/// `num_validators` validators.
+pub fn estimate_valset_proof_storage(num_validators: usize) -> usize {
+    // Borsh encodes the length of a map as a `u32`
+    const MAP_LEN_SIZE: usize = 4;
+    // The hot and cold key Ethereum addresses of a validator
+    const ADDR_BOOK_SIZE: usize = 40;
+    // A 256 bit unsigned integer
+    const VOTING_POWER_SIZE: usize = 32;
+    // Each validator contributes an entry to both the signatures
+    // and the voting powers maps
+    const VALIDATOR_SIZE: usize = ADDR_BOOK_SIZE
+        + secp256k1::SIGNATURE_SIZE
+        + ADDR_BOOK_SIZE
+        + VOTING_POWER_SIZE;
+
+    num_validators
+        .saturating_mul(VALIDATOR_SIZE)
+        .saturating_add(2 * MAP_LEN_SIZE)
+}
+
 impl Encode<1> for EthereumProof<(Epoch, VotingPowersMap)> {
     fn tokenize(&self) -> [eth_abi::Token; 1] {
         let signatures = sort_sigs(&self.data.1, &self.signatures);
@@ -123,6 +198,7 @@ mod test_ethbridge_proofs {
     //! Test ethereum bridge proofs.
 
     use assert_matches::assert_matches;
+    use namada_core::borsh::BorshSerializeExt;
     use namada_core::ethereum_events::EthAddress;
     use namada_core::key;
     use namada_tx::Signed;
@@ -148,4 +224,76 @@ mod test_ethbridge_proofs {
         );
         assert!(proof.signatures.is_empty());
     }
+
+    /// Test that the estimated storage footprint of a complete validator
+    /// set update proof matches the size of an actual proof.
+    #[test]
+    fn test_estimate_valset_proof_storage() {
+        const NUM_VALIDATORS: u8 = 3;
+
+        let sk = key::testing::gen_keypair::<secp256k1::SigScheme>();
+        let sk = common::SecretKey::Secp256k1(sk);
+        let mut proof = EthereumProof::new(VotingPowersMap::new());
+        for i in 0..NUM_VALIDATORS {
+            let addr_book = EthAddrBook {
+                hot_key_addr: EthAddress([i; 20]),
+                cold_key_addr: EthAddress([i; 20]),
+            };
+            let signed = Signed::<&'static str>::new(&sk, ":)))))))");
+            proof.attach_signature(addr_book.clone(), signed.sig);
+            proof.data.insert(addr_book, u64::from(i).into());
+        }
+
+        let actual = proof.serialize_to_vec().len();
+        let estimate =
+            estimate_valset_proof_storage(usize::from(NUM_VALIDATORS));
+        assert_eq!(estimate, actual);
+    }
+
+    /// Test that the signature coverage of a validator set update proof
+    /// only accounts for signers present in its voting powers.
+    #[test]
+    fn test_signature_coverage() {
+        let sk = key::testing::gen_keypair::<secp256k1::SigScheme>();
+        let sk = common::SecretKey::Secp256k1(sk);
+        let addr_book = |i: u8| EthAddrBook {
+            hot_key_addr: EthAddress([i; 20]),
+            cold_key_addr: EthAddress([i; 20]),
+        };
+        let mut proof = EthereumProof::new(VotingPowersMap::from([
+            (addr_book(1), token::Amount::from(100u64)),
+            (addr_book(2), token::Amount::from(200u64)),
+            (addr_book(3), token::Amount::from(300u64)),
+        ]));
+        assert_eq!(proof.signature_coverage(), token::Amount::zero());
+
+        for i in [1, 3, 4] {
+            let signed = Signed::<&'static str>::new(&sk, ":)))))))");
+            proof.attach_signature(addr_book(i), signed.sig);
+        }
+        assert_eq!(proof.signature_coverage(), token::Amount::from(400u64));
+    }
+
+    /// Test that the nonce of a proof is the one signed by validators, and
+    /// that proofs are encoded as they were before nonces were exposed.
+    #[test]
+    fn test_signed_nonce() {
+        let data = (KeccakHash([1; 32]), Uint::from(2));
+        let legacy = (
+            HashMap::<EthAddrBook, secp256k1::Signature>::new(),
+            data.clone(),
+        )
+            .serialize_to_vec();
+        let proof =
+            BridgePoolRootProof::try_from_slice(&legacy).expect("Test failed");
+        assert_eq!(proof.nonce(), Uint::from(2));
+        assert!(proof.matches_nonce(Uint::from(2)));
+        assert!(!proof.matches_nonce(Uint::from(3)));
+        assert_eq!(proof.serialize_to_vec(), legacy);
+
+        let proof = EthereumProof::new((Epoch(7), VotingPowersMap::new()));
+        assert_eq!(proof.nonce(), Uint::from(7));
+        assert!(proof.matches_nonce(Uint::from(7)));
+        assert!(!proof.matches_nonce(Uint::from(6)));
+    }
 }
@@ -9,7 +9,7 @@ use namada_core::chain::{BlockHeight, Epoch};
 use namada_core::ethereum_events::{EthereumEvent, Uint};
 use namada_core::hash::Hash;
 use namada_core::keccak::{keccak_hash, KeccakHash};
-use namada_core::storage::{DbKeySeg, Key};
+use namada_core::storage::{DbKeySeg, Key, KeySeg};
 use namada_macros::{BorshDeserializer, StorageKeys};
 #[cfg(feature = "migrations")]
 use namada_migrations::*;
@@ -31,6 +31,10 @@ pub const BRIDGE_POOL_ROOT_PREFIX_KEY_SEGMENT: &str = "bp_root_and_nonce";
 /// voting power assigned to validator set updates.
 pub const VALSET_UPDS_PREFIX_KEY_SEGMENT: &str = "validator_set_updates";
 
+/// Storage sub-key of a validator set update's [`Keys`], reserved to
+/// keeping track of how its voting power coverage evolved over time.
+pub const VALSET_UPD_COVERAGE_TIMELINE_KEY_SEGMENT: &str = "coverage_timeline";
+
 /// Storage segments of [`Keys`].
 #[derive(StorageKeys)]
 pub struct KeysSegments {
@@ -148,6 +152,34 @@ pub fn eth_event_keys(storage_key: &Key) -> Option<Keys<EthereumEvent>> {
     }
 }
 
+/// Get the [`Keys`] of the vote tally whose `seen` state is stored under the
+/// given storage key. It returns None if the storage key isn't the `seen` key
+/// of a vote tally.
+pub fn tally_keys_from_seen_key(storage_key: &Key) -> Option<Keys<()>> {
+    let (prefix, last) = storage_key.split_last()?;
+    let is_tally_prefix = matches!(prefix.segments, [
+                DbKeySeg::AddressSeg(ADDRESS),
+                DbKeySeg::StringSeg(sub_space),
+                _,
+                ..,
+            ] if sub_space == ETH_MSGS_PREFIX_KEY_SEGMENT
+                || sub_space == BRIDGE_POOL_ROOT_PREFIX_KEY_SEGMENT
+                || sub_space == VALSET_UPDS_PREFIX_KEY_SEGMENT);
+    match last {
+        DbKeySeg::StringSeg(seg)
+            if is_tally_prefix && seg == KeysSegments::VALUES.seen =>
+        {
+            Some(Keys {
+                prefix: Key {
+                    segments: prefix.segments.to_vec(),
+                },
+                _phantom: std::marker::PhantomData,
+            })
+        }
+        _ => None,
+    }
+}
+
 /// Return true if the storage key is a key to store the epoch
 pub fn is_epoch_key(key: &Key) -> bool {
     matches!(&key.segments[..], [
@@ -223,8 +255,7 @@ impl From<(&BridgePoolRoot, BlockHeight)> for Keys<BridgePoolRoot> {
 
             keccak_hash(to_hash).to_string()
         };
-        let prefix = super::prefix()
-            .with_segment(BRIDGE_POOL_ROOT_PREFIX_KEY_SEGMENT.to_owned())
+        let prefix = bridge_pool_roots_prefix()
             .with_segment(root_height)
             .with_segment(hash);
         Keys {
@@ -234,6 +265,14 @@ impl From<(&BridgePoolRoot, BlockHeight)> for Keys<BridgePoolRoot> {
     }
 }
 
+/// Get the key prefix corresponding to the storage location of bridge pool
+/// roots and nonces whose "seen" state is being tracked.
+pub fn bridge_pool_roots_prefix() -> Key {
+    super::prefix()
+        .push(&BRIDGE_POOL_ROOT_PREFIX_KEY_SEGMENT.to_owned())
+        .expect("should always be able to construct this key")
+}
+
 /// Get the key prefix corresponding to the storage location of validator set
 /// updates whose "seen" state is being tracked.
 pub fn valset_upds_prefix() -> Key {
@@ -242,6 +281,33 @@ pub fn valset_upds_prefix() -> Key {
         .expect("should always be able to construct this key")
 }
 
+/// Get the [`Epoch`] of the validator set update whose data is stored
+/// under the given storage key. It returns None if the storage key isn't
+/// for a validator set update.
+pub fn valset_upd_epoch(storage_key: &Key) -> Option<Epoch> {
+    match &storage_key.segments[..] {
+        [
+            DbKeySeg::AddressSeg(ADDRESS),
+            DbKeySeg::StringSeg(prefix),
+            DbKeySeg::StringSeg(epoch),
+            ..,
+        ] if prefix == VALSET_UPDS_PREFIX_KEY_SEGMENT => {
+            Epoch::parse(epoch.clone()).ok()
+        }
+        _ => None,
+    }
+}
+
+impl Keys<EthereumProof<VotingPowersMap>> {
+    /// Get the `coverage_timeline` key - there should be a
+    /// `Vec<(BlockHeight, FractionalVotingPower)>` stored here.
+    pub fn coverage_timeline(&self) -> Key {
+        self.prefix
+            .push(&VALSET_UPD_COVERAGE_TIMELINE_KEY_SEGMENT.to_owned())
+            .expect("should always be able to construct this key")
+    }
+}
+
 impl From<&Epoch> for Keys<EthereumProof<VotingPowersMap>> {
     fn from(epoch: &Epoch) -> Self {
         let prefix = valset_upds_prefix()
@@ -350,6 +416,19 @@ mod test {
         assert_eq!(&keys.prefix.segments[..], &expected[..]);
     }
 
+    #[test]
+    fn test_valset_upd_epoch() {
+        let epoch = Epoch(3);
+        let keys: Keys<EthereumProof<VotingPowersMap>> = (&epoch).into();
+        for key in &keys {
+            assert_eq!(valset_upd_epoch(&key), Some(epoch));
+        }
+
+        let (event, _) = helpers::arbitrary_event_with_hash();
+        let keys: Keys<EthereumEvent> = (&event).into();
+        assert_eq!(valset_upd_epoch(&keys.seen()), None);
+    }
+
     #[test]
     fn test_ethereum_event_keys_from_hash() {
         let (event, hash) = helpers::arbitrary_event_with_hash();
@@ -57,6 +57,9 @@ pub struct DefaultProposal {
     pub proposal: OnChainProposal,
     /// The default proposal extra data
     pub data: Option<Vec<u8>>,
+    /// The recipient of the locked funds if the proposal passes, instead of
+    /// its author
+    pub funds_recipient: Option<Address>,
 }
 
 impl DefaultProposal {
@@ -1,11 +1,15 @@
 //! Governance transaction events.
 
 use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::str::FromStr;
 
+use namada_core::address::Address;
+use namada_core::token;
 use namada_events::extend::{EventAttributeEntry, ExtendAttributesMap};
-use namada_events::{Event, EventLevel, EventToEmit};
+use namada_events::{Event, EventError, EventLevel, EventToEmit};
 
-use crate::utils::TallyResult as GovTallyResult;
+use crate::utils::{TallyOutcome, TallyResult as GovTallyResult};
 use crate::ProposalType as GovProposalType;
 
 pub mod types {
@@ -36,6 +40,13 @@ pub mod types {
     pub const NEW_PROPOSAL: EventType =
         namada_events::event_type!(GovernanceEvent, PROPOSAL_SUBDOMAIN, "new");
 
+    /// Batch of ended proposals.
+    pub const PROPOSALS_ENDED: EventType = namada_events::event_type!(
+        GovernanceEvent,
+        PROPOSAL_SUBDOMAIN,
+        "ended"
+    );
+
     #[cfg(test)]
     mod tests {
         use super::*;
@@ -57,6 +68,11 @@ pub enum GovernanceEvent {
         /// Governance proposal kind.
         kind: ProposalEventKind,
     },
+    /// Outcomes of a batch of ended governance proposals.
+    EndedProposals {
+        /// Outcome of the tally and execution of each ended proposal.
+        outcomes: BTreeMap<u64, TallyOutcome>,
+    },
 }
 
 impl GovernanceEvent {
@@ -71,30 +87,34 @@ impl GovernanceEvent {
         }
     }
 
-    /// Create a new proposal event for default with wasm proposal
-    pub fn passed_proposal(
+    /// Event for an ended proposal, from the outcome of its tally and
+    /// execution, along with the author of the proposal, the funds it had
+    /// locked and the amount of these funds that have been refunded to its
+    /// author
+    pub fn ended_proposal(
         proposal_id: u64,
+        outcome: TallyOutcome,
         has_proposal_code: bool,
-        is_proposal_code_successful: bool,
+        author: Address,
+        funds: token::Amount,
+        refunded_amount: token::Amount,
     ) -> Self {
         Self::Proposal {
             id: proposal_id,
-            kind: ProposalEventKind::Passed {
+            kind: ProposalEventKind::Ended {
+                outcome,
                 has_proposal_code,
-                is_proposal_code_successful,
+                author,
+                funds,
+                refunded_amount,
             },
         }
     }
 
-    /// Event for a reject proposal
-    pub fn rejected_proposal(
-        proposal_id: u64,
-        has_proposal_code: bool,
-    ) -> Self {
-        Self::Proposal {
-            id: proposal_id,
-            kind: ProposalEventKind::Rejected { has_proposal_code },
-        }
+    /// Event for a batch of ended proposals, from the outcome of the tally
+    /// and execution of each of them
+    pub fn ended_proposals(outcomes: BTreeMap<u64, TallyOutcome>) -> Self {
+        Self::EndedProposals { outcomes }
     }
 }
 
@@ -106,26 +126,32 @@ pub enum ProposalEventKind {
         /// Type of proposal
         proposal_type: GovProposalType,
     },
-    /// Passed proposal
-    Passed {
-        /// Does the proposal contain code?
-        has_proposal_code: bool,
-        /// Did the proposal code run successfully?
-        is_proposal_code_successful: bool,
-    },
-    /// Rejected proposal
-    Rejected {
+    /// Ended proposal
+    Ended {
+        /// Outcome of the tally and execution of the proposal
+        outcome: TallyOutcome,
         /// Does the proposal contain code?
         has_proposal_code: bool,
+        /// Author of the proposal
+        author: Address,
+        /// Funds locked by the proposal
+        funds: token::Amount,
+        /// Amount of locked funds refunded to the author
+        refunded_amount: token::Amount,
     },
 }
 
 impl From<GovernanceEvent> for Event {
     fn from(proposal_event: GovernanceEvent) -> Self {
-        let GovernanceEvent::Proposal {
-            id: proposal_id,
-            kind,
-        } = proposal_event;
+        let (proposal_id, kind) = match proposal_event {
+            GovernanceEvent::Proposal { id, kind } => (id, kind),
+            GovernanceEvent::EndedProposals { outcomes } => {
+                let mut event =
+                    Self::new(types::PROPOSALS_ENDED, EventLevel::Block);
+                event.extend(ProposalOutcomes(outcomes));
+                return event;
+            }
+        };
 
         let (event_type, attributes) = match kind {
             ProposalEventKind::NewProposal { proposal_type } => {
@@ -136,27 +162,29 @@ impl From<GovernanceEvent> for Event {
                 );
                 (event_type, attributes)
             }
-            ProposalEventKind::Passed {
+            ProposalEventKind::Ended {
+                outcome,
                 has_proposal_code,
-                is_proposal_code_successful,
+                author,
+                funds,
+                refunded_amount,
             } => {
-                let event_type = types::PROPOSAL_PASSED;
-                let attributes = ended_governance_proposal_attributes(
-                    GovTallyResult::Passed,
+                let (event_type, tally) = if outcome.has_passed() {
+                    (types::PROPOSAL_PASSED, GovTallyResult::Passed)
+                } else {
+                    (types::PROPOSAL_REJECTED, GovTallyResult::Rejected)
+                };
+                let mut attributes = ended_governance_proposal_attributes(
+                    tally,
                     proposal_id,
                     has_proposal_code,
-                    is_proposal_code_successful,
-                );
-                (event_type, attributes)
-            }
-            ProposalEventKind::Rejected { has_proposal_code } => {
-                let event_type = types::PROPOSAL_REJECTED;
-                let attributes = ended_governance_proposal_attributes(
-                    GovTallyResult::Rejected,
-                    proposal_id,
-                    has_proposal_code,
-                    false,
+                    has_proposal_code && outcome == TallyOutcome::Passed,
                 );
+                attributes
+                    .with_attribute(ProposalTallyOutcome(outcome))
+                    .with_attribute(ProposalAuthor(author))
+                    .with_attribute(ProposalFunds(funds))
+                    .with_attribute(RefundedAmount(refunded_amount));
                 (event_type, attributes)
             }
         };
@@ -172,6 +200,49 @@ impl From<GovernanceEvent> for Event {
     }
 }
 
+impl TryFrom<&Event> for GovernanceEvent {
+    type Error = EventError;
+
+    fn try_from(event: &Event) -> Result<Self, Self::Error> {
+        let kind = event.kind();
+        if kind == &types::PROPOSALS_ENDED {
+            let ProposalOutcomesList(outcomes) =
+                event.read_attribute::<ProposalOutcomes>()?;
+            return Ok(Self::ended_proposals(outcomes));
+        }
+        if kind == &types::NEW_PROPOSAL {
+            // Only the name of the type of a new proposal is added to its
+            // event, not the data attached to it
+            return Err(EventError::AttributeEncoding(
+                "The type of a new proposal cannot be recovered from its event"
+                    .to_string(),
+            ));
+        }
+        let passed = if kind == &types::PROPOSAL_PASSED {
+            true
+        } else if kind == &types::PROPOSAL_REJECTED {
+            false
+        } else {
+            return Err(EventError::InvalidEventType);
+        };
+        let outcome = event.read_attribute::<ProposalTallyOutcome>()?;
+        if outcome.has_passed() != passed {
+            return Err(EventError::AttributeEncoding(format!(
+                "The tally outcome {outcome} does not match the event type \
+                 {kind}"
+            )));
+        }
+        Ok(Self::ended_proposal(
+            event.read_attribute::<ProposalId>()?,
+            outcome,
+            event.read_attribute::<HasProposalCode>()?,
+            event.read_attribute::<ProposalAuthor>()?,
+            event.read_attribute::<ProposalFunds>()?,
+            event.read_attribute::<RefundedAmount>()?,
+        ))
+    }
+}
+
 /// Return the attributes of a governance proposal.
 #[inline]
 fn ended_governance_proposal_attributes(
@@ -219,6 +290,21 @@ impl EventAttributeEntry<'static> for TallyResult {
     }
 }
 
+/// Extend an [`Event`] with the outcome of the tally and execution of a
+/// proposal.
+pub struct ProposalTallyOutcome(pub TallyOutcome);
+
+impl EventAttributeEntry<'static> for ProposalTallyOutcome {
+    type Value = TallyOutcome;
+    type ValueOwned = Self::Value;
+
+    const KEY: &'static str = "tally_outcome";
+
+    fn into_value(self) -> Self::Value {
+        self.0
+    }
+}
+
 /// Extend an [`Event`] with tally result data.
 pub struct ProposalType(pub GovProposalType);
 
@@ -233,6 +319,53 @@ impl EventAttributeEntry<'static> for ProposalType {
     }
 }
 
+/// Extend an [`Event`] with the outcomes of a batch of proposals, keyed by
+/// proposal id.
+pub struct ProposalOutcomes(pub BTreeMap<u64, TallyOutcome>);
+
+impl EventAttributeEntry<'static> for ProposalOutcomes {
+    type Value = ProposalOutcomesList;
+    type ValueOwned = Self::Value;
+
+    const KEY: &'static str = "proposal_outcomes";
+
+    fn into_value(self) -> Self::Value {
+        ProposalOutcomesList(self.0)
+    }
+}
+
+/// The outcomes of a batch of proposals, displayed as a JSON object mapping
+/// proposal ids to outcomes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProposalOutcomesList(pub BTreeMap<u64, TallyOutcome>);
+
+impl Display for ProposalOutcomesList {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let outcomes: BTreeMap<u64, String> = self
+            .0
+            .iter()
+            .map(|(id, outcome)| (*id, outcome.to_string()))
+            .collect();
+        let outcomes =
+            serde_json::to_string(&outcomes).map_err(|_| std::fmt::Error)?;
+        write!(f, "{outcomes}")
+    }
+}
+
+impl FromStr for ProposalOutcomesList {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let outcomes: BTreeMap<u64, String> =
+            serde_json::from_str(s).map_err(|err| err.to_string())?;
+        outcomes
+            .into_iter()
+            .map(|(id, outcome)| Ok((id, outcome.parse()?)))
+            .collect::<Result<_, String>>()
+            .map(Self)
+    }
+}
+
 /// Extend an [`Event`] with proposal id data.
 pub struct ProposalId(pub u64);
 
@@ -274,3 +407,211 @@ impl EventAttributeEntry<'static> for ProposalCodeExitStatus {
         self.0
     }
 }
+
+/// Extend an [`Event`] with proposal author data.
+pub struct ProposalAuthor(pub Address);
+
+impl EventAttributeEntry<'static> for ProposalAuthor {
+    type Value = Address;
+    type ValueOwned = Self::Value;
+
+    const KEY: &'static str = "proposal_author";
+
+    fn into_value(self) -> Self::Value {
+        self.0
+    }
+}
+
+/// Extend an [`Event`] with the amount of funds locked by a proposal.
+pub struct ProposalFunds(pub token::Amount);
+
+impl EventAttributeEntry<'static> for ProposalFunds {
+    type Value = token::Amount;
+    type ValueOwned = Self::Value;
+
+    const KEY: &'static str = "proposal_funds";
+
+    fn into_value(self) -> Self::Value {
+        self.0
+    }
+}
+
+/// Extend an [`Event`] with the amount of refunded proposal funds.
+pub struct RefundedAmount(pub token::Amount);
+
+impl EventAttributeEntry<'static> for RefundedAmount {
+    type Value = token::Amount;
+    type ValueOwned = Self::Value;
+
+    const KEY: &'static str = "refunded_amount";
+
+    fn into_value(self) -> Self::Value {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use namada_core::address::testing::established_address_1;
+
+    use super::*;
+    use crate::utils::RejectionReason;
+
+    /// Test that the author and funds of an ended proposal are added to the
+    /// attributes of its event.
+    #[test]
+    fn test_ended_proposal_event_attributes() {
+        let author = established_address_1();
+        let funds = token::Amount::native_whole(500);
+        let refunded_amount = token::Amount::native_whole(250);
+        let event: Event = GovernanceEvent::ended_proposal(
+            0,
+            TallyOutcome::QuorumNotMet,
+            false,
+            author.clone(),
+            funds,
+            refunded_amount,
+        )
+        .into();
+
+        assert_eq!(
+            event.read_attribute::<ProposalId>().expect("Test failed"),
+            0
+        );
+        assert_eq!(
+            event
+                .read_attribute::<ProposalAuthor>()
+                .expect("Test failed"),
+            author
+        );
+        assert_eq!(
+            event
+                .read_attribute::<ProposalFunds>()
+                .expect("Test failed"),
+            funds
+        );
+        assert_eq!(
+            event
+                .read_attribute::<RefundedAmount>()
+                .expect("Test failed"),
+            refunded_amount
+        );
+    }
+
+    /// Test that the outcomes of a batch of ended proposals are added to the
+    /// attributes of their event.
+    #[test]
+    fn test_ended_proposals_event_attributes() {
+        let event: Event = GovernanceEvent::ended_proposals(BTreeMap::from([
+            (0, TallyOutcome::Passed),
+            (1, TallyOutcome::QuorumNotMet),
+        ]))
+        .into();
+
+        assert_eq!(event.kind(), &types::PROPOSALS_ENDED);
+        assert_eq!(
+            event.raw_read_attribute::<ProposalOutcomes>(),
+            Some(r#"{"0":"passed","1":"quorum not met"}"#)
+        );
+    }
+
+    /// Test that ended proposal events can be parsed back from the events
+    /// they are converted to.
+    #[test]
+    fn test_ended_proposal_event_round_trip() {
+        let proposal_events = [
+            GovernanceEvent::ended_proposal(
+                0,
+                TallyOutcome::PassedExecutionFailed,
+                true,
+                established_address_1(),
+                token::Amount::native_whole(500),
+                token::Amount::native_whole(500),
+            ),
+            GovernanceEvent::ended_proposal(
+                1,
+                TallyOutcome::Rejected {
+                    reason: RejectionReason::MalformedVotingPeriod,
+                },
+                false,
+                established_address_1(),
+                token::Amount::native_whole(500),
+                token::Amount::native_whole(250),
+            ),
+            GovernanceEvent::ended_proposals(BTreeMap::from([
+                (0, TallyOutcome::Passed),
+                (1, TallyOutcome::QuorumNotMet),
+            ])),
+        ];
+        for proposal_event in proposal_events {
+            let event = Event::from(proposal_event.clone());
+            assert_eq!(
+                GovernanceEvent::try_from(&event).expect("Test failed"),
+                proposal_event
+            );
+        }
+
+        let event = Event::from(GovernanceEvent::new_proposal(
+            2,
+            GovProposalType::Default,
+        ));
+        assert!(GovernanceEvent::try_from(&event).is_err());
+    }
+
+    /// Test that the type and the tally attributes of an ended proposal event
+    /// follow from the outcome of the proposal.
+    #[test]
+    fn test_ended_proposal_event_from_outcome() {
+        let author = established_address_1();
+        let funds = token::Amount::native_whole(500);
+        let cases = [
+            (TallyOutcome::Passed, true, true),
+            (TallyOutcome::PassedExecutionFailed, true, false),
+            (
+                TallyOutcome::Rejected {
+                    reason: RejectionReason::NotEnoughYayVotes,
+                },
+                false,
+                false,
+            ),
+            (
+                TallyOutcome::Rejected {
+                    reason: RejectionReason::MalformedVotingPeriod,
+                },
+                false,
+                false,
+            ),
+            (TallyOutcome::QuorumNotMet, false, false),
+            (TallyOutcome::Error, false, false),
+        ];
+        for (outcome, passed, exit_status) in cases {
+            let event: Event = GovernanceEvent::ended_proposal(
+                0,
+                outcome,
+                true,
+                author.clone(),
+                funds,
+                token::Amount::zero(),
+            )
+            .into();
+
+            let (event_type, tally) = if passed {
+                (types::PROPOSAL_PASSED, "Passed")
+            } else {
+                (types::PROPOSAL_REJECTED, "Rejected")
+            };
+            assert_eq!(event.kind(), &event_type);
+            assert_eq!(event.raw_read_attribute::<TallyResult>(), Some(tally));
+            assert_eq!(
+                event
+                    .read_attribute::<ProposalCodeExitStatus>()
+                    .expect("Test failed"),
+                exit_status
+            );
+            assert_eq!(
+                event.raw_read_attribute::<ProposalTallyOutcome>(),
+                Some(outcome.to_string().as_str())
+            );
+        }
+    }
+}
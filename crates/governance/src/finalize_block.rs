@@ -1,16 +1,22 @@
 //! Governance logic applied on an end of a block.
 
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
+use std::ops::{Deref, DerefMut};
 
 use borsh::BorshDeserialize;
 use namada_core::address::Address;
 use namada_core::chain::Epoch;
 use namada_core::collections::HashMap;
-use namada_core::encode;
+use namada_core::dec::Dec;
 use namada_core::ibc::PGFIbcTarget;
+use namada_core::{arith, encode};
 use namada_events::extend::{ComposeEvent, Height};
 use namada_events::{EmitEvents, EventLevel};
-use namada_state::{Key, Result, State, StateRead, StorageRead, StorageWrite};
+use namada_state::write_log::StorageModification;
+use namada_state::{
+    Key, OptionExt, Result, ResultExt, State, StateRead, StorageRead,
+    StorageWrite,
+};
 use namada_systems::{proof_of_stake, trans_token as token};
 use namada_tx::data::TxType;
 use namada_tx::{Code, Data, Tx};
@@ -24,138 +30,433 @@ use crate::storage::proposal::{
 };
 use crate::storage::{keys, load_proposals};
 use crate::utils::{
-    compute_proposal_result, ProposalVotes, TallyResult, TallyType, VotePower,
+    compute_proposal_result, ProposalResult, ProposalVotes, RejectionReason,
+    TallyOutcome, TallyResult, TallyType, VotePower,
 };
 use crate::{storage, ProposalVote, ADDRESS as GOV_ADDRESS};
 
+/// The outcome of dispatching the code attached to a governance proposal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProposalCodeResult {
+    /// The code was executed and its changes were accepted
+    Accepted,
+    /// The code failed or its changes were rejected
+    Rejected,
+    /// The code exceeded the gas limit of governance proposals
+    OutOfGas,
+    /// The code exceeded the maximum size of proposal code, and was not
+    /// executed
+    Oversized,
+}
+
+/// The keys changed while executing a governance proposal, attributed either
+/// to the governance machinery or to the code attached to the proposal.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProposalChangedKeys {
+    /// Keys changed by governance itself (e.g. funds transfers and result
+    /// writes)
+    pub governance: BTreeSet<Key>,
+    /// Keys changed by the code attached to the proposal
+    pub proposal_code: BTreeSet<Key>,
+}
+
+/// The outcome of the execution of a set of governance proposals.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProposalsResult {
+    /// The keys changed while executing each proposal
+    pub changed_keys: BTreeMap<u64, ProposalChangedKeys>,
+    /// The outcome of the tally and execution of each proposal
+    pub outcomes: BTreeMap<u64, TallyOutcome>,
+    /// The ids of the passed proposals whose code was rejected, and will be
+    /// retried in the next block
+    pub pending_retry: BTreeSet<u64>,
+}
+
 /// Apply governance updates for a block. On a new epoch, this will look for
 /// proposals to tally completed proposals and execute accepted proposals.
+///
+/// The code of accepted proposals is dispatched with `dispatch_tx`, along
+/// with the maximum amount of gas (in whole gas units) that it may consume.
+/// Besides the result of the code, `dispatch_tx` returns the keys changed by
+/// the code, as recorded in the write log of the dispatched tx, or no keys if
+/// its changes were dropped. `dispatch_tx` must leave the changes of the code
+/// in the write log of the tx: they are committed if the code is accepted,
+/// and dropped otherwise.
+/// The funds locked by a proposal whose code runs out of gas, or whose code
+/// exceeds the maximum proposal code size, are burnt. The code of a proposal
+/// that is rejected may be retried in subsequent blocks, as described in
+/// [`retry_governance_proposals`].
+///
+/// Whenever the code of an executed proposal changes any key under one of
+/// the `watched_prefixes`, `on_watched_keys_changed` is called with the id of
+/// the proposal and the set of watched keys that it has changed.
+///
+/// If `batch_events` is set, a single event carrying the outcomes of all the
+/// proposals ended in the block is emitted, instead of one event per ended
+/// proposal.
 #[allow(clippy::too_many_arguments)]
-pub fn finalize_block<S, Token, PoS, FnTx, FnIbcTransfer>(
+pub fn finalize_block<S, Token, PoS, FnTx, FnIbcTransfer, FnWatch>(
     state: &mut S,
     events: &mut impl EmitEvents,
     current_epoch: Epoch,
     is_new_epoch: bool,
-    dispatch_tx: FnTx,
+    mut dispatch_tx: FnTx,
     transfer_over_ibc: FnIbcTransfer,
+    watched_prefixes: &[Key],
+    mut on_watched_keys_changed: FnWatch,
+    batch_events: bool,
 ) -> Result<()>
 where
     S: StateRead + State,
     Token: token::Read<S> + token::Write<S> + token::Events<S>,
     PoS: proof_of_stake::Read<S>,
-    FnTx: FnMut(&Tx, &mut S) -> Result<bool>,
+    FnTx:
+        FnMut(&Tx, &mut S, u64) -> Result<(ProposalCodeResult, BTreeSet<Key>)>,
     FnIbcTransfer: Fn(&mut S, &Address, &Address, &PGFIbcTarget) -> Result<()>,
+    FnWatch: FnMut(u64, &BTreeSet<Key>),
 {
+    // Retry proposals before executing new ones, so that the code of a
+    // proposal is executed at most once per block
+    let mut proposals_result = retry_governance_proposals::<S, Token, _, _>(
+        state,
+        events,
+        &mut dispatch_tx,
+        watched_prefixes,
+        &mut on_watched_keys_changed,
+        batch_events,
+    )?;
     if is_new_epoch {
-        load_and_execute_governance_proposals::<
+        let executed_result = load_and_execute_governance_proposals::<
             S,
             Token,
             PoS,
             FnTx,
             FnIbcTransfer,
-        >(state, events, current_epoch, dispatch_tx, transfer_over_ibc)?;
+            FnWatch,
+        >(
+            state,
+            events,
+            current_epoch,
+            dispatch_tx,
+            transfer_over_ibc,
+            watched_prefixes,
+            on_watched_keys_changed,
+            batch_events,
+        )?;
+        proposals_result
+            .changed_keys
+            .extend(executed_result.changed_keys);
+        proposals_result.outcomes.extend(executed_result.outcomes);
+    }
+    // The retried and newly executed proposals that ended in this block are
+    // reported together, such that at most one batched event is emitted
+    if batch_events && !proposals_result.outcomes.is_empty() {
+        events
+            .emit(GovernanceEvent::ended_proposals(proposals_result.outcomes));
+    }
+    for (id, keys) in proposals_result.changed_keys {
+        tracing::debug!(
+            "Governance proposal #{} changed keys {:?}, and its code changed \
+             keys {:?}.",
+            id,
+            keys.governance,
+            keys.proposal_code
+        );
     }
     Ok(())
 }
 
-fn load_and_execute_governance_proposals<S, Token, PoS, FnTx, FnIbcTransfer>(
+#[allow(clippy::too_many_arguments)]
+fn load_and_execute_governance_proposals<
+    S,
+    Token,
+    PoS,
+    FnTx,
+    FnIbcTransfer,
+    FnWatch,
+>(
     state: &mut S,
     events: &mut impl EmitEvents,
     current_epoch: Epoch,
     dispatch_tx: FnTx,
     transfer_over_ibc: FnIbcTransfer,
-) -> Result<()>
+    watched_prefixes: &[Key],
+    on_watched_keys_changed: FnWatch,
+    batch_events: bool,
+) -> Result<ProposalsResult>
 where
     S: StateRead + State,
     Token: token::Read<S> + token::Write<S> + token::Events<S>,
     PoS: proof_of_stake::Read<S>,
-    FnTx: FnMut(&Tx, &mut S) -> Result<bool>,
+    FnTx:
+        FnMut(&Tx, &mut S, u64) -> Result<(ProposalCodeResult, BTreeSet<Key>)>,
     FnIbcTransfer: Fn(&mut S, &Address, &Address, &PGFIbcTarget) -> Result<()>,
+    FnWatch: FnMut(u64, &BTreeSet<Key>),
 {
     let proposal_ids = load_proposals(state, current_epoch)?;
 
-    execute_governance_proposals::<S, Token, PoS, FnTx, FnIbcTransfer>(
+    execute_governance_proposals::<S, Token, PoS, FnTx, FnIbcTransfer, FnWatch>(
         state,
         events,
         proposal_ids,
         dispatch_tx,
         transfer_over_ibc,
+        watched_prefixes,
+        on_watched_keys_changed,
+        batch_events,
     )
 }
 
-fn execute_governance_proposals<S, Token, PoS, FnTx, FnIbcTransfer>(
+fn execute_governance_proposals<S, Token, PoS, FnTx, FnIbcTransfer, FnWatch>(
     state: &mut S,
     events: &mut impl EmitEvents,
     proposal_ids: BTreeSet<u64>,
     mut dispatch_tx: FnTx,
     mut transfer_over_ibc: FnIbcTransfer,
-) -> Result<()>
+    watched_prefixes: &[Key],
+    mut on_watched_keys_changed: FnWatch,
+    batch_events: bool,
+) -> Result<ProposalsResult>
 where
     S: StateRead + State,
     Token: token::Read<S> + token::Write<S> + token::Events<S>,
     PoS: proof_of_stake::Read<S>,
-    FnTx: FnMut(&Tx, &mut S) -> Result<bool>,
+    FnTx:
+        FnMut(&Tx, &mut S, u64) -> Result<(ProposalCodeResult, BTreeSet<Key>)>,
     FnIbcTransfer: Fn(&mut S, &Address, &Address, &PGFIbcTarget) -> Result<()>,
+    FnWatch: FnMut(u64, &BTreeSet<Key>),
 {
+    let mut proposals_result = ProposalsResult::default();
     for id in proposal_ids {
+        if storage::is_proposal_executed(state, id)? {
+            tracing::warn!(
+                "Governance proposal #{} has already been executed. Skipping \
+                 it.",
+                id
+            );
+            continue;
+        }
+        let modifications_before = read_modifications(state);
+        storage::write_proposal_executed(state, id)?;
         let proposal_funds_key = keys::get_funds_key(id);
+        let proposal_start_epoch_key = keys::get_voting_start_epoch_key(id);
         let proposal_end_epoch_key = keys::get_voting_end_epoch_key(id);
         let proposal_type_key = keys::get_proposal_type_key(id);
         let proposal_author_key = keys::get_author_key(id);
 
         let funds: token::Amount = force_read(state, &proposal_funds_key)?;
+        let proposal_start_epoch: Epoch =
+            force_read(state, &proposal_start_epoch_key)?;
         let proposal_end_epoch: Epoch =
             force_read(state, &proposal_end_epoch_key)?;
         let proposal_type: ProposalType =
             force_read(state, &proposal_type_key)?;
         let proposal_author: Address = force_read(state, &proposal_author_key)?;
 
-        let is_steward = pgf_storage::is_steward(state, &proposal_author)?;
+        let has_proposal_code =
+            matches!(proposal_type, ProposalType::DefaultWithWasm(_));
 
-        let total_active_voting_power = PoS::total_active_stake::<
-            crate::Store<_>,
-        >(state, proposal_end_epoch)?;
-
-        let tally_type = TallyType::from(proposal_type.clone(), is_steward);
-        let votes =
-            compute_proposal_votes::<S, PoS>(state, id, proposal_end_epoch)?;
-        let proposal_result = compute_proposal_result(
-            votes,
-            total_active_voting_power,
-            tally_type,
-        )
-        .expect("Proposal result calculation must not over/underflow");
+        if proposal_end_epoch <= proposal_start_epoch {
+            tracing::warn!(
+                "Governance proposal #{} is malformed: its voting end epoch \
+                 {} is not after its voting start epoch {}. Refunding the \
+                 locked funds to its author.",
+                id,
+                proposal_end_epoch,
+                proposal_start_epoch
+            );
+            let outcome = TallyOutcome::Rejected {
+                reason: RejectionReason::MalformedVotingPeriod,
+            };
+            emit_ended_proposal(
+                events,
+                batch_events,
+                GovernanceEvent::ended_proposal(
+                    id,
+                    outcome,
+                    has_proposal_code,
+                    proposal_author.clone(),
+                    funds,
+                    funds,
+                ),
+            );
+            refund_or_burn_proposal_funds::<S, Token>(
+                state,
+                funds,
+                Some(proposal_author),
+            )?;
+            proposals_result.outcomes.insert(id, outcome);
+            proposals_result.changed_keys.insert(
+                id,
+                ProposalChangedKeys {
+                    governance: changed_modified_keys(
+                        &modifications_before,
+                        read_modifications(state),
+                    ),
+                    proposal_code: BTreeSet::new(),
+                },
+            );
+            continue;
+        }
+
+        // Storage errors are propagated, such that only the arithmetic
+        // errors of the tally itself cause the locked funds to be burnt
+        let tally = compute_proposal_tally::<S, PoS>(
+            state,
+            id,
+            proposal_type.clone(),
+            &proposal_author,
+            proposal_end_epoch,
+        )?;
+        let (proposal_result, tally_outcome) = match tally {
+            Ok(tally) => tally,
+            Err(err) => {
+                tracing::error!(
+                    "Governance proposal #{} could not be tallied: {}. \
+                     Burning the locked funds.",
+                    id,
+                    err
+                );
+                emit_ended_proposal(
+                    events,
+                    batch_events,
+                    GovernanceEvent::ended_proposal(
+                        id,
+                        TallyOutcome::Error,
+                        has_proposal_code,
+                        proposal_author,
+                        funds,
+                        token::Amount::zero(),
+                    ),
+                );
+                refund_or_burn_proposal_funds::<S, Token>(state, funds, None)?;
+                proposals_result.outcomes.insert(id, TallyOutcome::Error);
+                proposals_result.changed_keys.insert(
+                    id,
+                    ProposalChangedKeys {
+                        governance: changed_modified_keys(
+                            &modifications_before,
+                            read_modifications(state),
+                        ),
+                        proposal_code: BTreeSet::new(),
+                    },
+                );
+                continue;
+            }
+        };
         storage::write_proposal_result(state, id, proposal_result)?;
+        if let Some(tally_fractions) = proposal_result.tally_fractions() {
+            storage::write_proposal_tally_fractions(
+                state,
+                id,
+                tally_fractions,
+            )?;
+        }
 
-        let transfer_address = match proposal_result.result {
-            TallyResult::Passed => {
-                let proposal_event = match proposal_type {
+        let mut proposal_code_keys = BTreeSet::new();
+        let (outcome, refund_address, refunded_amount) = match tally_outcome {
+            TallyOutcome::Passed | TallyOutcome::PassedExecutionFailed => {
+                let mut burn_funds = false;
+                let mut retry_scheduled = false;
+                let outcome = match proposal_type {
                     ProposalType::Default => {
                         tracing::info!(
                             "Governance proposal #{} (default) has passed.",
                             id,
                         );
 
-                        GovernanceEvent::passed_proposal(id, false, false)
+                        TallyOutcome::Passed
                     }
                     ProposalType::DefaultWithWasm(_) => {
                         let proposal_code =
                             storage::get_proposal_code(state, id)?
                                 .unwrap_or_default();
-                        let result = execute_default_proposal(
+                        let max_code_size =
+                            storage::get_max_proposal_code_size(state)?;
+                        let max_gas = storage::get_max_code_gas(
                             state,
-                            id,
-                            proposal_code.clone(),
-                            &mut dispatch_tx,
+                            proposal_end_epoch,
                         )?;
-                        tracing::info!(
-                            "Governance proposal #{} (default with wasm) has \
-                             passed and been executed, wasm execution: {}.",
+                        let max_code_retries =
+                            storage::get_max_proposal_code_retries(state)?;
+                        let (code_result, code_keys) =
+                            execute_default_proposal(
+                                state,
+                                id,
+                                proposal_code.clone(),
+                                max_code_size,
+                                max_gas,
+                                &mut dispatch_tx,
+                            )?;
+                        notify_watched_keys(
                             id,
-                            if result { "successful" } else { "unsuccessful" }
+                            &code_keys,
+                            watched_prefixes,
+                            &mut on_watched_keys_changed,
                         );
-
-                        GovernanceEvent::passed_proposal(id, true, result)
+                        proposal_code_keys = code_keys;
+                        match code_result {
+                            ProposalCodeResult::Accepted => {
+                                tracing::info!(
+                                    "Governance proposal #{} (default with \
+                                     wasm) has passed and been executed, wasm \
+                                     execution: successful.",
+                                    id,
+                                );
+                                TallyOutcome::Passed
+                            }
+                            ProposalCodeResult::Rejected
+                                if max_code_retries > 0 =>
+                            {
+                                state.write(
+                                    &keys::get_proposal_retry_count_key(id),
+                                    0_u64,
+                                )?;
+                                tracing::info!(
+                                    "Governance proposal #{} (default with \
+                                     wasm) has passed, but its wasm code has \
+                                     been rejected. Retrying it in the next \
+                                     block.",
+                                    id,
+                                );
+                                retry_scheduled = true;
+                                TallyOutcome::PassedExecutionFailed
+                            }
+                            ProposalCodeResult::Rejected => {
+                                tracing::info!(
+                                    "Governance proposal #{} (default with \
+                                     wasm) has passed and been executed, wasm \
+                                     execution: unsuccessful.",
+                                    id,
+                                );
+                                TallyOutcome::PassedExecutionFailed
+                            }
+                            ProposalCodeResult::OutOfGas => {
+                                tracing::warn!(
+                                    "Governance proposal #{} (default with \
+                                     wasm) has passed, but its wasm code \
+                                     exceeded the gas limit of {}. Burning \
+                                     the locked funds.",
+                                    id,
+                                    max_gas
+                                );
+                                burn_funds = true;
+                                TallyOutcome::PassedExecutionFailed
+                            }
+                            ProposalCodeResult::Oversized => {
+                                tracing::warn!(
+                                    "Governance proposal #{} (default with \
+                                     wasm) has passed, but its wasm code of \
+                                     {} bytes exceeds the maximum size of {} \
+                                     bytes. Burning the locked funds.",
+                                    id,
+                                    proposal_code.len(),
+                                    max_code_size
+                                );
+                                burn_funds = true;
+                                TallyOutcome::PassedExecutionFailed
+                            }
+                        }
                     }
                     ProposalType::PGFSteward(stewards) => {
                         let result =
@@ -172,7 +473,11 @@ where
                             }
                         );
 
-                        GovernanceEvent::passed_proposal(id, false, false)
+                        if result {
+                            TallyOutcome::Passed
+                        } else {
+                            TallyOutcome::PassedExecutionFailed
+                        }
                     }
                     ProposalType::PGFPayment(payments) => {
                         let native_token = state.get_native_token()?;
@@ -189,10 +494,64 @@ where
                             id
                         );
 
-                        GovernanceEvent::passed_proposal(id, false, false)
+                        TallyOutcome::Passed
                     }
+                    ProposalType::GovernanceParameters(parameters) => {
+                        match parameters.validate() {
+                            Ok(()) => {
+                                parameters.write_storage(state)?;
+                                tracing::info!(
+                                    "Governance proposal #{} for governance \
+                                     parameters has passed and been executed.",
+                                    id
+                                );
+                                TallyOutcome::Passed
+                            }
+                            Err(err) => {
+                                tracing::warn!(
+                                    "Governance proposal #{} for governance \
+                                     parameters has passed, but its \
+                                     parameters are invalid: {}. No state \
+                                     change occurred.",
+                                    id,
+                                    err
+                                );
+                                TallyOutcome::PassedExecutionFailed
+                            }
+                        }
+                    }
+                };
+                if retry_scheduled {
+                    // The proposal only ends once its code has been retried,
+                    // so its funds remain locked until then
+                    proposals_result.pending_retry.insert(id);
+                    proposals_result.changed_keys.insert(
+                        id,
+                        attribute_changed_keys(
+                            state,
+                            &modifications_before,
+                            proposal_code_keys,
+                        ),
+                    );
+                    continue;
+                }
+                let refunded_amount = if burn_funds {
+                    token::Amount::zero()
+                } else {
+                    funds
                 };
-                events.emit(proposal_event);
+                emit_ended_proposal(
+                    events,
+                    batch_events,
+                    GovernanceEvent::ended_proposal(
+                        id,
+                        outcome,
+                        has_proposal_code,
+                        proposal_author.clone(),
+                        funds,
+                        refunded_amount,
+                    ),
+                );
 
                 // Take events that could have been emitted by PGF
                 // over IBC, governance proposal execution, etc
@@ -207,9 +566,17 @@ where
                         .map(|event| event.with(Height(current_height))),
                 );
 
-                storage::get_proposal_author(state, id)?
+                // The funds of a passed proposal go to its funds recipient,
+                // if it was given one, rather than to its author
+                let funds_recipient =
+                    storage::get_proposal_funds_recipient(state, id)?
+                        .unwrap_or(proposal_author);
+
+                (outcome, funds_recipient, refunded_amount)
             }
-            TallyResult::Rejected => {
+            TallyOutcome::Rejected { .. }
+            | TallyOutcome::QuorumNotMet
+            | TallyOutcome::Error => {
                 if let ProposalType::PGFPayment(_) = proposal_type {
                     if proposal_result.two_thirds_nay_over_two_thirds_total() {
                         pgf_storage::remove_steward(state, &proposal_author)?;
@@ -224,55 +591,401 @@ where
                         );
                     }
                 }
-                let proposal_event = GovernanceEvent::rejected_proposal(
-                    id,
-                    matches!(proposal_type, ProposalType::DefaultWithWasm(_)),
+                // A misconfigured fraction must not make the refund exceed
+                // the locked funds
+                let refund_fraction =
+                    storage::get_rejection_refund_fraction(state)?
+                        .clamp(Dec::zero(), Dec::one());
+                let refunded_amount = funds.mul_floor(refund_fraction)?;
+                emit_ended_proposal(
+                    events,
+                    batch_events,
+                    GovernanceEvent::ended_proposal(
+                        id,
+                        tally_outcome,
+                        has_proposal_code,
+                        proposal_author.clone(),
+                        funds,
+                        refunded_amount,
+                    ),
                 );
-                events.emit(proposal_event);
 
                 tracing::info!(
-                    "Governance proposal {} has been executed and rejected.",
-                    id
+                    "Governance proposal {} has been executed and rejected \
+                     ({}). Refunding {} of the locked funds to its author.",
+                    id,
+                    tally_outcome,
+                    refunded_amount.to_string_native()
                 );
 
-                None
+                (tally_outcome, proposal_author, refunded_amount)
             }
         };
+        proposals_result.outcomes.insert(id, outcome);
+        refund_and_burn_proposal_funds::<S, Token>(
+            state,
+            funds,
+            refund_address,
+            refunded_amount,
+        )?;
 
-        let native_token = state.get_native_token()?;
-        if let Some(address) = transfer_address {
-            Token::transfer(
+        proposals_result.changed_keys.insert(
+            id,
+            attribute_changed_keys(
                 state,
-                &native_token,
-                &GOV_ADDRESS,
-                &address,
-                funds,
-            )?;
+                &modifications_before,
+                proposal_code_keys,
+            ),
+        );
+    }
+    Ok(proposals_result)
+}
 
-            const DESCRIPTOR: &str = "governance-locked-funds-refund";
+/// Execute again the code of the passed proposals that has been rejected, as
+/// allowed by the "max_proposal_code_retries" governance parameter.
+///
+/// The code of such a proposal is retried once per block, until it is either
+/// accepted, in which case the proposal ends as [`TallyOutcome::Passed`], or
+/// it has been retried "max_proposal_code_retries" times, in which case the
+/// proposal ends as [`TallyOutcome::PassedExecutionFailed`]. Code that runs
+/// out of gas or is oversized is not retried. The funds locked by the
+/// proposal are only refunded, or burnt, once it has ended.
+///
+/// The number of retries of each pending proposal is kept under
+/// [`keys::get_proposal_retry_count_key`], and is compared against the
+/// parameter in effect at each retry. Lowering the parameter thus ends the
+/// proposals that have already been retried as many times as the new
+/// maximum, and a proposal can never be retried indefinitely.
+fn retry_governance_proposals<S, Token, FnTx, FnWatch>(
+    state: &mut S,
+    events: &mut impl EmitEvents,
+    dispatch_tx: &mut FnTx,
+    watched_prefixes: &[Key],
+    on_watched_keys_changed: &mut FnWatch,
+    batch_events: bool,
+) -> Result<ProposalsResult>
+where
+    S: StateRead + State,
+    Token: token::Read<S> + token::Write<S> + token::Events<S>,
+    FnTx:
+        FnMut(&Tx, &mut S, u64) -> Result<(ProposalCodeResult, BTreeSet<Key>)>,
+    FnWatch: FnMut(u64, &BTreeSet<Key>),
+{
+    let mut proposals_result = ProposalsResult::default();
+    let retries = storage::get_proposal_retries(state)?;
+    if retries.is_empty() {
+        return Ok(proposals_result);
+    }
+    let max_code_retries = storage::get_max_proposal_code_retries(state)?;
+    let max_code_size = storage::get_max_proposal_code_size(state)?;
+    for (id, retry_count) in retries {
+        let modifications_before = read_modifications(state);
+        let retry_count_key = keys::get_proposal_retry_count_key(id);
+        let funds: token::Amount = force_read(state, &keys::get_funds_key(id))?;
+        let proposal_end_epoch: Epoch =
+            force_read(state, &keys::get_voting_end_epoch_key(id))?;
+        let proposal_author: Address =
+            force_read(state, &keys::get_author_key(id))?;
 
-            Token::emit_transfer_event(
-                state,
-                DESCRIPTOR.into(),
-                EventLevel::Tx,
-                &native_token,
-                funds,
-                token::UserAccount::Internal(GOV_ADDRESS),
-                token::UserAccount::Internal(address),
-            )?;
-        } else {
-            Token::burn_tokens(state, &native_token, &GOV_ADDRESS, funds)?;
+        let proposal_code =
+            storage::get_proposal_code(state, id)?.unwrap_or_default();
+        let max_gas = storage::get_max_code_gas(state, proposal_end_epoch)?;
+        let (code_result, code_keys) = execute_default_proposal(
+            state,
+            id,
+            proposal_code,
+            max_code_size,
+            max_gas,
+            dispatch_tx,
+        )?;
+        notify_watched_keys(
+            id,
+            &code_keys,
+            watched_prefixes,
+            on_watched_keys_changed,
+        );
+        let retry_count = retry_count
+            .checked_add(1)
+            .ok_or_err_msg("The proposal retry count must not overflow")?;
 
-            const DESCRIPTOR: &str = "governance-locked-funds-burn";
+        let (outcome, burn_funds) = match code_result {
+            ProposalCodeResult::Accepted => (TallyOutcome::Passed, false),
+            ProposalCodeResult::Rejected if retry_count < max_code_retries => {
+                state.write(&retry_count_key, retry_count)?;
+                tracing::info!(
+                    "The wasm code of governance proposal #{} has been \
+                     rejected again ({} of {} retries). Retrying it in the \
+                     next block.",
+                    id,
+                    retry_count,
+                    max_code_retries
+                );
+                proposals_result.pending_retry.insert(id);
+                proposals_result.changed_keys.insert(
+                    id,
+                    attribute_changed_keys(
+                        state,
+                        &modifications_before,
+                        code_keys,
+                    ),
+                );
+                continue;
+            }
+            ProposalCodeResult::Rejected => {
+                (TallyOutcome::PassedExecutionFailed, false)
+            }
+            ProposalCodeResult::OutOfGas | ProposalCodeResult::Oversized => {
+                (TallyOutcome::PassedExecutionFailed, true)
+            }
+        };
+        state.delete(&retry_count_key)?;
+        tracing::info!(
+            "Governance proposal #{} (default with wasm) has ended after {} \
+             retries of its wasm code: {}.",
+            id,
+            retry_count,
+            outcome
+        );
 
-            Token::emit_burn_event(
-                state,
-                DESCRIPTOR.into(),
-                &native_token,
+        let refunded_amount = if burn_funds {
+            token::Amount::zero()
+        } else {
+            funds
+        };
+        emit_ended_proposal(
+            events,
+            batch_events,
+            GovernanceEvent::ended_proposal(
+                id,
+                outcome,
+                true,
+                proposal_author.clone(),
                 funds,
-                &GOV_ADDRESS,
-            )?;
-        }
+                refunded_amount,
+            ),
+        );
+        let current_height =
+            state.in_mem().get_last_block_height().next_height();
+        events.emit_many(
+            state
+                .write_log_mut()
+                .take_events()
+                .into_iter()
+                .map(|event| event.with(Height(current_height))),
+        );
+
+        let funds_recipient = storage::get_proposal_funds_recipient(state, id)?
+            .unwrap_or(proposal_author);
+        proposals_result.outcomes.insert(id, outcome);
+        refund_and_burn_proposal_funds::<S, Token>(
+            state,
+            funds,
+            funds_recipient,
+            refunded_amount,
+        )?;
+        proposals_result.changed_keys.insert(
+            id,
+            attribute_changed_keys(state, &modifications_before, code_keys),
+        );
+    }
+    Ok(proposals_result)
+}
+
+/// Call `on_watched_keys_changed` with the keys changed by the code of a
+/// proposal that fall under one of the `watched_prefixes`, if any.
+fn notify_watched_keys<FnWatch>(
+    id: u64,
+    code_keys: &BTreeSet<Key>,
+    watched_prefixes: &[Key],
+    on_watched_keys_changed: &mut FnWatch,
+) where
+    FnWatch: FnMut(u64, &BTreeSet<Key>),
+{
+    let changed_watched_keys: BTreeSet<Key> = code_keys
+        .iter()
+        .filter(|key| {
+            watched_prefixes
+                .iter()
+                .any(|prefix| key.split_prefix(prefix).is_some())
+        })
+        .cloned()
+        .collect();
+    if !changed_watched_keys.is_empty() {
+        on_watched_keys_changed(id, &changed_watched_keys);
+    }
+}
+
+/// Attribute the keys changed while executing a proposal to either its code,
+/// or to governance for any key that wasn't changed by its code.
+fn attribute_changed_keys<S>(
+    state: &S,
+    modifications_before: &BTreeMap<String, StorageModification>,
+    proposal_code_keys: BTreeSet<Key>,
+) -> ProposalChangedKeys
+where
+    S: StateRead,
+{
+    let governance_keys =
+        changed_modified_keys(modifications_before, read_modifications(state))
+            .difference(&proposal_code_keys)
+            .cloned()
+            .collect();
+    ProposalChangedKeys {
+        governance: governance_keys,
+        proposal_code: proposal_code_keys,
+    }
+}
+
+/// Emit the event of an ended proposal, unless the outcomes of all the ended
+/// proposals are batched into a single event.
+fn emit_ended_proposal(
+    events: &mut impl EmitEvents,
+    batch_events: bool,
+    event: GovernanceEvent,
+) {
+    if !batch_events {
+        events.emit(event);
+    }
+}
+
+/// Tally the votes of a proposal without executing it, nor mutating storage.
+/// Returns the projected result of the proposal, and whether it has some
+/// code attached to it.
+pub fn tally_proposal<S, PoS>(
+    storage: &S,
+    id: u64,
+) -> Result<(TallyResult, bool)>
+where
+    S: StorageRead,
+    PoS: proof_of_stake::Read<S>,
+{
+    let proposal_end_epoch: Epoch =
+        force_read(storage, &keys::get_voting_end_epoch_key(id))?;
+    let proposal_type: ProposalType =
+        force_read(storage, &keys::get_proposal_type_key(id))?;
+    let proposal_author: Address =
+        force_read(storage, &keys::get_author_key(id))?;
+
+    let (proposal_result, _) = compute_proposal_tally::<S, PoS>(
+        storage,
+        id,
+        proposal_type,
+        &proposal_author,
+        proposal_end_epoch,
+    )??;
+    let has_code = storage::get_proposal_code(storage, id)?.is_some();
+    Ok((proposal_result.result, has_code))
+}
+
+/// Compute the result of a proposal from the votes cast on it, weighted by
+/// the voting power at the end of its voting period, along with the outcome
+/// of its tally. A proposal on which less than the minimum proposal quorum
+/// voted is rejected.
+///
+/// Errors reading the storage are returned in the outer result, while
+/// arithmetic errors of the tally are returned in the inner one.
+fn compute_proposal_tally<S, PoS>(
+    storage: &S,
+    id: u64,
+    proposal_type: ProposalType,
+    proposal_author: &Address,
+    proposal_end_epoch: Epoch,
+) -> Result<std::result::Result<(ProposalResult, TallyOutcome), arith::Error>>
+where
+    S: StorageRead,
+    PoS: proof_of_stake::Read<S>,
+{
+    let is_steward = pgf_storage::is_steward(storage, proposal_author)?;
+
+    let total_active_voting_power = PoS::total_active_stake::<crate::Store<_>>(
+        storage,
+        proposal_end_epoch,
+    )?;
+
+    let tally_type = TallyType::from(proposal_type, is_steward);
+    let votes =
+        compute_proposal_votes::<S, PoS>(storage, id, proposal_end_epoch)?;
+    let min_quorum = storage::get_min_proposal_quorum(storage)?;
+
+    Ok(
+        compute_proposal_result(votes, total_active_voting_power, tally_type)
+            .and_then(|mut proposal_result| {
+                let tally_outcome = proposal_result
+                    .tally_outcome_with_min_quorum(min_quorum)?;
+                if tally_outcome == TallyOutcome::QuorumNotMet {
+                    proposal_result.result = TallyResult::Rejected;
+                }
+                Ok((proposal_result, tally_outcome))
+            }),
+    )
+}
+
+/// Refund the given amount of the funds locked by a proposal to the given
+/// address, and burn the remainder.
+fn refund_and_burn_proposal_funds<S, Token>(
+    state: &mut S,
+    funds: token::Amount,
+    refund_address: Address,
+    refunded_amount: token::Amount,
+) -> Result<()>
+where
+    S: StateRead + State,
+    Token: token::Read<S> + token::Write<S> + token::Events<S>,
+{
+    let burnt_amount = funds.checked_sub(refunded_amount).ok_or_err_msg(
+        "The refunded amount must not exceed the locked funds",
+    )?;
+    if !refunded_amount.is_zero() {
+        refund_or_burn_proposal_funds::<S, Token>(
+            state,
+            refunded_amount,
+            Some(refund_address),
+        )?;
+    }
+    if !burnt_amount.is_zero() {
+        refund_or_burn_proposal_funds::<S, Token>(state, burnt_amount, None)?;
+    }
+    Ok(())
+}
+
+/// Refund the funds locked by a proposal to the given address, or burn them
+/// if no address is given.
+fn refund_or_burn_proposal_funds<S, Token>(
+    state: &mut S,
+    funds: token::Amount,
+    refund_address: Option<Address>,
+) -> Result<()>
+where
+    S: StateRead + State,
+    Token: token::Read<S> + token::Write<S> + token::Events<S>,
+{
+    let native_token = state.get_native_token()?;
+    if let Some(address) = refund_address {
+        Token::transfer(state, &native_token, &GOV_ADDRESS, &address, funds)?;
+
+        const DESCRIPTOR: &str = "governance-locked-funds-refund";
+
+        Token::emit_transfer_event(
+            state,
+            DESCRIPTOR.into(),
+            EventLevel::Tx,
+            &native_token,
+            funds,
+            token::UserAccount::Internal(GOV_ADDRESS),
+            token::UserAccount::Internal(address),
+        )?;
+    } else {
+        Token::burn_tokens(state, &native_token, &GOV_ADDRESS, funds)?;
+
+        const DESCRIPTOR: &str = "governance-locked-funds-burn";
+
+        Token::emit_burn_event(
+            state,
+            DESCRIPTOR.into(),
+            &native_token,
+            funds,
+            &GOV_ADDRESS,
+        )?;
     }
     Ok(())
 }
@@ -364,25 +1077,138 @@ fn execute_default_proposal<S, FnTx>(
     state: &mut S,
     id: u64,
     proposal_code: Vec<u8>,
+    max_code_size: u64,
+    max_gas: u64,
     dispatch_tx: &mut FnTx,
-) -> Result<bool>
+) -> Result<(ProposalCodeResult, BTreeSet<Key>)>
 where
     S: StateRead + State,
-    FnTx: FnMut(&Tx, &mut S) -> Result<bool>,
+    FnTx:
+        FnMut(&Tx, &mut S, u64) -> Result<(ProposalCodeResult, BTreeSet<Key>)>,
 {
-    let pending_execution_key = keys::get_proposal_execution_key(id);
-    state.write(&pending_execution_key, ())?;
+    let is_oversized = u64::try_from(proposal_code.len())
+        .map_or(true, |code_size| code_size > max_code_size);
+    if is_oversized {
+        return Ok((ProposalCodeResult::Oversized, BTreeSet::new()));
+    }
 
     let mut tx = Tx::from_type(TxType::Raw);
     tx.header.chain_id = state.get_chain_id()?;
     tx.set_data(Data::new(encode(&id)));
     tx.set_code(Code::new(proposal_code, None));
 
-    let dispatch_result = dispatch_tx(&tx, state);
+    let mut scope = WriteLogTxScope::new(state, id)?;
+    let dispatch_result = dispatch_tx(&tx, &mut scope, max_gas)?;
+    if dispatch_result.0 == ProposalCodeResult::Accepted {
+        scope.commit()?;
+    }
+    Ok(dispatch_result)
+}
+
+/// Guards the write log of the transaction that executes the code of a
+/// proposal. The proposal is marked as pending execution in a batch of the
+/// write log, such that validity predicates observe the marker in their
+/// prior state, for as long as the scope is alive.
+///
+/// The changes of the transaction are only committed to the block write log
+/// with [`WriteLogTxScope::commit`]. Otherwise, they are dropped together
+/// with the marker when the scope is dropped, even if the execution of the
+/// proposal's code panics. Dropping the scope only discards the write log of
+/// the transaction, and never touches storage.
+struct WriteLogTxScope<'state, S>
+where
+    S: StateRead + State,
+{
+    state: &'state mut S,
+    pending_execution_key: Key,
+    committed: bool,
+}
+
+impl<'state, S> WriteLogTxScope<'state, S>
+where
+    S: StateRead + State,
+{
+    /// Mark the given proposal as pending execution.
+    fn new(state: &'state mut S, id: u64) -> Result<Self> {
+        let pending_execution_key = keys::get_proposal_execution_key(id);
+        let write_log = state.write_log_mut();
+        write_log
+            .write(&pending_execution_key, encode(&()))
+            .into_storage_result()?;
+        write_log.commit_tx_to_batch();
+        Ok(Self {
+            state,
+            pending_execution_key,
+            committed: false,
+        })
+    }
+
+    /// Commit the changes of the transaction to the block write log, without
+    /// the pending execution marker of the proposal.
+    fn commit(mut self) -> Result<()> {
+        let write_log = self.state.write_log_mut();
+        write_log
+            .delete(&self.pending_execution_key)
+            .into_storage_result()?;
+        write_log.commit_batch_and_current_tx();
+        self.committed = true;
+        Ok(())
+    }
+}
+
+impl<S> Deref for WriteLogTxScope<'_, S>
+where
+    S: StateRead + State,
+{
+    type Target = S;
+
+    fn deref(&self) -> &S {
+        self.state
+    }
+}
+
+impl<S> DerefMut for WriteLogTxScope<'_, S>
+where
+    S: StateRead + State,
+{
+    fn deref_mut(&mut self) -> &mut S {
+        self.state
+    }
+}
+
+impl<S> Drop for WriteLogTxScope<'_, S>
+where
+    S: StateRead + State,
+{
+    fn drop(&mut self) {
+        if !self.committed {
+            self.state.write_log_mut().drop_batch();
+        }
+    }
+}
+
+/// Collect the write log modifications of all the keys.
+fn read_modifications<S>(state: &S) -> BTreeMap<String, StorageModification>
+where
+    S: StateRead,
+{
     state
-        .delete(&pending_execution_key)
-        .expect("Should be able to delete the storage.");
-    dispatch_result
+        .write_log()
+        .iter_prefix_post(&Key::default())
+        .collect()
+}
+
+/// Find the keys whose modifications differ between the two snapshots
+/// obtained from [`read_modifications`].
+fn changed_modified_keys(
+    before: &BTreeMap<String, StorageModification>,
+    after: BTreeMap<String, StorageModification>,
+) -> BTreeSet<Key> {
+    after
+        .into_iter()
+        .filter(|(key, modification)| before.get(key) != Some(modification))
+        .map(|(key, _)| Key::parse(key).expect("The key should be parsable"))
+        .collect()
 }
 
 fn execute_pgf_steward_proposal<S>(
@@ -558,3 +1384,1360 @@ where
         .transpose()
         .expect("Storage key must be present.")
 }
+
+#[cfg(test)]
+mod test {
+    use assert_matches::assert_matches;
+    use namada_core::address::testing::{
+        established_address_1, established_address_2,
+    };
+    use namada_core::collections::HashSet;
+    use namada_core::hash::Hash;
+    use namada_events::Event;
+    use namada_proof_of_stake::test_utils::{
+        get_dummy_genesis_validator, test_init_genesis,
+    };
+    use namada_proof_of_stake::types::GenesisValidator;
+    use namada_state::testing::TestState;
+
+    use super::*;
+    use crate::parameters::GovernanceParameters;
+    use crate::storage::proposal::{InitProposalData, VoteProposalData};
+    use crate::utils::TallyFractions;
+
+    type Token = namada_token::Store<TestState>;
+    type PoS = namada_proof_of_stake::Store<TestState>;
+
+    /// Execute the given proposals, and return the emitted events along with
+    /// the result of their execution.
+    fn execute_proposals(
+        state: &mut TestState,
+        proposal_ids: BTreeSet<u64>,
+    ) -> (Vec<Event>, ProposalsResult) {
+        let mut events = vec![];
+        let proposals_result =
+            execute_governance_proposals::<_, Token, PoS, _, _, _>(
+                state,
+                &mut events,
+                proposal_ids,
+                |_, _, _| Ok((ProposalCodeResult::Accepted, BTreeSet::new())),
+                |_, _, _, _| Ok(()),
+                &[],
+                |_, _| {},
+                false,
+            )
+            .expect("Test failed");
+        (events, proposals_result)
+    }
+
+    /// Initialize a proposal authored by [`established_address_1`],
+    /// and return its id.
+    fn init_proposal(
+        state: &mut TestState,
+        voting_start_epoch: Epoch,
+        voting_end_epoch: Epoch,
+    ) -> u64 {
+        init_proposal_with_type(
+            state,
+            ProposalType::Default,
+            None,
+            voting_start_epoch,
+            voting_end_epoch,
+        )
+    }
+
+    /// Initialize a proposal of the given type authored by
+    /// [`established_address_1`], and return its id.
+    fn init_proposal_with_type(
+        state: &mut TestState,
+        r#type: ProposalType,
+        code: Option<Vec<u8>>,
+        voting_start_epoch: Epoch,
+        voting_end_epoch: Epoch,
+    ) -> u64 {
+        let proposal = InitProposalData {
+            content: Hash::default(),
+            author: established_address_1(),
+            r#type,
+            voting_start_epoch,
+            voting_end_epoch,
+            activation_epoch: voting_end_epoch.next(),
+        };
+        storage::init_proposal::<_, Token>(state, &proposal, vec![], code)
+            .expect("Test failed")
+    }
+
+    /// Initialize the governance parameters, and credit some native tokens
+    /// to [`established_address_1`].
+    fn init_storage() -> TestState {
+        let mut state = TestState::default();
+        GovernanceParameters::default()
+            .init_storage(&mut state)
+            .expect("Test failed");
+        let native_token = state.get_native_token().expect("Test failed");
+        namada_token::credit_tokens(
+            &mut state,
+            &native_token,
+            &established_address_1(),
+            token::Amount::native_whole(1_000),
+        )
+        .expect("Test failed");
+        state
+    }
+
+    /// Initialize PoS with a single genesis validator, which is also
+    /// [`established_address_1`], and credit some native tokens to it.
+    fn init_storage_with_validator() -> (TestState, GenesisValidator) {
+        let mut state = TestState::default();
+        let validator = get_dummy_genesis_validator();
+        test_init_genesis::<
+            _,
+            namada_parameters::Store<_>,
+            crate::Store<_>,
+            namada_token::Store<_>,
+        >(
+            &mut state,
+            namada_proof_of_stake::OwnedPosParams::default(),
+            vec![validator.clone()].into_iter(),
+            Epoch(0),
+        )
+        .expect("Test failed");
+        let native_token = state.get_native_token().expect("Test failed");
+        namada_token::credit_tokens(
+            &mut state,
+            &native_token,
+            &established_address_1(),
+            token::Amount::native_whole(1_000),
+        )
+        .expect("Test failed");
+        (state, validator)
+    }
+
+    /// Cast a vote on a proposal on behalf of the given validator.
+    fn vote_as_validator(
+        state: &mut TestState,
+        validator: &GenesisValidator,
+        id: u64,
+        vote: ProposalVote,
+    ) {
+        storage::vote_proposal(
+            state,
+            VoteProposalData {
+                id,
+                vote,
+                voter: validator.address.clone(),
+            },
+            HashSet::from([validator.address.clone()]),
+        )
+        .expect("Test failed");
+    }
+
+    /// Test that a proposal whose voting end epoch is not after its voting
+    /// start epoch is rejected as malformed, and its author gets refunded.
+    #[test]
+    fn test_proposal_with_inverted_epochs_is_rejected() {
+        let mut state = init_storage();
+        let native_token = state.get_native_token().expect("Test failed");
+        let author = established_address_1();
+
+        let id = init_proposal(&mut state, Epoch(2), Epoch(1));
+        assert_eq!(
+            namada_token::read_balance(&state, &native_token, &author)
+                .expect("Test failed"),
+            token::Amount::native_whole(500)
+        );
+
+        let (events, proposals_result) =
+            execute_proposals(&mut state, BTreeSet::from([id]));
+
+        let outcome = TallyOutcome::Rejected {
+            reason: RejectionReason::MalformedVotingPeriod,
+        };
+        assert_eq!(proposals_result.outcomes, BTreeMap::from([(id, outcome)]));
+        assert_eq!(
+            events,
+            vec![Event::from(GovernanceEvent::ended_proposal(
+                id,
+                outcome,
+                false,
+                author.clone(),
+                token::Amount::native_whole(500),
+                token::Amount::native_whole(500)
+            ))]
+        );
+        assert_eq!(
+            namada_token::read_balance(&state, &native_token, &author)
+                .expect("Test failed"),
+            token::Amount::native_whole(1_000)
+        );
+        assert!(
+            storage::get_proposal_result(&state, id)
+                .expect("Test failed")
+                .is_none()
+        );
+    }
+
+    /// Test that the watched keys callback fires when the code of an
+    /// executed proposal writes to a watched key, and only then.
+    #[test]
+    fn test_proposal_changing_watched_keys_fires_callback() {
+        let mut state = init_storage();
+        namada_proof_of_stake::storage::write_pos_params(
+            &mut state,
+            &namada_proof_of_stake::OwnedPosParams::default(),
+        )
+        .expect("Test failed");
+
+        let watched_prefix = Key::parse("watched").expect("Test failed");
+        let watched_key = Key::parse("watched/key").expect("Test failed");
+        let unwatched_key = Key::parse("unwatched").expect("Test failed");
+
+        let [id, other_id] = [(); 2].map(|()| {
+            init_proposal_with_type(
+                &mut state,
+                ProposalType::DefaultWithWasm(Hash::default()),
+                Some(vec![]),
+                Epoch(0),
+                Epoch(1),
+            )
+        });
+
+        let mut events = vec![];
+        let mut fired = vec![];
+        let mut is_first_proposal = true;
+        execute_governance_proposals::<_, Token, PoS, _, _, _>(
+            &mut state,
+            &mut events,
+            BTreeSet::from([id, other_id]),
+            |_, state, _| {
+                let mut changed_keys = BTreeSet::from([unwatched_key.clone()]);
+                // only the first proposal touches the watched key
+                if std::mem::take(&mut is_first_proposal) {
+                    state.write(&watched_key, 1_u64)?;
+                    changed_keys.insert(watched_key.clone());
+                }
+                state.write(&unwatched_key, 1_u64)?;
+                Ok((ProposalCodeResult::Accepted, changed_keys))
+            },
+            |_, _, _, _| Ok(()),
+            &[watched_prefix],
+            |id, keys| fired.push((id, keys.clone())),
+            false,
+        )
+        .expect("Test failed");
+
+        assert_eq!(
+            events,
+            vec![
+                Event::from(GovernanceEvent::ended_proposal(
+                    id,
+                    TallyOutcome::Passed,
+                    true,
+                    established_address_1(),
+                    token::Amount::native_whole(500),
+                    token::Amount::native_whole(500)
+                )),
+                Event::from(GovernanceEvent::ended_proposal(
+                    other_id,
+                    TallyOutcome::Passed,
+                    true,
+                    established_address_1(),
+                    token::Amount::native_whole(500),
+                    token::Amount::native_whole(500)
+                )),
+            ]
+        );
+        assert_eq!(fired, vec![(id, BTreeSet::from([watched_key]))]);
+    }
+
+    /// Test that a proposal whose code exceeds the maximum proposal code size
+    /// is rejected without executing its code, and that its locked funds are
+    /// burnt.
+    #[test]
+    fn test_oversized_proposal_code_is_not_executed() {
+        let mut state = init_storage();
+        GovernanceParameters {
+            max_proposal_code_size: 4,
+            ..Default::default()
+        }
+        .init_storage(&mut state)
+        .expect("Test failed");
+        namada_proof_of_stake::storage::write_pos_params(
+            &mut state,
+            &namada_proof_of_stake::OwnedPosParams::default(),
+        )
+        .expect("Test failed");
+        let native_token = state.get_native_token().expect("Test failed");
+        let author = established_address_1();
+
+        let id = init_proposal_with_type(
+            &mut state,
+            ProposalType::DefaultWithWasm(Hash::default()),
+            Some(vec![0; 5]),
+            Epoch(0),
+            Epoch(1),
+        );
+
+        let mut events = vec![];
+        let mut is_executed = false;
+        execute_governance_proposals::<_, Token, PoS, _, _, _>(
+            &mut state,
+            &mut events,
+            BTreeSet::from([id]),
+            |_, _, _| {
+                is_executed = true;
+                Ok((ProposalCodeResult::Accepted, BTreeSet::new()))
+            },
+            |_, _, _, _| Ok(()),
+            &[],
+            |_, _| {},
+            false,
+        )
+        .expect("Test failed");
+
+        assert!(!is_executed);
+        assert_eq!(
+            events,
+            vec![Event::from(GovernanceEvent::ended_proposal(
+                id,
+                TallyOutcome::PassedExecutionFailed,
+                true,
+                author.clone(),
+                token::Amount::native_whole(500),
+                token::Amount::zero()
+            ))]
+        );
+        assert_eq!(
+            namada_token::read_balance(&state, &native_token, &author)
+                .expect("Test failed"),
+            token::Amount::native_whole(500)
+        );
+        assert_eq!(
+            namada_token::read_balance(&state, &native_token, &GOV_ADDRESS)
+                .expect("Test failed"),
+            token::Amount::zero()
+        );
+    }
+
+    /// Test that a storage error while tallying a proposal is propagated,
+    /// rather than causing the locked funds of the proposal to be burnt.
+    #[test]
+    fn test_proposal_tally_storage_error_is_propagated() {
+        let mut state = init_storage();
+        // corrupt the PoS params, such that tallying fails
+        state
+            .write_bytes(&namada_proof_of_stake::storage_key::params_key(), [0])
+            .expect("Test failed");
+        let native_token = state.get_native_token().expect("Test failed");
+        let id = init_proposal(&mut state, Epoch(0), Epoch(1));
+
+        let mut events = vec![];
+        execute_governance_proposals::<_, Token, PoS, _, _, _>(
+            &mut state,
+            &mut events,
+            BTreeSet::from([id]),
+            |_, _, _| Ok((ProposalCodeResult::Accepted, BTreeSet::new())),
+            |_, _, _, _| Ok(()),
+            &[],
+            |_, _| {},
+            false,
+        )
+        .expect_err("Test failed");
+
+        assert!(events.is_empty());
+        assert_eq!(
+            namada_token::read_balance(&state, &native_token, &GOV_ADDRESS)
+                .expect("Test failed"),
+            token::Amount::native_whole(500)
+        );
+    }
+
+    /// Test that the keys changed while executing a proposal are correctly
+    /// attributed to either governance or the proposal code.
+    #[test]
+    fn test_proposal_changed_keys_attribution() {
+        let mut state = init_storage();
+        namada_proof_of_stake::storage::write_pos_params(
+            &mut state,
+            &namada_proof_of_stake::OwnedPosParams::default(),
+        )
+        .expect("Test failed");
+        let native_token = state.get_native_token().expect("Test failed");
+        let code_key = Key::parse("proposal/code/key").expect("Test failed");
+
+        let id = init_proposal_with_type(
+            &mut state,
+            ProposalType::DefaultWithWasm(Hash::default()),
+            Some(vec![]),
+            Epoch(0),
+            Epoch(1),
+        );
+
+        let mut events = vec![];
+        let proposals_result =
+            execute_governance_proposals::<_, Token, PoS, _, _, _>(
+                &mut state,
+                &mut events,
+                BTreeSet::from([id]),
+                |_, state, _| {
+                    state.write(&code_key, 1_u64)?;
+                    Ok((
+                        ProposalCodeResult::Accepted,
+                        BTreeSet::from([code_key.clone()]),
+                    ))
+                },
+                |_, _, _, _| Ok(()),
+                &[],
+                |_, _| {},
+                false,
+            )
+            .expect("Test failed");
+
+        let ProposalChangedKeys {
+            governance,
+            proposal_code,
+        } = proposals_result.changed_keys.get(&id).expect("Test failed");
+        assert_eq!(proposal_code, &BTreeSet::from([code_key]));
+        assert!(governance.is_disjoint(proposal_code));
+        assert!(governance.contains(&keys::get_proposal_result_key(id)));
+        assert!(governance.contains(&namada_token::storage_key::balance_key(
+            &native_token,
+            &established_address_1(),
+        )));
+    }
+
+    /// Test that a proposal whose code exceeds the gas limit of governance
+    /// proposals is treated as a failed execution, and that its locked funds
+    /// are burnt rather than refunded.
+    #[test]
+    fn test_proposal_code_out_of_gas_burns_funds() {
+        let mut state = init_storage();
+        namada_proof_of_stake::storage::write_pos_params(
+            &mut state,
+            &namada_proof_of_stake::OwnedPosParams::default(),
+        )
+        .expect("Test failed");
+        let native_token = state.get_native_token().expect("Test failed");
+        let author = established_address_1();
+        let max_gas = storage::get_max_proposal_execution_gas(&state)
+            .expect("Test failed");
+
+        let id = init_proposal_with_type(
+            &mut state,
+            ProposalType::DefaultWithWasm(Hash::default()),
+            Some(vec![]),
+            Epoch(0),
+            Epoch(1),
+        );
+
+        let mut events = vec![];
+        let mut gas_limits = vec![];
+        execute_governance_proposals::<_, Token, PoS, _, _, _>(
+            &mut state,
+            &mut events,
+            BTreeSet::from([id]),
+            |_, _, gas_limit| {
+                // the metering of the dispatched code is covered by the
+                // tests of the node, which dispatches it
+                gas_limits.push(gas_limit);
+                Ok((ProposalCodeResult::OutOfGas, BTreeSet::new()))
+            },
+            |_, _, _, _| Ok(()),
+            &[],
+            |_, _| {},
+            false,
+        )
+        .expect("Test failed");
+
+        assert_eq!(gas_limits, vec![max_gas]);
+        assert_eq!(
+            events,
+            vec![Event::from(GovernanceEvent::ended_proposal(
+                id,
+                TallyOutcome::PassedExecutionFailed,
+                true,
+                author.clone(),
+                token::Amount::native_whole(500),
+                token::Amount::zero()
+            ))]
+        );
+        assert_eq!(
+            namada_token::read_balance(&state, &native_token, &author)
+                .expect("Test failed"),
+            token::Amount::native_whole(500)
+        );
+        assert_eq!(
+            namada_token::read_balance(&state, &native_token, &GOV_ADDRESS)
+                .expect("Test failed"),
+            token::Amount::zero()
+        );
+    }
+
+    /// Retry the code of the proposals pending a retry, dispatching it with
+    /// the given outcome, and return the emitted events along with the result
+    /// of the retries.
+    fn retry_proposals(
+        state: &mut TestState,
+        code_result: ProposalCodeResult,
+    ) -> (Vec<Event>, ProposalsResult) {
+        let mut events = vec![];
+        let proposals_result = retry_governance_proposals::<_, Token, _, _>(
+            state,
+            &mut events,
+            &mut |_, _, _| Ok((code_result, BTreeSet::new())),
+            &[],
+            &mut |_, _| {},
+            false,
+        )
+        .expect("Test failed");
+        (events, proposals_result)
+    }
+
+    /// Test that a passed proposal whose code is rejected once is retried in
+    /// the next block, and passes once its code is accepted.
+    #[test]
+    fn test_rejected_proposal_code_is_retried() {
+        let mut state = init_storage();
+        GovernanceParameters {
+            max_proposal_code_retries: 2,
+            ..Default::default()
+        }
+        .init_storage(&mut state)
+        .expect("Test failed");
+        namada_proof_of_stake::storage::write_pos_params(
+            &mut state,
+            &namada_proof_of_stake::OwnedPosParams::default(),
+        )
+        .expect("Test failed");
+        let native_token = state.get_native_token().expect("Test failed");
+        let author = established_address_1();
+        let id = init_proposal_with_type(
+            &mut state,
+            ProposalType::DefaultWithWasm(Hash::default()),
+            Some(vec![]),
+            Epoch(0),
+            Epoch(1),
+        );
+
+        let mut events = vec![];
+        let proposals_result =
+            execute_governance_proposals::<_, Token, PoS, _, _, _>(
+                &mut state,
+                &mut events,
+                BTreeSet::from([id]),
+                |_, _, _| Ok((ProposalCodeResult::Rejected, BTreeSet::new())),
+                |_, _, _, _| Ok(()),
+                &[],
+                |_, _| {},
+                false,
+            )
+            .expect("Test failed");
+
+        // The proposal hasn't ended yet, and its funds are still locked
+        assert_eq!(proposals_result.pending_retry, BTreeSet::from([id]));
+        assert!(proposals_result.outcomes.is_empty());
+        assert!(events.is_empty());
+        assert_eq!(
+            storage::get_proposal_retries(&state).expect("Test failed"),
+            BTreeMap::from([(id, 0)])
+        );
+        assert_eq!(
+            namada_token::read_balance(&state, &native_token, &GOV_ADDRESS)
+                .expect("Test failed"),
+            token::Amount::native_whole(500)
+        );
+
+        let (events, proposals_result) =
+            retry_proposals(&mut state, ProposalCodeResult::Accepted);
+
+        assert_eq!(
+            proposals_result.outcomes,
+            BTreeMap::from([(id, TallyOutcome::Passed)])
+        );
+        assert!(proposals_result.pending_retry.is_empty());
+        assert_eq!(
+            events,
+            vec![Event::from(GovernanceEvent::ended_proposal(
+                id,
+                TallyOutcome::Passed,
+                true,
+                author.clone(),
+                token::Amount::native_whole(500),
+                token::Amount::native_whole(500)
+            ))]
+        );
+        assert!(
+            storage::get_proposal_retries(&state)
+                .expect("Test failed")
+                .is_empty()
+        );
+        assert_eq!(
+            namada_token::read_balance(&state, &native_token, &author)
+                .expect("Test failed"),
+            token::Amount::native_whole(1_000)
+        );
+    }
+
+    /// Test that the code of a proposal is retried at most as many times as
+    /// the max proposal code retries, after which the proposal fails.
+    #[test]
+    fn test_proposal_code_retries_are_bounded() {
+        let mut state = init_storage();
+        GovernanceParameters {
+            max_proposal_code_retries: 2,
+            ..Default::default()
+        }
+        .init_storage(&mut state)
+        .expect("Test failed");
+        namada_proof_of_stake::storage::write_pos_params(
+            &mut state,
+            &namada_proof_of_stake::OwnedPosParams::default(),
+        )
+        .expect("Test failed");
+        let id = init_proposal_with_type(
+            &mut state,
+            ProposalType::DefaultWithWasm(Hash::default()),
+            Some(vec![]),
+            Epoch(0),
+            Epoch(1),
+        );
+        let mut events = vec![];
+        execute_governance_proposals::<_, Token, PoS, _, _, _>(
+            &mut state,
+            &mut events,
+            BTreeSet::from([id]),
+            |_, _, _| Ok((ProposalCodeResult::Rejected, BTreeSet::new())),
+            |_, _, _, _| Ok(()),
+            &[],
+            |_, _| {},
+            false,
+        )
+        .expect("Test failed");
+
+        let (_, proposals_result) =
+            retry_proposals(&mut state, ProposalCodeResult::Rejected);
+        assert_eq!(proposals_result.pending_retry, BTreeSet::from([id]));
+
+        let (_, proposals_result) =
+            retry_proposals(&mut state, ProposalCodeResult::Rejected);
+        assert!(proposals_result.pending_retry.is_empty());
+        assert_eq!(
+            proposals_result.outcomes,
+            BTreeMap::from([(id, TallyOutcome::PassedExecutionFailed)])
+        );
+
+        // There is nothing left to retry
+        let (events, proposals_result) =
+            retry_proposals(&mut state, ProposalCodeResult::Accepted);
+        assert!(events.is_empty());
+        assert!(proposals_result.outcomes.is_empty());
+    }
+
+    /// Test that the pending execution marker of a proposal is removed even
+    /// if the execution of its code panics.
+    #[test]
+    fn test_pending_execution_marker_removed_on_panic() {
+        let mut state = init_storage();
+        let id = 0;
+        let pending_execution_key = keys::get_proposal_execution_key(id);
+
+        let result =
+            std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                execute_default_proposal(
+                    &mut state,
+                    id,
+                    vec![],
+                    u64::MAX,
+                    u64::MAX,
+                    &mut |_, state: &mut TestState, _| {
+                        assert!(
+                            state
+                                .has_key(&keys::get_proposal_execution_key(id))
+                                .expect("Test failed")
+                        );
+                        panic!("The proposal code panicked")
+                    },
+                )
+            }));
+
+        assert!(result.is_err());
+        assert!(!state.has_key(&pending_execution_key).expect("Test failed"));
+    }
+
+    /// Test that the changes of the code of a proposal are committed only if
+    /// the code is accepted, and that the pending execution marker of the
+    /// proposal is never committed.
+    #[test]
+    fn test_proposal_code_write_log_tx_scope() {
+        let mut state = init_storage();
+        let id = 0;
+        let pending_execution_key = keys::get_proposal_execution_key(id);
+        let code_key = Key::parse("code").expect("Test failed");
+
+        for (code_result, is_committed) in [
+            (ProposalCodeResult::Rejected, false),
+            (ProposalCodeResult::OutOfGas, false),
+            (ProposalCodeResult::Accepted, true),
+        ] {
+            execute_default_proposal(
+                &mut state,
+                id,
+                vec![],
+                u64::MAX,
+                u64::MAX,
+                &mut |_, state: &mut TestState, _| {
+                    state
+                        .write_log_mut()
+                        .write(&code_key, encode(&1_u64))
+                        .expect("Test failed");
+                    Ok((code_result, BTreeSet::from([code_key.clone()])))
+                },
+            )
+            .expect("Test failed");
+
+            assert_eq!(
+                state.has_key(&code_key).expect("Test failed"),
+                is_committed
+            );
+            assert!(
+                !state.has_key(&pending_execution_key).expect("Test failed")
+            );
+        }
+    }
+
+    /// Test that the code of a proposal is executed under the max code gas
+    /// in effect at the voting end epoch of the proposal.
+    #[test]
+    fn test_proposal_code_gas_limit_at_end_epoch() {
+        let mut state = init_storage();
+        namada_proof_of_stake::storage::write_pos_params(
+            &mut state,
+            &namada_proof_of_stake::OwnedPosParams::default(),
+        )
+        .expect("Test failed");
+        let default_max_gas = storage::get_max_proposal_execution_gas(&state)
+            .expect("Test failed");
+
+        // only the limit set at genesis is in effect
+        assert_eq!(
+            state
+                .read::<u64>(&keys::get_max_code_gas_key(Epoch(0)))
+                .expect("Test failed"),
+            Some(default_max_gas)
+        );
+        assert_eq!(
+            storage::get_max_code_gas(&state, Epoch(2)).expect("Test failed"),
+            default_max_gas
+        );
+
+        // a previous proposal raised the limit from epoch 2 onwards, and
+        // another one raised it again from epoch 4 onwards
+        for (epoch, max_gas) in [(2, 50_000_000), (4, 70_000_000)] {
+            state.in_mem_mut().block.epoch = Epoch(epoch);
+            GovernanceParameters {
+                max_proposal_execution_gas: max_gas,
+                ..Default::default()
+            }
+            .write_storage(&mut state)
+            .expect("Test failed");
+        }
+        state.in_mem_mut().block.epoch = Epoch(0);
+        assert_eq!(
+            storage::get_max_code_gas(&state, Epoch(1)).expect("Test failed"),
+            default_max_gas
+        );
+        assert_eq!(
+            storage::get_max_code_gas(&state, Epoch(3)).expect("Test failed"),
+            50_000_000
+        );
+        assert_eq!(
+            storage::get_max_code_gas(&state, Epoch(10)).expect("Test failed"),
+            70_000_000
+        );
+
+        let id = init_proposal_with_type(
+            &mut state,
+            ProposalType::DefaultWithWasm(Hash::default()),
+            Some(vec![]),
+            Epoch(0),
+            Epoch(3),
+        );
+
+        let mut events = vec![];
+        let mut gas_limits = vec![];
+        execute_governance_proposals::<_, Token, PoS, _, _, _>(
+            &mut state,
+            &mut events,
+            BTreeSet::from([id]),
+            |_, _, gas_limit| {
+                gas_limits.push(gas_limit);
+                Ok((ProposalCodeResult::Accepted, BTreeSet::new()))
+            },
+            |_, _, _, _| Ok(()),
+            &[],
+            |_, _| {},
+            false,
+        )
+        .expect("Test failed");
+
+        assert_eq!(gas_limits, vec![50_000_000]);
+    }
+
+    /// Test that the tally fractions of an executed proposal are persisted,
+    /// and that they reconcile with the votes that were cast.
+    #[test]
+    fn test_proposal_tally_fractions_are_persisted() {
+        let (mut state, validator) = init_storage_with_validator();
+        let id = init_proposal(&mut state, Epoch(0), Epoch(1));
+        vote_as_validator(&mut state, &validator, id, ProposalVote::Yay);
+
+        execute_proposals(&mut state, BTreeSet::from([id]));
+
+        let result = storage::get_proposal_result(&state, id)
+            .expect("Test failed")
+            .expect("Test failed");
+        assert_eq!(result.total_voting_power, validator.tokens);
+        assert_eq!(result.total_yay_power, validator.tokens);
+        let tally_fractions = storage::get_proposal_tally_fractions(&state, id)
+            .expect("Test failed")
+            .expect("Test failed");
+        assert_eq!(
+            tally_fractions,
+            TallyFractions {
+                yay: Dec::one(),
+                nay: Dec::zero(),
+                abstain: Dec::zero(),
+            }
+        );
+    }
+
+    /// Test that the stake of a delegator voting on the same side as its
+    /// validator is not counted twice in the tally of a proposal.
+    #[test]
+    fn test_delegator_and_validator_votes_are_not_double_counted() {
+        let (mut state, validator) = init_storage_with_validator();
+        let native_token = state.get_native_token().expect("Test failed");
+        let delegator = established_address_2();
+        let bond_amount = token::Amount::native_whole(100);
+        namada_token::credit_tokens(
+            &mut state,
+            &native_token,
+            &delegator,
+            bond_amount,
+        )
+        .expect("Test failed");
+        namada_proof_of_stake::bond_tokens::<_, crate::Store<_>, Token>(
+            &mut state,
+            Some(&delegator),
+            &validator.address,
+            bond_amount,
+            Epoch(0),
+            None,
+        )
+        .expect("Test failed");
+        let id = init_proposal(&mut state, Epoch(0), Epoch(2));
+        vote_as_validator(&mut state, &validator, id, ProposalVote::Yay);
+        storage::vote_proposal(
+            &mut state,
+            VoteProposalData {
+                id,
+                vote: ProposalVote::Yay,
+                voter: delegator.clone(),
+            },
+            HashSet::from([validator.address.clone()]),
+        )
+        .expect("Test failed");
+
+        let votes = compute_proposal_votes::<_, PoS>(&state, id, Epoch(2))
+            .expect("Test failed");
+        assert_eq!(
+            votes
+                .delegator_voting_power
+                .get(&delegator)
+                .and_then(|delegations| delegations.get(&validator.address)),
+            Some(&bond_amount)
+        );
+
+        execute_proposals(&mut state, BTreeSet::from([id]));
+
+        let validator_stake = validator
+            .tokens
+            .checked_add(bond_amount)
+            .expect("Test failed");
+        let result = storage::get_proposal_result(&state, id)
+            .expect("Test failed")
+            .expect("Test failed");
+        assert_eq!(result.total_voting_power, validator_stake);
+        assert_eq!(result.total_yay_power, validator_stake);
+        assert_eq!(result.total_nay_power, token::Amount::zero());
+        assert_eq!(result.total_abstain_power, token::Amount::zero());
+    }
+
+    /// Test that a passed governance parameters proposal writes the new
+    /// parameters to storage.
+    #[test]
+    fn test_governance_parameters_proposal_writes_parameters() {
+        let (mut state, validator) = init_storage_with_validator();
+        GovernanceParameters::default()
+            .init_storage(&mut state)
+            .expect("Test failed");
+        let parameters = GovernanceParameters {
+            min_proposal_fund: token::Amount::native_whole(100),
+            max_proposal_execution_gas: 10_000_000,
+            ..Default::default()
+        };
+        let id = init_proposal_with_type(
+            &mut state,
+            ProposalType::GovernanceParameters(parameters.clone()),
+            None,
+            Epoch(0),
+            Epoch(1),
+        );
+        vote_as_validator(&mut state, &validator, id, ProposalVote::Yay);
+
+        let (_, proposals_result) =
+            execute_proposals(&mut state, BTreeSet::from([id]));
+
+        assert_eq!(
+            proposals_result.outcomes,
+            BTreeMap::from([(id, TallyOutcome::Passed)])
+        );
+        assert_eq!(
+            storage::get_parameters(&state).expect("Test failed"),
+            parameters
+        );
+        // The proposal counter is left untouched
+        assert_eq!(
+            state
+                .read::<u64>(&keys::get_counter_key())
+                .expect("Test failed"),
+            Some(id.checked_add(1).expect("Test failed"))
+        );
+    }
+
+    /// Test that refunding the funds of a proposal to an author whose balance
+    /// would overflow fails with an error, rather than a panic, and leaves the
+    /// locked funds in place.
+    #[test]
+    fn test_proposal_refund_overflow_is_an_error() {
+        let (mut state, validator) = init_storage_with_validator();
+        let native_token = state.get_native_token().expect("Test failed");
+        let author = established_address_1();
+        let id = init_proposal(&mut state, Epoch(0), Epoch(1));
+        vote_as_validator(&mut state, &validator, id, ProposalVote::Yay);
+        state
+            .write(
+                &namada_token::storage_key::balance_key(&native_token, &author),
+                token::Amount::max(),
+            )
+            .expect("Test failed");
+
+        let mut events = vec![];
+        let result = execute_governance_proposals::<_, Token, PoS, _, _, _>(
+            &mut state,
+            &mut events,
+            BTreeSet::from([id]),
+            |_, _, _| Ok((ProposalCodeResult::Accepted, BTreeSet::new())),
+            |_, _, _, _| Ok(()),
+            &[],
+            |_, _| {},
+            false,
+        );
+
+        assert!(result.is_err());
+        assert_eq!(
+            namada_token::read_balance(&state, &native_token, &author)
+                .expect("Test failed"),
+            token::Amount::max()
+        );
+        assert_eq!(
+            namada_token::read_balance(&state, &native_token, &GOV_ADDRESS)
+                .expect("Test failed"),
+            token::Amount::native_whole(500)
+        );
+    }
+
+    /// Test that a rejected proposal refunds the configured fraction of its
+    /// locked funds to its author, and burns the remainder.
+    #[test]
+    fn test_rejected_proposal_partial_refund() {
+        let (mut state, validator) = init_storage_with_validator();
+        GovernanceParameters {
+            rejection_refund_fraction: Dec::new(5, 1).expect("Test failed"),
+            ..Default::default()
+        }
+        .init_storage(&mut state)
+        .expect("Test failed");
+        let native_token = state.get_native_token().expect("Test failed");
+        let author = established_address_1();
+        let id = init_proposal(&mut state, Epoch(0), Epoch(1));
+        vote_as_validator(&mut state, &validator, id, ProposalVote::Nay);
+        let balance_pre =
+            namada_token::read_balance(&state, &native_token, &author)
+                .expect("Test failed");
+        let supply_pre = namada_token::read_total_supply(&state, &native_token)
+            .expect("Test failed");
+
+        let (events, _) = execute_proposals(&mut state, BTreeSet::from([id]));
+
+        let refunded_amount = token::Amount::native_whole(250);
+        assert_eq!(
+            events,
+            vec![Event::from(GovernanceEvent::ended_proposal(
+                id,
+                TallyOutcome::Rejected {
+                    reason: RejectionReason::NotEnoughYayVotes,
+                },
+                false,
+                author.clone(),
+                token::Amount::native_whole(500),
+                refunded_amount
+            ))]
+        );
+        assert_eq!(
+            namada_token::read_balance(&state, &native_token, &author)
+                .expect("Test failed"),
+            balance_pre
+                .checked_add(refunded_amount)
+                .expect("Test failed")
+        );
+        assert_eq!(
+            namada_token::read_total_supply(&state, &native_token)
+                .expect("Test failed"),
+            supply_pre
+                .checked_sub(token::Amount::native_whole(250))
+                .expect("Test failed")
+        );
+        assert_eq!(
+            namada_token::read_balance(&state, &native_token, &GOV_ADDRESS)
+                .expect("Test failed"),
+            token::Amount::zero()
+        );
+    }
+
+    /// Test that the outcome of the tally of a proposal distinguishes passed
+    /// proposals, rejected proposals and proposals that didn't meet the
+    /// quorum, and that it is carried over to the emitted events.
+    #[test]
+    fn test_proposal_tally_outcomes() {
+        let (mut state, validator) = init_storage_with_validator();
+        let native_token = state.get_native_token().expect("Test failed");
+        let author = established_address_1();
+        namada_token::credit_tokens(
+            &mut state,
+            &native_token,
+            &author,
+            token::Amount::native_whole(500),
+        )
+        .expect("Test failed");
+        let [passed_id, rejected_id, no_quorum_id] =
+            [(); 3].map(|()| init_proposal(&mut state, Epoch(0), Epoch(1)));
+        vote_as_validator(&mut state, &validator, passed_id, ProposalVote::Yay);
+        vote_as_validator(
+            &mut state,
+            &validator,
+            rejected_id,
+            ProposalVote::Nay,
+        );
+
+        let (events, proposals_result) = execute_proposals(
+            &mut state,
+            BTreeSet::from([passed_id, rejected_id, no_quorum_id]),
+        );
+
+        let rejected = TallyOutcome::Rejected {
+            reason: RejectionReason::NotEnoughYayVotes,
+        };
+        assert_eq!(
+            proposals_result.outcomes,
+            BTreeMap::from([
+                (passed_id, TallyOutcome::Passed),
+                (rejected_id, rejected),
+                (no_quorum_id, TallyOutcome::QuorumNotMet),
+            ])
+        );
+        assert_eq!(
+            events,
+            vec![
+                Event::from(GovernanceEvent::ended_proposal(
+                    passed_id,
+                    TallyOutcome::Passed,
+                    false,
+                    author.clone(),
+                    token::Amount::native_whole(500),
+                    token::Amount::native_whole(500)
+                )),
+                Event::from(GovernanceEvent::ended_proposal(
+                    rejected_id,
+                    rejected,
+                    false,
+                    author.clone(),
+                    token::Amount::native_whole(500),
+                    token::Amount::zero()
+                )),
+                Event::from(GovernanceEvent::ended_proposal(
+                    no_quorum_id,
+                    TallyOutcome::QuorumNotMet,
+                    false,
+                    author.clone(),
+                    token::Amount::native_whole(500),
+                    token::Amount::zero()
+                )),
+            ]
+        );
+    }
+
+    /// Test that the events of ended proposals are either emitted one per
+    /// proposal, or batched into a single event per block carrying the
+    /// outcomes of both the retried and the newly executed proposals.
+    #[test]
+    fn test_proposal_events_batching() {
+        for batch_events in [false, true] {
+            let (mut state, validator) = init_storage_with_validator();
+            let native_token = state.get_native_token().expect("Test failed");
+            namada_token::credit_tokens(
+                &mut state,
+                &native_token,
+                &established_address_1(),
+                token::Amount::native_whole(1_000),
+            )
+            .expect("Test failed");
+            let [passed_id, rejected_id, no_quorum_id] =
+                [(); 3].map(|()| init_proposal(&mut state, Epoch(0), Epoch(1)));
+            vote_as_validator(
+                &mut state,
+                &validator,
+                passed_id,
+                ProposalVote::Yay,
+            );
+            vote_as_validator(
+                &mut state,
+                &validator,
+                rejected_id,
+                ProposalVote::Nay,
+            );
+            // a passed proposal whose code is retried in this block
+            let retried_id = init_proposal_with_type(
+                &mut state,
+                ProposalType::DefaultWithWasm(Hash::default()),
+                Some(vec![]),
+                Epoch(0),
+                Epoch(1),
+            );
+            storage::write_proposal_executed(&mut state, retried_id)
+                .expect("Test failed");
+            state
+                .write(&keys::get_proposal_retry_count_key(retried_id), 0_u64)
+                .expect("Test failed");
+
+            let mut events: Vec<Event> = vec![];
+            finalize_block::<_, Token, PoS, _, _, _>(
+                &mut state,
+                &mut events,
+                Epoch(2),
+                true,
+                |_, _, _| Ok((ProposalCodeResult::Accepted, BTreeSet::new())),
+                |_, _, _, _| Ok(()),
+                &[],
+                |_, _| {},
+                batch_events,
+            )
+            .expect("Test failed");
+
+            if batch_events {
+                assert_eq!(
+                    events,
+                    vec![Event::from(GovernanceEvent::ended_proposals(
+                        BTreeMap::from([
+                            (passed_id, TallyOutcome::Passed),
+                            (
+                                rejected_id,
+                                TallyOutcome::Rejected {
+                                    reason: RejectionReason::NotEnoughYayVotes,
+                                }
+                            ),
+                            (no_quorum_id, TallyOutcome::QuorumNotMet),
+                            (retried_id, TallyOutcome::Passed),
+                        ])
+                    ))]
+                );
+            } else {
+                assert_eq!(events.len(), 4);
+            }
+        }
+    }
+
+    /// Test that the funds of a passed proposal are delivered to its funds
+    /// recipient, when it was given one, instead of its author.
+    #[test]
+    fn test_passed_proposal_funds_go_to_recipient() {
+        let mut state = init_storage();
+        namada_proof_of_stake::storage::write_pos_params(
+            &mut state,
+            &namada_proof_of_stake::OwnedPosParams::default(),
+        )
+        .expect("Test failed");
+        let native_token = state.get_native_token().expect("Test failed");
+        let author = established_address_1();
+        let recipient = established_address_2();
+        let proposal = InitProposalData {
+            content: Hash::default(),
+            author: author.clone(),
+            r#type: ProposalType::Default,
+            voting_start_epoch: Epoch(0),
+            voting_end_epoch: Epoch(1),
+            activation_epoch: Epoch(2),
+        };
+        let id = storage::init_proposal::<_, Token>(
+            &mut state,
+            &proposal,
+            vec![],
+            None,
+        )
+        .expect("Test failed");
+        storage::write_proposal_funds_recipient(&mut state, id, &recipient)
+            .expect("Test failed");
+
+        let (_, proposals_result) =
+            execute_proposals(&mut state, BTreeSet::from([id]));
+
+        assert_eq!(
+            proposals_result.outcomes,
+            BTreeMap::from([(id, TallyOutcome::Passed)])
+        );
+        assert_eq!(
+            namada_token::read_balance(&state, &native_token, &recipient)
+                .expect("Test failed"),
+            token::Amount::native_whole(500)
+        );
+        assert_eq!(
+            namada_token::read_balance(&state, &native_token, &author)
+                .expect("Test failed"),
+            token::Amount::native_whole(500)
+        );
+        assert_eq!(
+            namada_token::read_balance(&state, &native_token, &GOV_ADDRESS)
+                .expect("Test failed"),
+            token::Amount::zero()
+        );
+    }
+
+    /// Test that a proposal that is enqueued for execution a second time is
+    /// skipped.
+    #[test]
+    fn test_proposal_is_not_executed_twice() {
+        let mut state = init_storage();
+        namada_proof_of_stake::storage::write_pos_params(
+            &mut state,
+            &namada_proof_of_stake::OwnedPosParams::default(),
+        )
+        .expect("Test failed");
+        let native_token = state.get_native_token().expect("Test failed");
+        let author = established_address_1();
+        let id = init_proposal_with_type(
+            &mut state,
+            ProposalType::DefaultWithWasm(Hash::default()),
+            Some(vec![]),
+            Epoch(0),
+            Epoch(1),
+        );
+
+        let mut executions = 0_u64;
+        for _ in 0..2 {
+            let mut events = vec![];
+            execute_governance_proposals::<_, Token, PoS, _, _, _>(
+                &mut state,
+                &mut events,
+                BTreeSet::from([id]),
+                |_, _, _| {
+                    executions =
+                        executions.checked_add(1).expect("Test failed");
+                    Ok((ProposalCodeResult::Accepted, BTreeSet::new()))
+                },
+                |_, _, _, _| Ok(()),
+                &[],
+                |_, _| {},
+                false,
+            )
+            .expect("Test failed");
+        }
+
+        assert_eq!(executions, 1);
+        assert!(storage::is_proposal_executed(&state, id).expect("Test failed"));
+        assert_eq!(
+            namada_token::read_balance(&state, &native_token, &author)
+                .expect("Test failed"),
+            token::Amount::native_whole(1_000)
+        );
+    }
+
+    /// Test that tallying a proposal returns its projected result without
+    /// mutating storage.
+    #[test]
+    fn test_tally_proposal_is_read_only() {
+        let (mut state, validator) = init_storage_with_validator();
+        let native_token = state.get_native_token().expect("Test failed");
+        let author = established_address_1();
+        let id = init_proposal(&mut state, Epoch(0), Epoch(1));
+        vote_as_validator(&mut state, &validator, id, ProposalVote::Nay);
+        let balance_pre =
+            namada_token::read_balance(&state, &native_token, &author)
+                .expect("Test failed");
+
+        let (result, has_code) =
+            tally_proposal::<_, PoS>(&state, id).expect("Test failed");
+
+        assert_matches!(result, TallyResult::Rejected);
+        assert!(!has_code);
+        assert!(state.write_log().get_events().next().is_none());
+        assert!(
+            storage::get_proposal_result(&state, id)
+                .expect("Test failed")
+                .is_none()
+        );
+        assert_eq!(
+            namada_token::read_balance(&state, &native_token, &author)
+                .expect("Test failed"),
+            balance_pre
+        );
+    }
+
+    /// Test that the governance parameters introduced after genesis default
+    /// when they are missing from storage, rather than halting the chain.
+    #[test]
+    fn test_get_parameters_defaults_missing_keys() {
+        let mut state = TestState::default();
+        let params = GovernanceParameters {
+            max_proposal_execution_gas: 1,
+            rejection_refund_fraction: Dec::one(),
+            min_proposal_quorum: Dec::one(),
+            max_proposal_code_retries: 1,
+            ..Default::default()
+        };
+        params.init_storage(&mut state).expect("Test failed");
+        assert_eq!(
+            storage::get_parameters(&state).expect("Test failed"),
+            params
+        );
+
+        for key in [
+            keys::get_max_proposal_execution_gas_key(),
+            keys::get_rejection_refund_fraction_key(),
+            keys::get_min_proposal_quorum_key(),
+            keys::get_max_proposal_code_retries_key(),
+        ] {
+            state.delete(&key).expect("Test failed");
+        }
+        let defaults = GovernanceParameters::default();
+        assert_eq!(
+            storage::get_parameters(&state).expect("Test failed"),
+            GovernanceParameters {
+                max_proposal_execution_gas: defaults.max_proposal_execution_gas,
+                rejection_refund_fraction: defaults.rejection_refund_fraction,
+                min_proposal_quorum: defaults.min_proposal_quorum,
+                max_proposal_code_retries: defaults.max_proposal_code_retries,
+                ..params
+            }
+        );
+    }
+}
@@ -35,13 +35,21 @@ pub mod storage;
 pub mod utils;
 pub mod vp;
 
-pub use finalize_block::finalize_block;
+pub use finalize_block::{
+    finalize_block, tally_proposal, ProposalChangedKeys, ProposalCodeResult,
+    ProposalsResult,
+};
 use namada_state::{StorageRead, StorageWrite};
 pub use namada_systems::governance::*;
 use parameters::GovernanceParameters;
-pub use storage::proposal::{InitProposalData, ProposalType, VoteProposalData};
+pub use storage::proposal::{
+    InitProposalData, InitProposalDataV1, ProposalType, VoteProposalData,
+};
 pub use storage::vote::ProposalVote;
-pub use storage::{init_proposal, is_proposal_accepted, vote_proposal};
+pub use storage::{
+    init_proposal, is_proposal_accepted, vote_proposal,
+    write_proposal_funds_recipient,
+};
 
 /// The governance internal address
 pub const ADDRESS: Address = address::GOV;
@@ -1,13 +1,15 @@
-use namada_core::borsh::{BorshDeserialize, BorshSerialize};
+use namada_core::borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
+use namada_core::dec::Dec;
 use namada_core::token;
 use namada_macros::BorshDeserializer;
 #[cfg(feature = "migrations")]
 use namada_migrations::*;
 use namada_state::{Result, StorageRead, StorageWrite};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use super::storage::keys as goverance_storage;
 
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(
     Clone,
     Debug,
@@ -19,7 +21,9 @@ use super::storage::keys as goverance_storage;
     BorshSerialize,
     BorshDeserialize,
     BorshDeserializer,
+    BorshSchema,
     Serialize,
+    Deserialize,
 )]
 /// Governance parameter structure
 pub struct GovernanceParameters {
@@ -38,6 +42,20 @@ pub struct GovernanceParameters {
     pub min_proposal_grace_epochs: u64,
     /// Maximum number of epochs between current epoch and start epoch
     pub max_proposal_latency: u64,
+    /// Maximum amount of gas (in whole gas units) that the code attached to
+    /// a proposal can consume when executed
+    pub max_proposal_execution_gas: u64,
+    /// Fraction of the locked funds of a rejected proposal that is refunded
+    /// to its author, the remainder being burnt
+    pub rejection_refund_fraction: Dec,
+    /// Minimum fraction of the total voting power that must participate in
+    /// a proposal's vote for it to be able to pass, regardless of its tally
+    /// type
+    pub min_proposal_quorum: Dec,
+    /// Maximum number of times that the code of a passed proposal rejected
+    /// by a VP is executed again, in subsequent blocks, before the proposal
+    /// is deemed to have failed
+    pub max_proposal_code_retries: u64,
 }
 
 impl Default for GovernanceParameters {
@@ -50,6 +68,10 @@ impl Default for GovernanceParameters {
             max_proposal_content_size: 10_000,
             min_proposal_grace_epochs: 6,
             max_proposal_latency: 30,
+            max_proposal_execution_gas: 30_000_000,
+            rejection_refund_fraction: Dec::zero(),
+            min_proposal_quorum: Dec::zero(),
+            max_proposal_code_retries: 0,
         }
     }
 }
@@ -57,6 +79,18 @@ impl Default for GovernanceParameters {
 impl GovernanceParameters {
     /// Initialize governance parameters into storage
     pub fn init_storage<S>(&self, storage: &mut S) -> Result<()>
+    where
+        S: StorageRead + StorageWrite,
+    {
+        self.write_storage(storage)?;
+
+        let counter_key = goverance_storage::get_counter_key();
+        storage.write(&counter_key, u64::MIN)
+    }
+
+    /// Write the governance parameters into storage, overwriting the ones
+    /// currently in effect
+    pub fn write_storage<S>(&self, storage: &mut S) -> Result<()>
     where
         S: StorageRead + StorageWrite,
     {
@@ -68,6 +102,10 @@ impl GovernanceParameters {
             max_proposal_content_size,
             min_proposal_grace_epochs,
             max_proposal_latency,
+            max_proposal_execution_gas,
+            rejection_refund_fraction,
+            min_proposal_quorum,
+            max_proposal_code_retries,
         } = self;
 
         let min_proposal_fund_key =
@@ -103,7 +141,64 @@ impl GovernanceParameters {
             goverance_storage::get_max_proposal_latency_key();
         storage.write(&max_proposal_latency_key, max_proposal_latency)?;
 
-        let counter_key = goverance_storage::get_counter_key();
-        storage.write(&counter_key, u64::MIN)
+        let max_proposal_execution_gas_key =
+            goverance_storage::get_max_proposal_execution_gas_key();
+        storage.write(
+            &max_proposal_execution_gas_key,
+            max_proposal_execution_gas,
+        )?;
+        // Proposals ending from the current epoch onward are executed under
+        // the new limit, while those that ended before keep their own
+        let max_code_gas_key =
+            goverance_storage::get_max_code_gas_key(storage.get_block_epoch()?);
+        storage.write(&max_code_gas_key, max_proposal_execution_gas)?;
+
+        let rejection_refund_fraction_key =
+            goverance_storage::get_rejection_refund_fraction_key();
+        storage
+            .write(&rejection_refund_fraction_key, rejection_refund_fraction)?;
+
+        let min_proposal_quorum_key =
+            goverance_storage::get_min_proposal_quorum_key();
+        storage.write(&min_proposal_quorum_key, min_proposal_quorum)?;
+
+        let max_proposal_code_retries_key =
+            goverance_storage::get_max_proposal_code_retries_key();
+        storage.write(&max_proposal_code_retries_key, max_proposal_code_retries)
+    }
+
+    /// Check that the parameters are consistent, returning a description of
+    /// the first inconsistency found otherwise
+    pub fn validate(&self) -> std::result::Result<(), String> {
+        let is_fraction = |fraction: &Dec| {
+            *fraction >= Dec::zero() && *fraction <= Dec::one()
+        };
+        if !is_fraction(&self.rejection_refund_fraction) {
+            return Err(format!(
+                "The rejection refund fraction {} is not between 0 and 1",
+                self.rejection_refund_fraction
+            ));
+        }
+        if !is_fraction(&self.min_proposal_quorum) {
+            return Err(format!(
+                "The min proposal quorum {} is not between 0 and 1",
+                self.min_proposal_quorum
+            ));
+        }
+        let min_proposal_period = self
+            .min_proposal_voting_period
+            .checked_add(self.min_proposal_grace_epochs);
+        if min_proposal_period.map_or(true, |min_proposal_period| {
+            min_proposal_period > self.max_proposal_period
+        }) {
+            return Err(format!(
+                "The max proposal period {} is shorter than the min proposal \
+                 voting period {} plus the min proposal grace epochs {}",
+                self.max_proposal_period,
+                self.min_proposal_voting_period,
+                self.min_proposal_grace_epochs
+            ));
+        }
+        Ok(())
     }
 }
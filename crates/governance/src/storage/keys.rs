@@ -1,4 +1,5 @@
 use namada_core::address::Address;
+use namada_core::chain::Epoch;
 use namada_core::storage::{DbKeySeg, Key, KeySeg};
 use namada_macros::StorageKeys;
 
@@ -16,6 +17,7 @@ struct Keys {
     end_epoch: &'static str,
     activation_epoch: &'static str,
     funds: &'static str,
+    funds_recipient: &'static str,
     proposal_code: &'static str,
     committing_epoch: &'static str,
     min_fund: &'static str,
@@ -24,10 +26,18 @@ struct Keys {
     max_period: &'static str,
     max_content: &'static str,
     max_latency: &'static str,
+    max_execution_gas: &'static str,
+    max_code_gas: &'static str,
+    rejection_refund_fraction: &'static str,
+    min_quorum: &'static str,
+    max_code_retries: &'static str,
+    retry_count: &'static str,
     min_grace_epochs: &'static str,
     counter: &'static str,
     pending: &'static str,
     result: &'static str,
+    tally_fractions: &'static str,
+    executed: &'static str,
 }
 
 /// Check if key is inside governance address space
@@ -145,6 +155,24 @@ pub fn is_balance_key(key: &Key) -> bool {
     }
 }
 
+/// Check if key is the key of the recipient of the funds of a proposal
+pub fn is_funds_recipient_key(key: &Key) -> bool {
+    match &key.segments[..] {
+        [
+            DbKeySeg::AddressSeg(addr),
+            DbKeySeg::StringSeg(prefix),
+            DbKeySeg::StringSeg(id),
+            DbKeySeg::StringSeg(funds_recipient),
+        ] if addr == &ADDRESS
+            && prefix == Keys::VALUES.proposal
+            && funds_recipient == Keys::VALUES.funds_recipient =>
+        {
+            id.parse::<u64>().is_ok()
+        }
+        _ => false,
+    }
+}
+
 /// Check if key is start epoch key
 pub fn is_start_epoch_key(key: &Key) -> bool {
     match &key.segments[..] {
@@ -271,6 +299,64 @@ pub fn is_min_grace_epochs_key(key: &Key) -> bool {
                     && min_grace_epochs_param == Keys::VALUES.min_grace_epochs)
 }
 
+/// Check if key is a max proposal execution gas key
+pub fn is_max_proposal_execution_gas_key(key: &Key) -> bool {
+    matches!(&key.segments[..], [
+             DbKeySeg::AddressSeg(addr),
+             DbKeySeg::StringSeg(max_execution_gas_param),
+         ] if addr == &ADDRESS
+             && max_execution_gas_param == Keys::VALUES.max_execution_gas)
+}
+
+/// Check if key is an epoch indexed max proposal code gas key
+pub fn is_max_code_gas_key(key: &Key) -> bool {
+    get_max_code_gas_epoch(key).is_some()
+}
+
+/// Get the epoch from which the max proposal code gas stored under the
+/// given key is in effect
+pub fn get_max_code_gas_epoch(key: &Key) -> Option<Epoch> {
+    match &key.segments[..] {
+        [
+            DbKeySeg::AddressSeg(addr),
+            DbKeySeg::StringSeg(max_code_gas_param),
+            DbKeySeg::StringSeg(epoch),
+        ] if addr == &ADDRESS
+            && max_code_gas_param == Keys::VALUES.max_code_gas =>
+        {
+            epoch.parse::<u64>().ok().map(Epoch)
+        }
+        _ => None,
+    }
+}
+
+/// Check if key is a rejection refund fraction key
+pub fn is_rejection_refund_fraction_key(key: &Key) -> bool {
+    matches!(&key.segments[..], [
+             DbKeySeg::AddressSeg(addr),
+             DbKeySeg::StringSeg(refund_fraction_param),
+         ] if addr == &ADDRESS
+             && refund_fraction_param == Keys::VALUES.rejection_refund_fraction)
+}
+
+/// Check if key is a min proposal quorum key
+pub fn is_min_proposal_quorum_key(key: &Key) -> bool {
+    matches!(&key.segments[..], [
+             DbKeySeg::AddressSeg(addr),
+             DbKeySeg::StringSeg(min_quorum_param),
+         ] if addr == &ADDRESS
+             && min_quorum_param == Keys::VALUES.min_quorum)
+}
+
+/// Check if key is a max proposal code retries key
+pub fn is_max_proposal_code_retries_key(key: &Key) -> bool {
+    matches!(&key.segments[..], [
+             DbKeySeg::AddressSeg(addr),
+             DbKeySeg::StringSeg(max_code_retries_param),
+         ] if addr == &ADDRESS
+             && max_code_retries_param == Keys::VALUES.max_code_retries)
+}
+
 /// Check if key is parameter key
 pub fn is_parameter_key(key: &Key) -> bool {
     is_min_proposal_fund_key(key)
@@ -279,6 +365,11 @@ pub fn is_parameter_key(key: &Key) -> bool {
         || is_min_proposal_voting_period_key(key)
         || is_max_proposal_period_key(key)
         || is_min_grace_epochs_key(key)
+        || is_max_proposal_execution_gas_key(key)
+        || is_max_code_gas_key(key)
+        || is_rejection_refund_fraction_key(key)
+        || is_min_proposal_quorum_key(key)
+        || is_max_proposal_code_retries_key(key)
 }
 
 /// Check if key is start epoch or end epoch key
@@ -335,6 +426,78 @@ pub fn get_max_proposal_latency_key() -> Key {
         .expect("Cannot obtain a storage key")
 }
 
+/// Get maximum proposal execution gas key
+pub fn get_max_proposal_execution_gas_key() -> Key {
+    Key::from(ADDRESS.to_db_key())
+        .push(&Keys::VALUES.max_execution_gas.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Get the prefix of the epoch indexed max proposal code gas keys
+pub fn get_max_code_gas_prefix() -> Key {
+    Key::from(ADDRESS.to_db_key())
+        .push(&Keys::VALUES.max_code_gas.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Get the key of the max proposal code gas in effect from the given epoch
+pub fn get_max_code_gas_key(epoch: Epoch) -> Key {
+    get_max_code_gas_prefix()
+        .push(&epoch.0.to_string())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Get rejection refund fraction key
+pub fn get_rejection_refund_fraction_key() -> Key {
+    Key::from(ADDRESS.to_db_key())
+        .push(&Keys::VALUES.rejection_refund_fraction.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Get min proposal quorum key
+pub fn get_min_proposal_quorum_key() -> Key {
+    Key::from(ADDRESS.to_db_key())
+        .push(&Keys::VALUES.min_quorum.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Get max proposal code retries key
+pub fn get_max_proposal_code_retries_key() -> Key {
+    Key::from(ADDRESS.to_db_key())
+        .push(&Keys::VALUES.max_code_retries.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Get the prefix of the keys of the proposals whose code is pending a retry
+pub fn get_proposal_retry_count_prefix() -> Key {
+    Key::from(ADDRESS.to_db_key())
+        .push(&Keys::VALUES.retry_count.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Get the key of the number of times that the code of a proposal pending a
+/// retry has already been retried
+pub fn get_proposal_retry_count_key(id: u64) -> Key {
+    get_proposal_retry_count_prefix()
+        .push(&id.to_string())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Get the id of the proposal whose retry count is stored under the given
+/// key
+pub fn get_proposal_retry_id(key: &Key) -> Option<u64> {
+    match &key.segments[..] {
+        [
+            DbKeySeg::AddressSeg(addr),
+            DbKeySeg::StringSeg(retry_count),
+            DbKeySeg::StringSeg(id),
+        ] if addr == &ADDRESS && retry_count == Keys::VALUES.retry_count => {
+            id.parse::<u64>().ok()
+        }
+        _ => None,
+    }
+}
+
 /// Get min grace epochs proposal key
 pub fn get_min_proposal_grace_epochs_key() -> Key {
     Key::from(ADDRESS.to_db_key())
@@ -403,6 +566,15 @@ pub fn get_funds_key(id: u64) -> Key {
         .expect("Cannot obtain a storage key")
 }
 
+/// Get key of the optional recipient of the funds of a passed proposal
+pub fn get_funds_recipient_key(id: u64) -> Key {
+    proposal_prefix()
+        .push(&id.to_string())
+        .expect("Cannot obtain a storage key")
+        .push(&Keys::VALUES.funds_recipient.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
 /// Get proposal activation epoch key
 pub fn get_activation_epoch_key(id: u64) -> Key {
     proposal_prefix()
@@ -477,6 +649,24 @@ pub fn get_proposal_result_key(id: u64) -> Key {
         .expect("Cannot obtain a storage key")
 }
 
+/// Get the proposal tally fractions key
+pub fn get_proposal_tally_fractions_key(id: u64) -> Key {
+    proposal_prefix()
+        .push(&id.to_string())
+        .expect("Cannot obtain a storage key")
+        .push(&Keys::VALUES.tally_fractions.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
+/// Get the key of the marker of a proposal that has been executed
+pub fn get_proposal_executed_key(id: u64) -> Key {
+    proposal_prefix()
+        .push(&id.to_string())
+        .expect("Cannot obtain a storage key")
+        .push(&Keys::VALUES.executed.to_owned())
+        .expect("Cannot obtain a storage key")
+}
+
 /// Get proposal id from key
 pub fn get_proposal_id(key: &Key) -> Option<u64> {
     match key.get_at(2) {
@@ -13,6 +13,7 @@ use namada_core::address::Address;
 use namada_core::borsh::BorshDeserialize;
 use namada_core::chain::Epoch;
 use namada_core::collections::HashSet;
+use namada_core::dec::Dec;
 use namada_core::hash::Hash;
 use namada_core::token;
 use namada_state::{iter_prefix, Error, Result, StorageRead, StorageWrite};
@@ -24,7 +25,7 @@ use crate::storage::proposal::{
     InitProposalData, ProposalType, StorageProposal, VoteProposalData,
 };
 use crate::storage::vote::ProposalVote;
-use crate::utils::{ProposalResult, Vote};
+use crate::utils::{ProposalResult, TallyFractions, Vote};
 use crate::ADDRESS as governance_address;
 
 /// A proposal creation transaction.
@@ -144,6 +145,43 @@ where
     storage.write(&proposal_result_key, proposal_result)
 }
 
+/// Mark a proposal as executed, such that it can't be executed again.
+pub fn write_proposal_executed<S>(
+    storage: &mut S,
+    proposal_id: u64,
+) -> Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    let proposal_executed_key =
+        governance_keys::get_proposal_executed_key(proposal_id);
+    storage.write(&proposal_executed_key, ())
+}
+
+/// Check if a proposal has already been executed.
+pub fn is_proposal_executed<S>(storage: &S, proposal_id: u64) -> Result<bool>
+where
+    S: StorageRead,
+{
+    let proposal_executed_key =
+        governance_keys::get_proposal_executed_key(proposal_id);
+    storage.has_key(&proposal_executed_key)
+}
+
+/// Write the tally fractions of a proposal result to storage.
+pub fn write_proposal_tally_fractions<S>(
+    storage: &mut S,
+    proposal_id: u64,
+    tally_fractions: TallyFractions,
+) -> Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    let tally_fractions_key =
+        governance_keys::get_proposal_tally_fractions_key(proposal_id);
+    storage.write(&tally_fractions_key, tally_fractions)
+}
+
 /// Read a proposal by id from storage
 pub fn get_proposal_by_id<S>(
     storage: &S,
@@ -267,14 +305,38 @@ where
     storage.read::<Address>(&proposal_author_key)
 }
 
+/// Set the recipient of the funds of a proposal if it passes. Must be written
+/// by the transaction that initializes the proposal.
+pub fn write_proposal_funds_recipient<S>(
+    storage: &mut S,
+    proposal_id: u64,
+    funds_recipient: &Address,
+) -> Result<()>
+where
+    S: StorageWrite,
+{
+    let key = governance_keys::get_funds_recipient_key(proposal_id);
+    storage.write(&key, funds_recipient)
+}
+
+/// Get the recipient of the funds of a passed proposal, if it was given one
+pub fn get_proposal_funds_recipient<S>(
+    storage: &S,
+    proposal_id: u64,
+) -> Result<Option<Address>>
+where
+    S: StorageRead,
+{
+    let key = governance_keys::get_funds_recipient_key(proposal_id);
+    storage.read(&key)
+}
+
 /// Get governance parameters
 pub fn get_parameters<S>(storage: &S) -> Result<GovernanceParameters>
 where
     S: StorageRead,
 {
-    let key = governance_keys::get_max_proposal_code_size_key();
-    let max_proposal_code_size: u64 =
-        storage.read(&key)?.expect("Parameter should be defined.");
+    let max_proposal_code_size: u64 = get_max_proposal_code_size(storage)?;
 
     let key = governance_keys::get_max_proposal_content_key();
     let max_proposal_content_size: u64 =
@@ -298,6 +360,17 @@ where
     let max_proposal_latency: u64 =
         storage.read(&key)?.expect("Parameter should be defined.");
 
+    let max_proposal_execution_gas: u64 =
+        get_max_proposal_execution_gas(storage)?;
+
+    let rejection_refund_fraction: Dec =
+        get_rejection_refund_fraction(storage)?;
+
+    let min_proposal_quorum: Dec = get_min_proposal_quorum(storage)?;
+
+    let max_proposal_code_retries: u64 =
+        get_max_proposal_code_retries(storage)?;
+
     Ok(GovernanceParameters {
         min_proposal_fund,
         max_proposal_code_size,
@@ -306,9 +379,24 @@ where
         max_proposal_content_size,
         min_proposal_grace_epochs,
         max_proposal_latency,
+        max_proposal_execution_gas,
+        rejection_refund_fraction,
+        min_proposal_quorum,
+        max_proposal_code_retries,
     })
 }
 
+/// Get governance "max_proposal_code_size" parameter
+pub fn get_max_proposal_code_size<S>(storage: &S) -> Result<u64>
+where
+    S: StorageRead,
+{
+    let key = governance_keys::get_max_proposal_code_size_key();
+    let max_proposal_code_size: u64 =
+        storage.read(&key)?.expect("Parameter should be defined.");
+    Ok(max_proposal_code_size)
+}
+
 /// Get governance "max_proposal_period" parameter
 pub fn get_max_proposal_period<S>(storage: &S) -> Result<u64>
 where
@@ -320,6 +408,112 @@ where
     Ok(max_proposal_period)
 }
 
+/// Get governance "max_proposal_execution_gas" parameter
+///
+/// If the parameter has not been written to storage, e.g. on a chain
+/// initialized before it was introduced, its default value is returned.
+pub fn get_max_proposal_execution_gas<S>(storage: &S) -> Result<u64>
+where
+    S: StorageRead,
+{
+    let key = governance_keys::get_max_proposal_execution_gas_key();
+    let max_proposal_execution_gas: Option<u64> = storage.read(&key)?;
+    Ok(max_proposal_execution_gas.unwrap_or_else(|| {
+        GovernanceParameters::default().max_proposal_execution_gas
+    }))
+}
+
+/// Get the maximum amount of gas that the code of a proposal can consume,
+/// as in effect at the given epoch.
+///
+/// This is the value written under [`governance_keys::get_max_code_gas_key`]
+/// for the latest epoch not after `epoch`. If no such value has been written,
+/// the "max_proposal_execution_gas" parameter is returned instead.
+pub fn get_max_code_gas<S>(storage: &S, epoch: Epoch) -> Result<u64>
+where
+    S: StorageRead,
+{
+    let prefix = governance_keys::get_max_code_gas_prefix();
+    let mut in_effect: Option<(Epoch, u64)> = None;
+    for result in iter_prefix::<u64>(storage, &prefix)? {
+        let (key, max_gas) = result?;
+        let Some(from_epoch) = governance_keys::get_max_code_gas_epoch(&key)
+        else {
+            continue;
+        };
+        if from_epoch <= epoch
+            && in_effect.map_or(true, |(latest, _)| from_epoch > latest)
+        {
+            in_effect = Some((from_epoch, max_gas));
+        }
+    }
+    match in_effect {
+        Some((_, max_gas)) => Ok(max_gas),
+        None => get_max_proposal_execution_gas(storage),
+    }
+}
+
+/// Get governance "rejection_refund_fraction" parameter
+///
+/// If the parameter has not been written to storage, e.g. on a chain
+/// initialized before it was introduced, its default value is returned.
+pub fn get_rejection_refund_fraction<S>(storage: &S) -> Result<Dec>
+where
+    S: StorageRead,
+{
+    let key = governance_keys::get_rejection_refund_fraction_key();
+    let rejection_refund_fraction: Option<Dec> = storage.read(&key)?;
+    Ok(rejection_refund_fraction.unwrap_or_else(|| {
+        GovernanceParameters::default().rejection_refund_fraction
+    }))
+}
+
+/// Get governance "min_proposal_quorum" parameter
+///
+/// If the parameter has not been written to storage, e.g. on a chain
+/// initialized before it was introduced, its default value is returned.
+pub fn get_min_proposal_quorum<S>(storage: &S) -> Result<Dec>
+where
+    S: StorageRead,
+{
+    let key = governance_keys::get_min_proposal_quorum_key();
+    let min_proposal_quorum: Option<Dec> = storage.read(&key)?;
+    Ok(min_proposal_quorum
+        .unwrap_or_else(|| GovernanceParameters::default().min_proposal_quorum))
+}
+
+/// Get governance "max_proposal_code_retries" parameter
+///
+/// If the parameter has not been written to storage, e.g. on a chain
+/// initialized before it was introduced, its default value is returned.
+pub fn get_max_proposal_code_retries<S>(storage: &S) -> Result<u64>
+where
+    S: StorageRead,
+{
+    let key = governance_keys::get_max_proposal_code_retries_key();
+    let max_proposal_code_retries: Option<u64> = storage.read(&key)?;
+    Ok(max_proposal_code_retries.unwrap_or_else(|| {
+        GovernanceParameters::default().max_proposal_code_retries
+    }))
+}
+
+/// Get the proposals whose code is pending a retry, along with the number of
+/// times that it has already been retried
+pub fn get_proposal_retries<S>(storage: &S) -> Result<BTreeMap<u64, u64>>
+where
+    S: StorageRead,
+{
+    let prefix = governance_keys::get_proposal_retry_count_prefix();
+    let mut retries = BTreeMap::new();
+    for result in iter_prefix::<u64>(storage, &prefix)? {
+        let (key, retry_count) = result?;
+        if let Some(id) = governance_keys::get_proposal_retry_id(&key) {
+            retries.insert(id, retry_count);
+        }
+    }
+    Ok(retries)
+}
+
 /// Get governance proposal result stored in storage if proposal ended
 pub fn get_proposal_result<S>(
     storage: &S,
@@ -333,6 +527,18 @@ where
     Ok(proposal_result)
 }
 
+/// Read the tally fractions of a proposal result by id from storage
+pub fn get_proposal_tally_fractions<S>(
+    storage: &S,
+    proposal_id: u64,
+) -> Result<Option<TallyFractions>>
+where
+    S: StorageRead,
+{
+    let key = governance_keys::get_proposal_tally_fractions_key(proposal_id);
+    storage.read(&key)
+}
+
 /// Load proposals for execution in the current epoch.
 pub fn load_proposals<S>(
     storage: &S,
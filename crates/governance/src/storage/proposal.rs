@@ -19,6 +19,7 @@ use crate::cli::onchain::{
     DefaultProposal, PgfAction, PgfContinuous, PgfFundingProposal, PgfRetro,
     PgfSteward, PgfStewardProposal, StewardsUpdate,
 };
+use crate::parameters::GovernanceParameters;
 use crate::utils::{ProposalStatus, TallyType};
 
 #[allow(missing_docs)]
@@ -66,6 +67,49 @@ impl InitProposalData {
     }
 }
 
+/// A tx data type to hold proposal data along with the recipient of its
+/// locked funds. Its encoding extends the one of [`InitProposalData`], which
+/// remains valid tx data for proposals that refund their author.
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    BorshSchema,
+    BorshSerialize,
+    BorshDeserialize,
+    BorshDeserializer,
+    Serialize,
+    Deserialize,
+)]
+pub struct InitProposalDataV1 {
+    /// The proposal data
+    pub data: InitProposalData,
+    /// The recipient of the locked funds if the proposal passes, instead of
+    /// its author
+    pub funds_recipient: Option<Address>,
+}
+
+impl InitProposalDataV1 {
+    /// Decode tx data encoded either as [`InitProposalDataV1`] or as
+    /// [`InitProposalData`], in which case the proposal has no funds
+    /// recipient
+    pub fn try_from_versioned_slice(bytes: &[u8]) -> std::io::Result<Self> {
+        Self::try_from_slice(bytes).or_else(|_| {
+            InitProposalData::try_from_slice(bytes).map(Self::from)
+        })
+    }
+}
+
+impl From<InitProposalData> for InitProposalDataV1 {
+    fn from(data: InitProposalData) -> Self {
+        Self {
+            data,
+            funds_recipient: None,
+        }
+    }
+}
+
 /// A tx data type to hold vote proposal data
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[derive(
@@ -112,6 +156,18 @@ impl TryFrom<DefaultProposal> for InitProposalData {
     }
 }
 
+impl TryFrom<DefaultProposal> for InitProposalDataV1 {
+    type Error = ProposalError;
+
+    fn try_from(value: DefaultProposal) -> Result<Self, Self::Error> {
+        let funds_recipient = value.funds_recipient.clone();
+        Ok(InitProposalDataV1 {
+            data: InitProposalData::try_from(value)?,
+            funds_recipient,
+        })
+    }
+}
+
 impl TryFrom<PgfStewardProposal> for InitProposalData {
     type Error = ProposalError;
 
@@ -222,6 +278,8 @@ pub enum ProposalType {
     PGFSteward(BTreeSet<AddRemove<Address>>),
     /// PGF funding proposal
     PGFPayment(BTreeSet<PGFAction>),
+    /// Governance parameters change proposal
+    GovernanceParameters(GovernanceParameters),
 }
 
 /// An add or remove action for PGF
@@ -391,6 +449,9 @@ impl ProposalType {
                     .map(|action| format!("\n  {}", &action))
                     .join("")
             ),
+            ProposalType::GovernanceParameters(parameters) => {
+                format!("Parameters: {:?}", parameters)
+            }
         }
     }
 }
@@ -402,6 +463,9 @@ impl Display for ProposalType {
             ProposalType::DefaultWithWasm(_) => write!(f, "Default with Wasm"),
             ProposalType::PGFSteward(_) => write!(f, "PGF steward"),
             ProposalType::PGFPayment(_) => write!(f, "PGF funding"),
+            ProposalType::GovernanceParameters(_) => {
+                write!(f, "Governance parameters")
+            }
         }
     }
 }
@@ -719,3 +783,46 @@ pub mod testing {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use namada_core::address::testing::{
+        established_address_1, established_address_2,
+    };
+    use namada_core::borsh::BorshSerializeExt;
+
+    use super::*;
+
+    /// Test that the tx data of proposals is decoded in both of its
+    /// encodings, and that the original encoding is left unchanged.
+    #[test]
+    fn test_init_proposal_data_versions() {
+        let data = InitProposalData {
+            content: Hash::default(),
+            author: established_address_1(),
+            r#type: ProposalType::Default,
+            voting_start_epoch: Epoch(1),
+            voting_end_epoch: Epoch(4),
+            activation_epoch: Epoch(5),
+        };
+        let v0_bytes = data.serialize_to_vec();
+        assert_eq!(
+            InitProposalDataV1::try_from_versioned_slice(&v0_bytes)
+                .expect("Test failed"),
+            InitProposalDataV1::from(data.clone())
+        );
+
+        let data_v1 = InitProposalDataV1 {
+            data: data.clone(),
+            funds_recipient: Some(established_address_2()),
+        };
+        let v1_bytes = data_v1.serialize_to_vec();
+        assert!(v1_bytes.starts_with(&v0_bytes));
+        assert_eq!(
+            InitProposalDataV1::try_from_versioned_slice(&v1_bytes)
+                .expect("Test failed"),
+            data_v1
+        );
+        assert!(InitProposalData::try_from_slice(&v1_bytes).is_err());
+    }
+}
@@ -94,6 +94,7 @@ impl TallyType {
             (ProposalType::PGFPayment(_), false) => {
                 TallyType::OneHalfOverOneThird
             }
+            (ProposalType::GovernanceParameters(_), _) => TallyType::TwoFifths,
         }
     }
 }
@@ -243,6 +244,207 @@ impl ProposalResult {
         })()
         .unwrap_or_default()
     }
+
+    /// Return true if enough of the total voting power voted on the proposal
+    /// for its tally to be meaningful. Proposals tallied with
+    /// [`TallyType::LessOneHalfOverOneThirdNay`] have no quorum.
+    pub fn is_quorum_met(&self) -> Result<bool, arith::Error> {
+        let quorum = match self.tally_type {
+            TallyType::TwoFifths => {
+                self.total_voting_power.mul_ceil(Dec::two_fifths())?
+            }
+            TallyType::OneHalfOverOneThird => {
+                self.total_voting_power.mul_ceil(Dec::one_third())?
+            }
+            TallyType::LessOneHalfOverOneThirdNay => return Ok(true),
+        };
+        let total_voted_power = checked!(
+            self.total_yay_power
+                + self.total_nay_power
+                + self.total_abstain_power
+        )?;
+        Ok(total_voted_power >= quorum)
+    }
+
+    /// Return true if at least the given fraction of the total voting power
+    /// voted on the proposal, regardless of its tally type.
+    pub fn is_min_quorum_met(
+        &self,
+        min_quorum: Dec,
+    ) -> Result<bool, arith::Error> {
+        let quorum = self.total_voting_power.mul_ceil(min_quorum)?;
+        let total_voted_power = checked!(
+            self.total_yay_power
+                + self.total_nay_power
+                + self.total_abstain_power
+        )?;
+        Ok(total_voted_power >= quorum)
+    }
+
+    /// Compute the outcome of the tally of the proposal as in
+    /// [`Self::tally_outcome`], additionally requiring at least `min_quorum`
+    /// of the total voting power to have voted on it.
+    pub fn tally_outcome_with_min_quorum(
+        &self,
+        min_quorum: Dec,
+    ) -> Result<TallyOutcome, arith::Error> {
+        if !self.is_min_quorum_met(min_quorum)? {
+            return Ok(TallyOutcome::QuorumNotMet);
+        }
+        self.tally_outcome()
+    }
+
+    /// Compute the outcome of the tally of the proposal, before the
+    /// execution of any of its code.
+    pub fn tally_outcome(&self) -> Result<TallyOutcome, arith::Error> {
+        Ok(match self.result {
+            TallyResult::Passed => TallyOutcome::Passed,
+            TallyResult::Rejected if !self.is_quorum_met()? => {
+                TallyOutcome::QuorumNotMet
+            }
+            TallyResult::Rejected => TallyOutcome::Rejected {
+                reason: RejectionReason::NotEnoughYayVotes,
+            },
+        })
+    }
+
+    /// Compute the fractions of the total voting power that voted yay, nay
+    /// and abstain. Returns `None` if the total voting power is zero or if
+    /// any arithmetic fails.
+    pub fn tally_fractions(&self) -> Option<TallyFractions> {
+        let total_voting_power = Dec::try_from(self.total_voting_power).ok()?;
+        if total_voting_power.is_zero() {
+            return None;
+        }
+        let fraction = |voting_power: VotePower| {
+            Dec::try_from(voting_power)
+                .ok()?
+                .checked_div(total_voting_power)
+        };
+        Some(TallyFractions {
+            yay: fraction(self.total_yay_power)?,
+            nay: fraction(self.total_nay_power)?,
+            abstain: fraction(self.total_abstain_power)?,
+        })
+    }
+}
+
+/// The fractions of the total voting power that voted on each side of a
+/// proposal
+#[derive(
+    Clone,
+    Debug,
+    Copy,
+    PartialEq,
+    Eq,
+    BorshDeserialize,
+    BorshSerialize,
+    BorshDeserializer,
+)]
+pub struct TallyFractions {
+    /// The fraction of the total voting power from yay votes
+    pub yay: Dec,
+    /// The fraction of the total voting power from nay votes
+    pub nay: Dec,
+    /// The fraction of the total voting power from abstained votes
+    pub abstain: Dec,
+}
+
+/// The reason why a proposal was rejected
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Ord, PartialOrd, Hash)]
+pub enum RejectionReason {
+    /// The votes cast on the proposal did not meet the threshold required by
+    /// its tally type
+    NotEnoughYayVotes,
+    /// The voting end epoch of the proposal is not after its voting start
+    /// epoch
+    MalformedVotingPeriod,
+}
+
+impl Display for RejectionReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RejectionReason::NotEnoughYayVotes => {
+                write!(f, "not enough yay votes")
+            }
+            RejectionReason::MalformedVotingPeriod => {
+                write!(f, "malformed voting period")
+            }
+        }
+    }
+}
+
+impl FromStr for RejectionReason {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "not enough yay votes" => Ok(Self::NotEnoughYayVotes),
+            "malformed voting period" => Ok(Self::MalformedVotingPeriod),
+            r => Err(format!("Unknown proposal rejection reason {r:?}")),
+        }
+    }
+}
+
+/// The outcome of tallying and executing a governance proposal
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Ord, PartialOrd, Hash)]
+pub enum TallyOutcome {
+    /// The proposal passed, and its code (if any) was successfully executed
+    Passed,
+    /// The proposal passed, but its code failed to be executed
+    PassedExecutionFailed,
+    /// The proposal was rejected
+    Rejected {
+        /// Why the proposal was rejected
+        reason: RejectionReason,
+    },
+    /// Not enough of the total voting power voted on the proposal
+    QuorumNotMet,
+    /// The votes cast on the proposal could not be tallied
+    Error,
+}
+
+impl TallyOutcome {
+    /// Check if the proposal has passed, regardless of the execution of its
+    /// code
+    pub fn has_passed(&self) -> bool {
+        matches!(self, Self::Passed | Self::PassedExecutionFailed)
+    }
+}
+
+impl Display for TallyOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TallyOutcome::Passed => write!(f, "passed"),
+            TallyOutcome::PassedExecutionFailed => {
+                write!(f, "passed, execution failed")
+            }
+            TallyOutcome::Rejected { reason } => {
+                write!(f, "rejected: {reason}")
+            }
+            TallyOutcome::QuorumNotMet => write!(f, "quorum not met"),
+            TallyOutcome::Error => write!(f, "error"),
+        }
+    }
+}
+
+impl FromStr for TallyOutcome {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "passed" => Ok(Self::Passed),
+            "passed, execution failed" => Ok(Self::PassedExecutionFailed),
+            "quorum not met" => Ok(Self::QuorumNotMet),
+            "error" => Ok(Self::Error),
+            o => match o.strip_prefix("rejected: ") {
+                Some(reason) => Ok(Self::Rejected {
+                    reason: reason.parse()?,
+                }),
+                None => Err(format!("Unknown proposal tally outcome {o:?}")),
+            },
+        }
+    }
 }
 
 impl Display for ProposalResult {
@@ -1460,6 +1662,109 @@ mod test {
         assert!(!proposal_result.two_thirds_nay_over_two_thirds_total())
     }
 
+    /// Test that abstain votes count towards the quorum of a proposal, but
+    /// not towards the ratio of yay and nay votes.
+    #[test]
+    fn test_proposal_abstain_meets_quorum_but_fails_yay_ratio() {
+        let total_voting_power = token::Amount::from_u64(100);
+        let yay_validator = address::testing::established_address_1();
+        let nay_validator = address::testing::established_address_2();
+        let abstain_validator = address::testing::established_address_3();
+
+        let mut proposal_votes = ProposalVotes::default();
+        proposal_votes.add_validator(
+            &yay_validator,
+            token::Amount::from_u64(10),
+            ProposalVote::Yay,
+        );
+        proposal_votes.add_validator(
+            &nay_validator,
+            token::Amount::from_u64(10),
+            ProposalVote::Nay,
+        );
+
+        // without abstentions, the quorum is not met
+        let proposal_result = compute_proposal_result(
+            proposal_votes.clone(),
+            total_voting_power,
+            TallyType::TwoFifths,
+        )
+        .unwrap();
+        assert!(!proposal_result.is_quorum_met().unwrap());
+        assert_eq!(
+            proposal_result.tally_outcome().unwrap(),
+            TallyOutcome::QuorumNotMet
+        );
+
+        // abstentions push the proposal over the quorum...
+        proposal_votes.add_validator(
+            &abstain_validator,
+            token::Amount::from_u64(30),
+            ProposalVote::Abstain,
+        );
+        let proposal_result = compute_proposal_result(
+            proposal_votes,
+            total_voting_power,
+            TallyType::TwoFifths,
+        )
+        .unwrap();
+        assert!(proposal_result.is_quorum_met().unwrap());
+        assert_eq!(
+            proposal_result.total_abstain_power,
+            token::Amount::from_u64(30)
+        );
+
+        // ...but it still fails on the yay ratio
+        assert!(matches!(proposal_result.result, TallyResult::Rejected));
+        assert_eq!(
+            proposal_result.tally_outcome().unwrap(),
+            TallyOutcome::Rejected {
+                reason: RejectionReason::NotEnoughYayVotes
+            }
+        );
+    }
+
+    /// Test that a proposal on which less than the minimum quorum voted does
+    /// not pass, even if it met the quorum of its tally type.
+    #[test]
+    fn test_proposal_below_min_quorum_is_not_passed() {
+        let total_voting_power = token::Amount::from_u64(100);
+        let yay_validator = address::testing::established_address_1();
+
+        let mut proposal_votes = ProposalVotes::default();
+        proposal_votes.add_validator(
+            &yay_validator,
+            token::Amount::from_u64(50),
+            ProposalVote::Yay,
+        );
+        let proposal_result = compute_proposal_result(
+            proposal_votes,
+            total_voting_power,
+            TallyType::TwoFifths,
+        )
+        .unwrap();
+        assert!(proposal_result.is_quorum_met().unwrap());
+        assert!(matches!(proposal_result.result, TallyResult::Passed));
+
+        let min_quorum = Dec::new(5, 1).unwrap();
+        assert!(proposal_result.is_min_quorum_met(min_quorum).unwrap());
+        assert_eq!(
+            proposal_result
+                .tally_outcome_with_min_quorum(min_quorum)
+                .unwrap(),
+            TallyOutcome::Passed
+        );
+
+        let min_quorum = Dec::new(6, 1).unwrap();
+        assert!(!proposal_result.is_min_quorum_met(min_quorum).unwrap());
+        assert_eq!(
+            proposal_result
+                .tally_outcome_with_min_quorum(min_quorum)
+                .unwrap(),
+            TallyOutcome::QuorumNotMet
+        );
+    }
+
     #[test]
     fn test_validator_voting_period() {
         // Voting period of 2 epochs
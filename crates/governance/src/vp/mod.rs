@@ -164,6 +164,9 @@ where
                 (KeyType::AUTHOR, Some(proposal_id)) => {
                     Self::is_valid_author(ctx, proposal_id, verifiers)
                 }
+                (KeyType::FUNDS_RECIPIENT, Some(proposal_id)) => {
+                    Self::is_valid_funds_recipient(ctx, proposal_id)
+                }
                 (KeyType::COUNTER, _) => Self::is_valid_counter(ctx, set_count),
                 (KeyType::PROPOSAL_COMMIT, _) => {
                     Self::is_valid_proposal_commit(ctx)
@@ -540,6 +543,9 @@ where
                     )
                 })
             }
+            ProposalType::GovernanceParameters(parameters) => {
+                parameters.validate().map_err(Error::new_alloc)
+            }
             // Default proposal condition are checked already for all other
             // proposals.
             // default_with_wasm proposal needs to check only for valid code
@@ -944,6 +950,38 @@ where
         })
     }
 
+    /// Validate a funds recipient key
+    pub fn is_valid_funds_recipient(
+        ctx: &'ctx CTX,
+        proposal_id: u64,
+    ) -> Result<()> {
+        let funds_recipient_key =
+            gov_storage::get_funds_recipient_key(proposal_id);
+        let author_key = gov_storage::get_author_key(proposal_id);
+
+        // The recipient can only be set along with the proposal's author,
+        // when the proposal is initialized
+        let has_pre_author = ctx.has_key_pre(&author_key)?;
+        let has_pre_funds_recipient = ctx.has_key_pre(&funds_recipient_key)?;
+        if has_pre_author || has_pre_funds_recipient {
+            return Err(Error::new_alloc(format!(
+                "The funds recipient of the proposal with id {proposal_id} \
+                 can only be set when the proposal is initialized"
+            )));
+        }
+
+        let funds_recipient: Address =
+            Self::force_read(ctx, &funds_recipient_key, ReadType::Post)?;
+        namada_account::exists(&ctx.pre(), &funds_recipient).true_or_else(
+            || {
+                Error::new_alloc(format!(
+                    "No funds recipient account {funds_recipient} could be \
+                     found for the proposal with id {proposal_id}"
+                ))
+            },
+        )
+    }
+
     /// Validate a counter key
     pub fn is_valid_counter(ctx: &'ctx CTX, set_count: u64) -> Result<()> {
         let counter_key = gov_storage::get_counter_key();
@@ -1109,6 +1147,8 @@ enum KeyType {
     #[allow(non_camel_case_types)]
     AUTHOR,
     #[allow(non_camel_case_types)]
+    FUNDS_RECIPIENT,
+    #[allow(non_camel_case_types)]
     PARAMETER,
     #[allow(non_camel_case_types)]
     UNKNOWN_GOVERNANCE,
@@ -1141,6 +1181,8 @@ impl KeyType {
             KeyType::FUNDS
         } else if gov_storage::is_author_key(key) {
             KeyType::AUTHOR
+        } else if gov_storage::is_funds_recipient_key(key) {
+            KeyType::FUNDS_RECIPIENT
         } else if gov_storage::is_counter_key(key) {
             KeyType::COUNTER
         } else if gov_storage::is_parameter_key(key) {
@@ -1193,9 +1235,9 @@ mod test {
 
     use crate::storage::keys::{
         get_activation_epoch_key, get_author_key, get_committing_proposals_key,
-        get_content_key, get_counter_key, get_funds_key, get_proposal_type_key,
-        get_vote_proposal_key, get_voting_end_epoch_key,
-        get_voting_start_epoch_key,
+        get_content_key, get_counter_key, get_funds_key,
+        get_funds_recipient_key, get_proposal_type_key, get_vote_proposal_key,
+        get_voting_end_epoch_key, get_voting_start_epoch_key,
     };
     use crate::{ProposalType, ProposalVote, ADDRESS};
 
@@ -1572,6 +1614,130 @@ mod test {
         assert_eq!(counter, 1);
     }
 
+    /// Test that the funds recipient of a proposal can only be set when the
+    /// proposal is initialized.
+    #[test]
+    fn test_governance_funds_recipient() {
+        let mut state = init_storage();
+
+        let proposal_id = 0;
+        let activation_epoch = 19;
+        let funds_recipient_key = get_funds_recipient_key(proposal_id);
+
+        let mut keys_changed = get_proposal_keys(proposal_id, activation_epoch);
+        keys_changed.insert(funds_recipient_key.clone());
+
+        let gas_meter = RefCell::new(VpGasMeter::new_from_tx_meter(
+            &TxGasMeter::new(u64::MAX),
+        ));
+        let (vp_wasm_cache, _vp_cache_dir) =
+            wasm::compilation_cache::common::testing::vp_cache();
+
+        let tx_index = TxIndex::default();
+
+        let signer = keypair_1();
+        let signer_address = Address::from(&signer.clone().ref_to());
+        let verifiers = BTreeSet::from([signer_address.clone()]);
+
+        initialize_account_balance(
+            &mut state,
+            &signer_address.clone(),
+            token::Amount::native_whole(510),
+        );
+        initialize_account_balance(
+            &mut state,
+            &ADDRESS,
+            token::Amount::native_whole(0),
+        );
+        state.commit_block().unwrap();
+
+        let mut tx = Tx::from_type(TxType::Raw);
+        tx.header.chain_id = state.in_mem().chain_id.clone();
+        tx.set_code(Code::new(vec![], None));
+        tx.set_data(Data::new(vec![]));
+        tx.add_section(Section::Authorization(Authorization::new(
+            vec![tx.header_hash()],
+            [(0, keypair_1())].into_iter().collect(),
+            None,
+        )));
+
+        init_proposal(
+            &mut state,
+            proposal_id,
+            500,
+            3,
+            9,
+            19,
+            &signer_address,
+            false,
+        );
+        let _ = state
+            .write_log_mut()
+            .write(&funds_recipient_key, signer_address.serialize_to_vec())
+            .unwrap();
+
+        let batched_tx = tx.batch_ref_first_tx().unwrap();
+        let ctx = Ctx::new(
+            &ADDRESS,
+            &state,
+            batched_tx.tx,
+            batched_tx.cmt,
+            &tx_index,
+            &gas_meter,
+            &keys_changed,
+            &verifiers,
+            vp_wasm_cache.clone(),
+        );
+
+        // the recipient may be set along with the rest of the proposal
+        assert_matches!(
+            GovernanceVp::validate_tx(
+                &ctx,
+                &batched_tx,
+                &keys_changed,
+                &verifiers
+            ),
+            Ok(_)
+        );
+
+        state.write_log_mut().commit_batch_and_current_tx();
+        state.commit_block().unwrap();
+
+        let keys_changed = BTreeSet::from([funds_recipient_key.clone()]);
+        state
+            .push_action(Action::Gov(GovAction::InitProposal {
+                author: signer_address.clone(),
+            }))
+            .unwrap();
+        let _ = state
+            .write_log_mut()
+            .write(&funds_recipient_key, ADDRESS.serialize_to_vec())
+            .unwrap();
+
+        let ctx = Ctx::new(
+            &ADDRESS,
+            &state,
+            batched_tx.tx,
+            batched_tx.cmt,
+            &tx_index,
+            &gas_meter,
+            &keys_changed,
+            &verifiers,
+            vp_wasm_cache,
+        );
+
+        // but it can't be changed once the proposal exists
+        assert_matches!(
+            GovernanceVp::validate_tx(
+                &ctx,
+                &batched_tx,
+                &keys_changed,
+                &verifiers
+            ),
+            Err(_)
+        );
+    }
+
     #[test]
     fn test_governance_proposal_not_enough_funds_failed() {
         let mut state = init_storage();
@@ -27,6 +27,7 @@ impl InitProposal {
         voting_start_epoch: Epoch,
         voting_end_epoch: Epoch,
         activation_epoch: Epoch,
+        funds_recipient: Option<Address>,
         args: GlobalArgs,
     ) -> Self {
         let init_proposal = namada_sdk::governance::InitProposalData {
@@ -38,11 +39,23 @@ impl InitProposal {
             activation_epoch,
         };
 
-        Self(transaction::build_tx(
-            args,
-            init_proposal,
-            TX_INIT_PROPOSAL_WASM.to_string(),
-        ))
+        // Proposals that refund their author keep the original encoding
+        let tx = match funds_recipient {
+            Some(funds_recipient) => transaction::build_tx(
+                args,
+                namada_sdk::governance::InitProposalDataV1 {
+                    data: init_proposal,
+                    funds_recipient: Some(funds_recipient),
+                },
+                TX_INIT_PROPOSAL_WASM.to_string(),
+            ),
+            None => transaction::build_tx(
+                args,
+                init_proposal,
+                TX_INIT_PROPOSAL_WASM.to_string(),
+            ),
+        };
+        Self(tx)
     }
 
     /// Get the bytes to sign for the given transaction
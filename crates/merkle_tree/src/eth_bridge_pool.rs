@@ -7,8 +7,11 @@ use namada_core::borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
 use namada_core::chain::BlockHeight;
 use namada_core::eth_abi::{Encode, Token};
 pub use namada_core::eth_bridge_pool::PendingTransfer;
+use namada_core::ethereum_structs::{
+    bridge_pool_root, hash_pair, POOL_ROOT_PREFIX_LEAF,
+    POOL_ROOT_PREFIX_NON_LEAF,
+};
 use namada_core::hash::Hash;
-use namada_core::keccak::keccak_hash;
 use namada_core::storage;
 use namada_core::storage::DbKeySeg;
 use namada_macros::BorshDeserializer;
@@ -22,14 +25,6 @@ use crate::KeccakHash;
 /// Generic error that may be returned by the validity predicate
 pub struct Error(#[from] eyre::Error);
 
-/// Prefix to be used in Bridge pool tree root computations.
-/// This value corresponds to leaf nodes.
-const POOL_ROOT_PREFIX_LEAF: u8 = 0x00;
-
-/// Prefix to be used in Bridge pool tree root computations.
-/// This value corresponds to non-leaf nodes.
-const POOL_ROOT_PREFIX_NON_LEAF: u8 = 0xff;
-
 /// A simple Merkle tree for the Ethereum bridge pool
 ///
 /// Note that an empty tree has root [0u8; 20] by definition.
@@ -105,24 +100,7 @@ impl BridgePoolTree {
 
     /// Compute the root of the merkle tree
     fn compute_root(&self) -> KeccakHash {
-        let mut hashes: Vec<KeccakHash> = self.leaves.keys().cloned().collect();
-        let mut prefix = POOL_ROOT_PREFIX_LEAF;
-        while hashes.len() > 1 {
-            let mut next_hashes = vec![];
-            for pair in hashes.chunks(2) {
-                let left = pair[0].clone();
-                let right = pair.get(1).cloned().unwrap_or_default();
-                next_hashes.push(hash_pair(left, right, prefix));
-            }
-            hashes = next_hashes;
-            prefix = POOL_ROOT_PREFIX_NON_LEAF;
-        }
-
-        if hashes.is_empty() {
-            Default::default()
-        } else {
-            hashes.remove(0)
-        }
+        bridge_pool_root(self.leaves.keys().cloned().collect())
     }
 
     /// Return the root as a [`struct@Hash`] type.
@@ -256,23 +234,6 @@ impl BridgePoolTree {
     }
 }
 
-/// Concatenate a byte prefix and two keccak hashes,
-/// then compute the keccak hash of the resulting
-/// byte array.
-#[inline]
-fn hash_pair(left: KeccakHash, right: KeccakHash, prefix: u8) -> KeccakHash {
-    let mut buf = [0u8; 32 + 32 + 1];
-    buf[0] = prefix;
-    if left.0 < right.0 {
-        buf[1..33].copy_from_slice(&left.0);
-        buf[33..].copy_from_slice(&right.0);
-    } else {
-        buf[1..33].copy_from_slice(&right.0);
-        buf[33..].copy_from_slice(&left.0);
-    }
-    keccak_hash(buf)
-}
-
 /// Keeps track if a node is on a path from the
 /// root of the merkle tree to one of the leaves
 /// being included in a multi-proof.
@@ -984,6 +984,7 @@ where
     H: 'static + StorageHasher + Sync,
 {
     use namada_sdk::eth_bridge::protocol::transactions;
+    use namada_sdk::eth_bridge::protocol::validation::validator_set_update::VextDigestExt;
     use namada_vote_ext::{ethereum_events, validator_set_update};
 
     let Some(data) = data else {
@@ -1028,15 +1029,20 @@ where
             // with ABCI+, multiple vote extension protocol txs may be needed
             // to reach a complete proof.
             let signing_epoch = ext.data.signing_epoch;
+            let digest = validator_set_update::VextDigest::singleton(ext);
+            // reject bad signatures before mutating any vote tally
+            digest
+                .verify_signatures::<_, _, governance::Store<_>>(
+                    state,
+                    signing_epoch,
+                )
+                .wrap_err("Invalid validator set update vote extension")
+                .map_err(Error::ProtocolTxError)?;
             transactions::validator_set_update::aggregate_votes::<
                 _,
                 _,
                 governance::Store<_>,
-            >(
-                state,
-                validator_set_update::VextDigest::singleton(ext),
-                signing_epoch,
-            )
+            >(state, digest, signing_epoch)
             .map_err(Error::ProtocolTxError)
         }
         EthereumTxData::EthereumEvents(_)
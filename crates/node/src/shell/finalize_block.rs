@@ -19,7 +19,7 @@ use namada_sdk::state::write_log::StorageModification;
 use namada_sdk::state::{
     Result, ResultExt, StorageWrite, EPOCH_SWITCH_BLOCKS_DELAY,
 };
-use namada_sdk::storage::{BlockHeader, BlockResults, Epoch};
+use namada_sdk::storage::{BlockHeader, BlockResults, Epoch, KeySeg};
 use namada_sdk::tx::data::protocol::ProtocolTxType;
 use namada_sdk::tx::data::VpStatusFlags;
 use namada_sdk::tx::event::{Batch, Code};
@@ -1124,18 +1124,32 @@ where
 {
     let vp_wasm_cache = &mut shell.vp_wasm_cache;
     let tx_wasm_cache = &mut shell.tx_wasm_cache;
+    let batch_events = shell.batch_governance_events;
+    // Proposal code that changes the validator set or the protocol
+    // parameters, which include the config of the Ethereum bridge, is
+    // flagged for security tooling
+    let watched_prefixes = [
+        Key::from(namada_sdk::address::POS.to_db_key()),
+        Key::from(namada_sdk::address::PARAMETERS.to_db_key()),
+    ];
     governance::finalize_block::<
         _,
         token::Store<_>,
         proof_of_stake::Store<_>,
         _,
         _,
+        _,
     >(
         &mut shell.state,
         emit_events,
         current_epoch,
         is_new_epoch,
-        |tx, state| {
+        |tx, state, max_gas| {
+            let gas_scale = get_gas_scale(state)
+                .expect("Failed to get gas scale from parameters");
+            // Saturate the scaled gas limit of governance proposals
+            let gas_limit = Gas::from_whole_units(max_gas.into(), gas_scale)
+                .unwrap_or_else(|| u64::MAX.into());
             let dispatch_result = protocol::dispatch_tx(
                 tx,
                 protocol::DispatchArgs::Raw {
@@ -1145,8 +1159,7 @@ where
                     vp_wasm_cache,
                     tx_wasm_cache,
                 },
-                // No gas limit for governance proposal
-                &RefCell::new(TxGasMeter::new(u64::MAX)),
+                &RefCell::new(TxGasMeter::new(gas_limit)),
                 state,
             );
             // Governance must construct the tx with data and code commitments
@@ -1159,23 +1172,26 @@ where
                 {
                     Ok(batched_result) => {
                         if batched_result.is_accepted() {
-                            state.write_log_mut().commit_batch_and_current_tx();
-                            Ok(true)
+                            Ok((
+                                governance::ProposalCodeResult::Accepted,
+                                batched_result.changed_keys.clone(),
+                            ))
                         } else {
                             tracing::warn!(
                                 "Governance proposal rejected by VP(s): {}",
                                 batched_result.vps_result
                             );
-                            state.write_log_mut().drop_batch();
-                            Ok(false)
+                            Ok((
+                                governance::ProposalCodeResult::Rejected,
+                                BTreeSet::new(),
+                            ))
                         }
                     }
                     Err(e) => {
                         tracing::warn!(
                             "Error executing governance proposal {e}",
                         );
-                        state.write_log_mut().drop_batch();
-                        Ok(false)
+                        Ok((proposal_code_failure(e), BTreeSet::new()))
                     }
                 },
                 Err(e) => {
@@ -1183,8 +1199,7 @@ where
                         "Error executing governance proposal {}",
                         e.error
                     );
-                    state.write_log_mut().drop_batch();
-                    Ok(false)
+                    Ok((proposal_code_failure(&e.error), BTreeSet::new()))
                 }
             }
         },
@@ -1196,9 +1211,30 @@ where
                 token::Transfer,
             >(state, token, source, target)
         },
+        &watched_prefixes,
+        |proposal_id, keys| {
+            tracing::warn!(
+                proposal_id,
+                ?keys,
+                "The code of a governance proposal changed sensitive storage \
+                 keys"
+            );
+        },
+        batch_events,
     )
 }
 
+/// Classify the failure of the code of a governance proposal
+fn proposal_code_failure(
+    error: &protocol::Error,
+) -> governance::ProposalCodeResult {
+    if matches!(error, protocol::Error::GasError(_)) {
+        governance::ProposalCodeResult::OutOfGas
+    } else {
+        governance::ProposalCodeResult::Rejected
+    }
+}
+
 /// Dependency-injection indirection for token system
 fn token_finalize_block<S>(
     storage: &mut S,
@@ -1208,13 +1244,32 @@ fn token_finalize_block<S>(
 where
     S: StorageWrite + StorageRead + token::WithConversionState,
 {
-    token::finalize_block::<S, parameters::Store<_>>(
+    let mut hooks = token::conversion::EpochInflationHooks::default();
+    hooks.register(LogInflationHook);
+    token::finalize_block_with_hooks::<S, parameters::Store<_>>(
         storage,
         events,
         is_new_masp_epoch,
+        &mut hooks,
     )
 }
 
+/// Inflation hook that logs the tokens rewarded by the MASP on a new MASP
+/// epoch
+struct LogInflationHook;
+
+impl<S> token::conversion::EpochInflationHook<S> for LogInflationHook {
+    fn on_epoch_change(
+        &mut self,
+        _storage: &mut S,
+        token: &Address,
+        epoch: token::MaspEpoch,
+    ) -> token::Result<()> {
+        tracing::debug!(%token, %epoch, "Updating the MASP rewards of token");
+        Ok(())
+    }
+}
+
 /// Dependency-injection indirection for PoS system
 fn pos_finalize_block<S>(
     storage: &mut S,
@@ -1282,7 +1337,9 @@ mod test_finalize_block {
     use namada_sdk::events::extend::Log;
     use namada_sdk::events::Event;
     use namada_sdk::gas::VpGasMeter;
-    use namada_sdk::governance::storage::keys::get_proposal_execution_key;
+    use namada_sdk::governance::storage::keys::{
+        get_funds_key, get_max_code_gas_key, get_proposal_execution_key,
+    };
     use namada_sdk::governance::storage::proposal::ProposalType;
     use namada_sdk::governance::{
         InitProposalData, ProposalVote, VoteProposalData,
@@ -1892,6 +1949,150 @@ mod test_finalize_block {
         );
     }
 
+    /// Test that the code of a passed governance proposal is dispatched with
+    /// a gas meter bounded by the max proposal execution gas, and that the
+    /// funds locked by the proposal are burnt when its code runs out of gas.
+    #[test]
+    fn test_gov_proposal_code_out_of_gas() {
+        let (mut shell, _broadcaster, _, _eth_control) = setup();
+        let validator = shell.mode.get_validator_address().unwrap().clone();
+        let native_token = shell.state.in_mem().native_token.clone();
+
+        // Keep the gas limit low so that the proposal code quickly runs out
+        // of gas
+        shell
+            .state
+            .write(&get_max_code_gas_key(Epoch::default()), 1_000_u64)
+            .unwrap();
+
+        let proposal_code = TestWasms::TxInfiniteGuestGas.read_bytes();
+        let proposal = InitProposalData {
+            content: Hash::default(),
+            author: validator.clone(),
+            voting_start_epoch: Epoch::default(),
+            voting_end_epoch: Epoch::default().next(),
+            activation_epoch: Epoch::default().next(),
+            r#type: ProposalType::DefaultWithWasm(Hash::sha256(&proposal_code)),
+        };
+        let proposal_id =
+            namada_sdk::governance::init_proposal::<_, token::Store<_>>(
+                &mut shell.state,
+                &proposal,
+                vec![],
+                Some(proposal_code),
+            )
+            .unwrap();
+        // Vote to accept the proposal (there's only one validator, so its
+        // vote decides)
+        namada_sdk::governance::vote_proposal(
+            &mut shell.state,
+            VoteProposalData {
+                id: proposal_id,
+                vote: ProposalVote::Yay,
+                voter: validator.clone(),
+            },
+            HashSet::new(),
+        )
+        .unwrap();
+
+        let funds: Amount = shell
+            .state
+            .read(&get_funds_key(proposal_id))
+            .unwrap()
+            .unwrap();
+        assert!(!funds.is_zero());
+        let author_balance =
+            read_balance(&shell.state, &native_token, &validator).unwrap();
+        let gov_balance =
+            read_balance(&shell.state, &native_token, &governance::ADDRESS)
+                .unwrap();
+
+        let mut events = vec![];
+        gov_finalize_block(
+            &mut shell,
+            &mut events,
+            Epoch::default().next(),
+            true,
+        )
+        .unwrap();
+
+        // The funds have been burnt, rather than refunded to the author
+        assert_eq!(
+            read_balance(&shell.state, &native_token, &validator).unwrap(),
+            author_balance
+        );
+        assert_eq!(
+            read_balance(&shell.state, &native_token, &governance::ADDRESS)
+                .unwrap(),
+            gov_balance.checked_sub(funds).unwrap()
+        );
+    }
+
+    /// Test that the events of the governance proposals ended in a block are
+    /// batched according to the config of the node.
+    #[test]
+    fn test_gov_batch_events_config() {
+        use namada_sdk::governance::event::types::{
+            PROPOSALS_ENDED, PROPOSAL_PASSED, PROPOSAL_REJECTED,
+        };
+        use namada_sdk::governance::{init_proposal, vote_proposal};
+
+        for (batch_governance_events, expected_events) in
+            [(false, 2), (true, 1)]
+        {
+            let (mut shell, _broadcaster, _, _eth_control) = setup();
+            shell.batch_governance_events = batch_governance_events;
+            let validator = shell.mode.get_validator_address().unwrap().clone();
+
+            // Add a proposal to be accepted and one to be rejected
+            for vote in [ProposalVote::Yay, ProposalVote::Nay] {
+                let proposal = InitProposalData {
+                    content: Hash::default(),
+                    author: validator.clone(),
+                    voting_start_epoch: Epoch::default(),
+                    voting_end_epoch: Epoch::default().next(),
+                    activation_epoch: Epoch::default().next(),
+                    r#type: ProposalType::Default,
+                };
+                let proposal_id = init_proposal::<_, token::Store<_>>(
+                    &mut shell.state,
+                    &proposal,
+                    vec![],
+                    None,
+                )
+                .unwrap();
+                vote_proposal(
+                    &mut shell.state,
+                    VoteProposalData {
+                        id: proposal_id,
+                        vote,
+                        voter: validator.clone(),
+                    },
+                    HashSet::new(),
+                )
+                .unwrap();
+            }
+
+            let mut events = vec![];
+            gov_finalize_block(
+                &mut shell,
+                &mut events,
+                Epoch::default().next(),
+                true,
+            )
+            .unwrap();
+
+            let gov_events = events
+                .iter()
+                .filter(|event| {
+                    [PROPOSALS_ENDED, PROPOSAL_PASSED, PROPOSAL_REJECTED]
+                        .contains(event.kind())
+                })
+                .count();
+            assert_eq!(gov_events, expected_events);
+        }
+    }
+
     /// Test that the finalize block handler never commits changes directly to
     /// the DB.
     #[test]
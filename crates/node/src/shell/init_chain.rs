@@ -448,6 +448,7 @@ where
     /// Init genesis token accounts
     fn init_token_accounts(&mut self, genesis: &genesis::chain::Finalized) {
         let mut token_map = BTreeMap::new();
+        let mut params_by_token = Vec::new();
         let native_alias = &genesis.parameters.parameters.native_token;
         for (alias, token) in &genesis.tokens.token {
             tracing::debug!("Initializing token {alias}");
@@ -461,13 +462,11 @@ where
             }
             // associate a token with its denomination.
             write_denom(&mut self.state, address, *denom).unwrap();
-            namada_sdk::token::write_params(
-                masp_params,
-                &mut self.state,
-                address,
-                denom,
-            )
-            .unwrap();
+            params_by_token.push((
+                address.clone(),
+                *denom,
+                masp_params.clone(),
+            ));
             if masp_params.is_some() {
                 // add token addresses to the masp reward conversions lookup
                 // table.
@@ -475,6 +474,8 @@ where
                 token_map.insert(alias, address.clone());
             }
         }
+        namada_sdk::token::write_params_bulk(&mut self.state, &params_by_token)
+            .unwrap();
         self.state
             .write(&masp_token_map_key(), token_map)
             .expect("Couldn't init token accounts");
@@ -384,6 +384,10 @@ where
     /// When set, indicates after how many blocks a new snapshot
     /// will be taken (counting from the first block)
     pub blocks_between_snapshots: Option<NonZeroU64>,
+    /// Taken from config `batch_governance_events`. When set, the outcomes
+    /// of the governance proposals ended in a block are emitted as a single
+    /// event.
+    pub batch_governance_events: bool,
     /// Data for a node downloading and apply snapshots as part of
     /// the fast sync protocol.
     pub syncing: Option<SnapshotSync>,
@@ -669,6 +673,7 @@ where
             event_log: EventLog::default(),
             scheduled_migration,
             blocks_between_snapshots: config.shell.blocks_between_snapshots,
+            batch_governance_events: config.shell.batch_governance_events,
             syncing: None,
         };
         shell.update_eth_oracle(&Default::default());
@@ -25,6 +25,12 @@ struct Keys {
     native_erc20: &'static str,
     /// Sub-lkey for storing the Ethereum address of the bridge contract.
     bridge_contract_address: &'static str,
+    /// Sub-key for storing the number of blocks past an epoch boundary
+    /// during which late validator set update votes are still accepted.
+    valset_upd_grace_window: &'static str,
+    /// Sub-key for storing the minimum number of distinct validators that
+    /// must sign a validator set update for its proof to be complete.
+    min_valset_signers: &'static str,
     // ========================================
     // Core parameters
     // ========================================
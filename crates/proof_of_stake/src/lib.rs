@@ -217,6 +217,16 @@ pub fn staking_token_address(storage: &impl StorageRead) -> Address {
         .expect("Must be able to read native token address")
 }
 
+/// Read the balance of the staking token held in the PoS slash pool
+pub fn slash_fund_balance<S, Token>(storage: &S) -> Result<token::Amount>
+where
+    S: StorageRead,
+    Token: trans_token::Read<S>,
+{
+    let staking_token = staking_token_address(storage);
+    Token::read_balance(storage, &staking_token, &SLASH_POOL_ADDRESS)
+}
+
 /// Init genesis. Requires that the governance parameters are initialized.
 pub fn init_genesis<S>(
     storage: &mut S,
@@ -58,9 +58,10 @@ use crate::types::{
 use crate::{
     below_capacity_validator_set_handle, bond_handle,
     consensus_validator_set_handle, is_delegator, is_validator,
-    jail_for_liveness, read_validator_stake, staking_token_address,
-    unbond_handle, validator_consensus_key_handle,
+    jail_for_liveness, read_validator_stake, slash_fund_balance,
+    staking_token_address, unbond_handle, validator_consensus_key_handle,
     validator_set_positions_handle, validator_state_handle, StorageRead,
+    SLASH_POOL_ADDRESS,
 };
 
 proptest! {
@@ -2210,3 +2211,40 @@ fn test_delegation_targets() {
     assert!(de_2.prev_ranges.is_empty());
     assert_eq!(de_2.last_range.1, None);
 }
+
+/// Test reading the balance of the slash pool, up to the max amount that it
+/// can hold.
+#[test]
+fn test_slash_fund_balance() {
+    let mut storage = TestState::default();
+    let staking_token = staking_token_address(&storage);
+    assert_eq!(
+        slash_fund_balance::<_, token::Store<_>>(&storage).unwrap(),
+        token::Amount::zero()
+    );
+
+    credit_tokens(
+        &mut storage,
+        &staking_token,
+        &SLASH_POOL_ADDRESS,
+        token::Amount::max(),
+    )
+    .unwrap();
+    assert_eq!(
+        slash_fund_balance::<_, token::Store<_>>(&storage).unwrap(),
+        token::Amount::max()
+    );
+
+    // The balance of the slash pool cannot overflow
+    let result = credit_tokens(
+        &mut storage,
+        &staking_token,
+        &SLASH_POOL_ADDRESS,
+        token::Amount::from_u64(1),
+    );
+    assert!(result.is_err());
+    assert_eq!(
+        slash_fund_balance::<_, token::Store<_>>(&storage).unwrap(),
+        token::Amount::max()
+    );
+}
@@ -3,6 +3,7 @@
 use namada_core::address::Address;
 use namada_core::chain::Epoch;
 use namada_core::dec::Dec;
+use namada_core::eth_bridge_pool::BridgePoolError;
 use namada_core::ethereum_events::EthAddress;
 use namada_core::{arith, storage};
 use namada_events::EventError;
@@ -360,6 +361,9 @@ pub enum EthereumBridgeError {
     /// Invalid fee token error.
     #[error("An invalid fee token was provided: {0}")]
     InvalidFeeToken(Address),
+    /// Malformed Bridge pool transfer error.
+    #[error("Malformed Bridge pool transfer: {0}")]
+    InvalidTransfer(BridgePoolError),
     /// Not whitelisted error.
     #[error("ERC20 is not whitelisted: {0}")]
     Erc20NotWhitelisted(EthAddress),
@@ -184,6 +184,11 @@ async fn validate_bridge_pool_tx(
     // with the validations performed by the Bridge pool VP!
     //======================================================
 
+    // check that the transfer is well-formed
+    transfer.validate().map_err(|err| {
+        Error::EthereumBridge(EthereumBridgeError::InvalidTransfer(err))
+    })?;
+
     // check if an identical transfer is already in the Bridge pool
     let transfer_in_pool = RPC
         .shell()
@@ -744,7 +749,9 @@ mod recommendations {
     use namada_core::chain::BlockHeight;
     use namada_core::ethereum_events::Uint as EthUint;
     use namada_core::uint::{self, Uint, I256};
-    use namada_ethereum_bridge::storage::proof::BridgePoolRootProof;
+    use namada_ethereum_bridge::storage::proof::{
+        BridgePoolRootProof, SignedNonce,
+    };
     use namada_io::edisplay_line;
     use namada_vote_ext::validator_set_update::{
         EthAddrBook, VotingPowersMap, VotingPowersMapExt,
@@ -905,7 +912,7 @@ mod recommendations {
             )))
         })?;
 
-        if latest_bp_nonce != bp_root.data.1 {
+        if !bp_root.matches_nonce(latest_bp_nonce) {
             edisplay_line!(
                 context.io(),
                 "The signed Bridge pool nonce is not up to date, repeat this \
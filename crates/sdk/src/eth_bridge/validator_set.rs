@@ -650,11 +650,13 @@ where
             .next()
     };
 
-    if hints::unlikely(epoch_to_relay == Epoch(0)) {
+    // The proof for an epoch is signed by the validators of its predecessor
+    let Some(bridge_current_epoch) = epoch_to_relay.prev() else {
+        hints::cold();
         return Err(Error::critical(SdkError::Other(
             "There is no validator set update proof for epoch 0".into(),
         )));
-    }
+    };
 
     let shell = RPC.shell().eth_bridge();
     let encoded_proof_fut = shell
@@ -668,9 +670,6 @@ where
             })
         });
 
-    let bridge_current_epoch = epoch_to_relay
-        .prev()
-        .expect("Epoch to relay must have prev");
     let shell = RPC.shell().eth_bridge();
     let validator_set_args_fut = shell
         .read_bridge_valset(nam_client, &bridge_current_epoch)
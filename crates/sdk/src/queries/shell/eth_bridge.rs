@@ -22,7 +22,7 @@ use namada_ethereum_bridge::event::{BpTransferStatus, BridgePoolTxHash};
 use namada_ethereum_bridge::protocol::transactions::votes::{
     EpochedVotingPower, EpochedVotingPowerExt,
 };
-use namada_ethereum_bridge::storage::bridge_pool::get_key_from_hash;
+use namada_ethereum_bridge::storage::bridge_pool::{self, get_key_from_hash};
 use namada_ethereum_bridge::storage::eth_bridge_queries::EthBridgeQueries;
 use namada_ethereum_bridge::storage::parameters::UpgradeableContract;
 use namada_ethereum_bridge::storage::proof::{sort_sigs, EthereumProof};
@@ -33,7 +33,6 @@ use namada_ethereum_bridge::storage::{
 use namada_macros::BorshDeserializer;
 #[cfg(feature = "migrations")]
 use namada_migrations::*;
-use namada_state::MembershipProof::BridgePool;
 use namada_state::{DBIter, StorageHasher, StoreRef, StoreType, DB};
 use namada_storage::{CustomError, ResultExt, StorageRead};
 use namada_vote_ext::validator_set_update::{
@@ -524,89 +523,61 @@ where
             )));
         }
 
-        // get the merkle tree corresponding to the above root.
-        let tree = ctx
-            .state
-            .get_merkle_tree(height, Some(StoreType::BridgePool))
-            .into_storage_result()?;
-        // from the hashes of the transfers, get the actual values.
-        let mut missing_hashes = vec![];
-        let (keys, values): (Vec<_>, Vec<Vec<u8>>) = transfer_hashes
-            .iter()
-            .filter_map(|hash| {
-                let key = get_key_from_hash(hash);
-                match ctx.state.read_bytes(&key) {
-                    Ok(Some(bytes)) => Some((key, bytes)),
-                    _ => {
-                        missing_hashes.push(hash);
-                        None
-                    }
-                }
-            })
-            .unzip();
-        if !missing_hashes.is_empty() {
-            return Err(namada_storage::Error::Custom(CustomError(
-                format!(
-                    "One or more of the provided hashes had no corresponding \
-                     transfer in storage: {:?}",
-                    missing_hashes
-                )
-                .into(),
-            )));
-        }
-        let (transfers, appendices) = values.iter().fold(
-            (vec![], vec![]),
-            |(mut transfers, mut appendices), bytes| {
-                let pending = PendingTransfer::try_from_slice(bytes)
-                    .expect("Deserializing storage shouldn't fail");
-                let eth_transfer = (&pending).into();
-                if with_appendix {
-                    appendices.push(pending.into_appendix());
-                }
-                transfers.push(eth_transfer);
-                (transfers, appendices)
-            },
-        );
-        // get the membership proof
-        match tree.get_sub_tree_existence_proof(
-            &keys,
-            values.iter().map(|v| v.as_slice()).collect(),
-        ) {
-            Ok(BridgePool(proof)) => {
-                let (validator_args, voting_powers) = ctx
-                    .state
-                    .ethbridge_queries()
-                    .get_bridge_validator_set::<governance::Store<_>>(None);
-                let relay_proof = ethereum_structs::RelayProof {
-                    transfers,
-                    pool_root: signed_root.data.0.0,
-                    proof: proof.proof.into_iter().map(|hash| hash.0).collect(),
-                    proof_flags: proof.flags,
-                    batch_nonce: signed_root.data.1.into(),
-                    relayer_address: relayer.to_string(),
-                };
-                let validator_set: ethereum_structs::ValidatorSetArgs =
-                    validator_args.into();
-                let signatures =
-                    sort_sigs(&voting_powers, &signed_root.signatures);
-                let rsp = GenBridgePoolProofRsp {
-                    abi_encoded_args: ethers::abi::AbiEncode::encode((
-                        validator_set,
-                        signatures,
-                        relay_proof,
-                    )),
-                    appendices: with_appendix.then_some(appendices),
-                };
-                let data = rsp.serialize_to_vec();
-                Ok(EncodedResponseQuery {
-                    data,
-                    height,
-                    ..Default::default()
-                })
+        // get the membership proof of the transfers in the merkle tree
+        // corresponding to the above root
+        let proof = bridge_pool::generate_bridge_pool_proof(
+            ctx.state,
+            &transfer_hashes,
+            height,
+        )?;
+        let mut transfers = Vec::with_capacity(transfer_hashes.len());
+        let mut appendices = vec![];
+        for hash in transfer_hashes.iter() {
+            let pending: PendingTransfer =
+                StorageRead::read(ctx.state, &get_key_from_hash(hash))?
+                    .ok_or_else(|| {
+                        namada_storage::Error::Custom(CustomError(
+                            format!(
+                                "The proven transfer with hash {hash} is not \
+                                 in storage"
+                            )
+                            .into(),
+                        ))
+                    })?;
+            transfers.push((&pending).into());
+            if with_appendix {
+                appendices.push(pending.into_appendix());
             }
-            Ok(_) => unreachable!(),
-            Err(e) => Err(namada_storage::Error::new(e)),
         }
+        let (validator_args, voting_powers) = ctx
+            .state
+            .ethbridge_queries()
+            .get_bridge_validator_set::<governance::Store<_>>(None);
+        let relay_proof = ethereum_structs::RelayProof {
+            transfers,
+            pool_root: signed_root.data.0.0,
+            proof: proof.proof.into_iter().map(|hash| hash.0).collect(),
+            proof_flags: proof.flags,
+            batch_nonce: signed_root.data.1.into(),
+            relayer_address: relayer.to_string(),
+        };
+        let validator_set: ethereum_structs::ValidatorSetArgs =
+            validator_args.into();
+        let signatures = sort_sigs(&voting_powers, &signed_root.signatures);
+        let rsp = GenBridgePoolProofRsp {
+            abi_encoded_args: ethers::abi::AbiEncode::encode((
+                validator_set,
+                signatures,
+                relay_proof,
+            )),
+            appendices: with_appendix.then_some(appendices),
+        };
+        let data = rsp.serialize_to_vec();
+        Ok(EncodedResponseQuery {
+            data,
+            height,
+            ..Default::default()
+        })
     } else {
         Err(namada_storage::Error::SimpleMessage(
             "Could not deserialize transfers",
@@ -23,7 +23,7 @@ use namada_core::masp::{
 use namada_core::time::DateTimeUtc;
 use namada_core::token::{Amount, DenominatedAmount};
 use namada_governance::storage::proposal::{
-    InitProposalData, ProposalType, VoteProposalData,
+    InitProposalDataV1, ProposalType, VoteProposalData,
 };
 use namada_governance::storage::vote::ProposalVote;
 use namada_ibc::core::channel::types::timeout::{
@@ -1056,6 +1056,55 @@ fn proposal_type_to_ledger_vector(
                 }
             }
         }
+        ProposalType::GovernanceParameters(parameters) => {
+            output.push("Proposal type : Governance Parameters".to_string());
+            output.push(format!(
+                "Min proposal fund : NAM {}",
+                to_ledger_decimal_whitelisted_token(
+                    &parameters.min_proposal_fund.to_string_native()
+                )
+            ));
+            output.push(format!(
+                "Max proposal code size : {}",
+                parameters.max_proposal_code_size
+            ));
+            output.push(format!(
+                "Min proposal voting period : {}",
+                parameters.min_proposal_voting_period
+            ));
+            output.push(format!(
+                "Max proposal period : {}",
+                parameters.max_proposal_period
+            ));
+            output.push(format!(
+                "Max proposal content size : {}",
+                parameters.max_proposal_content_size
+            ));
+            output.push(format!(
+                "Min proposal grace epochs : {}",
+                parameters.min_proposal_grace_epochs
+            ));
+            output.push(format!(
+                "Max proposal latency : {}",
+                parameters.max_proposal_latency
+            ));
+            output.push(format!(
+                "Max proposal execution gas : {}",
+                parameters.max_proposal_execution_gas
+            ));
+            output.push(format!(
+                "Rejection refund fraction : {}",
+                parameters.rejection_refund_fraction
+            ));
+            output.push(format!(
+                "Min proposal quorum : {}",
+                parameters.min_proposal_quorum
+            ));
+            output.push(format!(
+                "Max proposal code retries : {}",
+                parameters.max_proposal_code_retries
+            ));
+        }
     }
     Ok(())
 }
@@ -1279,7 +1328,10 @@ pub async fn to_ledger_vector(
                 tv.output_expert.push(format!("Avatar : {}", avatar));
             }
         } else if code_sec.tag == Some(TX_INIT_PROPOSAL.to_string()) {
-            let init_proposal_data = InitProposalData::try_from_slice(
+            let InitProposalDataV1 {
+                data: init_proposal_data,
+                funds_recipient,
+            } = InitProposalDataV1::try_from_versioned_slice(
                 &tx.data(cmt)
                     .ok_or_else(|| Error::Other("Invalid Data".to_string()))?,
             )
@@ -1318,6 +1370,10 @@ pub async fn to_ledger_vector(
                 ),
                 format!("Content : {}", HEXLOWER.encode(&extra.0)),
             ]);
+            if let Some(funds_recipient) = &funds_recipient {
+                tv.output
+                    .push(format!("Funds recipient : {}", funds_recipient));
+            }
 
             proposal_type_to_ledger_vector(
                 &init_proposal_data.r#type,
@@ -1340,6 +1396,10 @@ pub async fn to_ledger_vector(
                 ),
                 format!("Content : {}", HEXLOWER.encode(&extra.0)),
             ]);
+            if let Some(funds_recipient) = &funds_recipient {
+                tv.output_expert
+                    .push(format!("Funds recipient : {}", funds_recipient));
+            }
         } else if code_sec.tag == Some(TX_VOTE_PROPOSAL.to_string()) {
             let vote_proposal = VoteProposalData::try_from_slice(
                 &tx.data(cmt)
@@ -49,7 +49,7 @@ use namada_governance::cli::onchain::{
 };
 use namada_governance::pgf::cli::steward::Commission;
 use namada_governance::storage::proposal::{
-    InitProposalData, ProposalType, VoteProposalData,
+    InitProposalData, InitProposalDataV1, ProposalType, VoteProposalData,
 };
 use namada_governance::storage::vote::ProposalVote;
 use namada_ibc::storage::channel_key;
@@ -2127,6 +2127,7 @@ pub async fn build_default_proposal(
 
     let init_proposal_data = InitProposalData::try_from(proposal.clone())
         .map_err(|e| TxSubmitError::InvalidProposal(e.to_string()))?;
+    let funds_recipient = proposal.funds_recipient.clone();
 
     let push_data =
         |tx_builder: &mut Tx, init_proposal_data: &mut InitProposalData| {
@@ -2148,17 +2149,40 @@ pub async fn build_default_proposal(
             Ok(())
         };
 
-    build(
-        context,
-        tx,
-        tx_code_path.clone(),
-        init_proposal_data,
-        push_data,
-        fee_amount,
-        &signing_data.fee_payer,
-    )
-    .await
-    .map(|tx| (tx, signing_data))
+    // Proposals that refund their author keep the original encoding
+    let built_tx = match funds_recipient {
+        Some(funds_recipient) => {
+            let init_proposal_data = InitProposalDataV1 {
+                data: init_proposal_data,
+                funds_recipient: Some(funds_recipient),
+            };
+            build(
+                context,
+                tx,
+                tx_code_path.clone(),
+                init_proposal_data,
+                |tx_builder, init_proposal_data| {
+                    push_data(tx_builder, &mut init_proposal_data.data)
+                },
+                fee_amount,
+                &signing_data.fee_payer,
+            )
+            .await
+        }
+        None => {
+            build(
+                context,
+                tx,
+                tx_code_path.clone(),
+                init_proposal_data,
+                push_data,
+                fee_amount,
+                &signing_data.fee_payer,
+            )
+            .await
+        }
+    };
+    built_tx.map(|tx| (tx, signing_data))
 }
 
 /// Build a proposal vote
@@ -1,5 +1,8 @@
 //! MASP rewards conversions
 
+use std::collections::BTreeMap;
+use std::marker::PhantomData;
+
 use namada_controller::PDController;
 use namada_core::address::{Address, MASP};
 use namada_core::arith::checked;
@@ -8,18 +11,20 @@ use namada_core::borsh::BorshSerializeExt;
 use namada_core::dec::Dec;
 #[cfg(any(feature = "multicore", test))]
 use namada_core::hash::Hash;
+use namada_core::masp::MaspEpoch;
 use namada_core::token::{Amount, DenominatedAmount, Denomination};
 use namada_core::uint::Uint;
 use namada_systems::{parameters, trans_token};
 
 #[cfg(any(feature = "multicore", test))]
-use crate::storage_key::{masp_assets_hash_key, masp_token_map_key};
+use crate::storage_key::masp_assets_hash_key;
 use crate::storage_key::{
     masp_kd_gain_key, masp_kp_gain_key, masp_last_inflation_key,
-    masp_last_locked_amount_key, masp_locked_amount_target_key,
-    masp_max_reward_rate_key,
+    masp_last_locked_amount_key, masp_max_reward_rate_key, masp_token_map_key,
+};
+use crate::{
+    Error, Result, ResultExt, StorageRead, StorageWrite, WithConversionState,
 };
-use crate::{Result, StorageRead, StorageWrite, WithConversionState};
 
 /// Compute shielded token inflation amount
 #[allow(clippy::too_many_arguments)]
@@ -33,7 +38,7 @@ pub fn compute_inflation(
     epochs_per_year: u64,
     target_amount: Dec,
     last_amount: Dec,
-) -> Uint {
+) -> Result<Uint> {
     let controller = PDController::new(
         total_native_amount,
         max_reward_rate,
@@ -45,11 +50,10 @@ pub fn compute_inflation(
         last_amount,
     );
 
-    let metric = Dec::try_from(locked_amount)
-        .expect("Should not fail to convert Uint to Dec");
+    let metric = Dec::try_from(locked_amount).into_storage_result()?;
     let control_coeff = max_reward_rate
         .checked_div(controller.get_epochs_per_year())
-        .expect("Control coefficient overflow");
+        .ok_or_else(|| Error::new_const("Control coefficient overflow"))?;
 
     tracing::debug!(
         "Shielded token inflation inputs: {controller:#?}, metric: {metric}, \
@@ -57,7 +61,92 @@ pub fn compute_inflation(
     );
     controller
         .compute_inflation(control_coeff, metric)
-        .expect("Inflation calculation overflow")
+        .into_storage_result()
+}
+
+/// Run a single step of the MASP rewards PD-controller of a token with the
+/// given parameters, without touching storage. The locked amounts, including
+/// the target, are in the smallest unit of the token. The new inflation amount
+/// is capped at the given supply headroom of the token, if any. Returns the
+/// annual reward rate of the new inflation amount relative to the total native
+/// supply, along with the new inflation amount itself.
+#[allow(clippy::too_many_arguments)]
+pub fn compute_inflation_step(
+    last_locked_amount: Amount,
+    locked_amount: Amount,
+    last_inflation: Amount,
+    total_native_amount: Amount,
+    epochs_per_year: u64,
+    max_reward_rate: Dec,
+    kp_gain_nom: Dec,
+    kd_gain_nom: Dec,
+    target_locked_amount: Amount,
+    supply_headroom: Option<Amount>,
+) -> Result<(Dec, Amount)> {
+    let target_locked_dec = Dec::try_from(target_locked_amount.raw_amount())
+        .into_storage_result()?;
+    let last_locked_dec =
+        Dec::try_from(last_locked_amount.raw_amount()).into_storage_result()?;
+
+    let inflation = compute_inflation(
+        locked_amount.raw_amount(),
+        total_native_amount.raw_amount(),
+        max_reward_rate,
+        last_inflation.raw_amount(),
+        kp_gain_nom,
+        kd_gain_nom,
+        epochs_per_year,
+        target_locked_dec,
+        last_locked_dec,
+    )?;
+    // Refuse to inflate the supply of the token beyond its cap, if any
+    let inflation = match supply_headroom {
+        Some(headroom) => std::cmp::min(inflation, headroom.raw_amount()),
+        None => inflation,
+    };
+    // Never mint more than the max reward rate allows, regardless of the
+    // controller's output
+    let inflation = std::cmp::min(
+        inflation,
+        max_inflation(total_native_amount, max_reward_rate, epochs_per_year)?
+            .raw_amount(),
+    );
+
+    let rate = if total_native_amount.is_zero() {
+        Dec::zero()
+    } else {
+        let inflation_dec = Dec::try_from(inflation).into_storage_result()?;
+        let total_native_dec = Dec::try_from(total_native_amount.raw_amount())
+            .into_storage_result()?;
+        checked!(inflation_dec * Dec::from(epochs_per_year) / total_native_dec)?
+    };
+    let rate = clamp_reward_rate(rate, max_reward_rate);
+    let inflation = Amount::from_uint(inflation, 0).into_storage_result()?;
+    Ok((rate, inflation))
+}
+
+/// Compute the max inflation amount that can be minted in an epoch at the
+/// given max reward rate, relative to the total native supply.
+pub fn max_inflation(
+    total_native_amount: Amount,
+    max_reward_rate: Dec,
+    epochs_per_year: u64,
+) -> Result<Amount> {
+    if epochs_per_year == 0 || max_reward_rate <= Dec::zero() {
+        return Ok(Amount::zero());
+    }
+    // NB: flooring the yearly inflation before dividing it by the epochs per
+    // year yields the same result as flooring the exact quotient
+    total_native_amount
+        .checked_mul_dec(max_reward_rate)
+        .and_then(|yearly| yearly.checked_div_u64(epochs_per_year))
+        .ok_or_else(|| Error::new_const("Max MASP inflation overflow"))
+}
+
+/// Clamp a reward rate computed by the MASP rewards PD-controller to the range
+/// between zero and the given max reward rate.
+pub fn clamp_reward_rate(rate: Dec, max_reward_rate: Dec) -> Dec {
+    std::cmp::max(std::cmp::min(rate, max_reward_rate), Dec::zero())
 }
 
 /// Compute the precision of MASP rewards for the given token. This function
@@ -133,27 +222,37 @@ where
         .read(&masp_kd_gain_key::<TransToken>(token))?
         .expect("kd_gain_nom reward should properly decode");
 
-    let target_locked_amount: Amount = storage
-        .read(&masp_locked_amount_target_key::<TransToken>(token))?
-        .expect("locked ratio target should properly decode");
-
-    let target_locked_dec = Dec::try_from(target_locked_amount.raw_amount())
-        .expect("Should not fail to convert Uint to Dec");
-    let last_locked_dec = Dec::try_from(last_locked_amount.raw_amount())
-        .expect("Should not fail to convert Uint to Dec");
+    // NB: the target is read exactly, in the smallest unit of the token, as
+    // it needs not be a whole number of tokens
+    let target_locked_amount =
+        crate::read_locked_amount_target::<S, TransToken>(storage, token)?;
+
+    //// Room left under the supply cap of the token, if any
+    let supply_headroom =
+        match crate::read_supply_cap::<S, TransToken>(storage, token)? {
+            Some(supply_cap) => {
+                let total_supply: Amount = storage
+                    .read(&TransToken::minted_balance_key(token))?
+                    .unwrap_or_default();
+                Some(supply_cap.checked_sub(total_supply).unwrap_or_default())
+            }
+            None => None,
+        };
 
     // Initial computation of the new shielded inflation
-    let inflation = compute_inflation(
-        total_tokens_in_masp.raw_amount(),
-        total_native_tokens.raw_amount(),
+    let (_rate, inflation) = compute_inflation_step(
+        last_locked_amount,
+        total_tokens_in_masp,
+        last_inflation,
+        total_native_tokens,
+        masp_epochs_per_year,
         max_reward_rate,
-        last_inflation.raw_amount(),
         kp_gain_nom,
         kd_gain_nom,
-        masp_epochs_per_year,
-        target_locked_dec,
-        last_locked_dec,
-    );
+        target_locked_amount,
+        supply_headroom,
+    )?;
+    let inflation = inflation.raw_amount();
 
     // inflation-per-token = inflation / locked tokens = n/PRECISION
     // ∴ n = (inflation * PRECISION) / locked tokens
@@ -191,17 +290,18 @@ where
 
     tracing::debug!(
         "Controller, call: total_in_masp {:?}, total_native_tokens {:?}, \
-         locked_target_amount {:?}, last_locked_amount {:?}, max_reward_rate \
-         {:?}, last_inflation {:?}, kp_gain_nom {:?}, kd_gain_nom {:?}, \
-         epochs_per_year {:?}",
+         last_locked_amount {:?}, last_inflation {:?}, max_reward_rate {:?}, \
+         kp_gain_nom {:?}, kd_gain_nom {:?}, target_locked_amount {:?}, \
+         supply_headroom {:?}, epochs_per_year {:?}",
         total_tokens_in_masp,
         total_native_tokens,
-        target_locked_amount,
         last_locked_amount,
-        max_reward_rate,
         last_inflation,
+        max_reward_rate,
         kp_gain_nom,
         kd_gain_nom,
+        target_locked_amount,
+        supply_headroom,
         masp_epochs_per_year,
     );
     tracing::debug!("Token address: {:?}", token);
@@ -227,6 +327,157 @@ where
     Ok(((noterized_inflation, precision), denomination))
 }
 
+/// Token-economic logic that runs for every token rewarded by the MASP when a
+/// new MASP epoch begins, alongside the MASP rewards controller.
+pub trait EpochInflationHook<S> {
+    /// Run the hook for the given token at the start of the given MASP epoch
+    fn on_epoch_change(
+        &mut self,
+        storage: &mut S,
+        token: &Address,
+        epoch: MaspEpoch,
+    ) -> Result<()>;
+}
+
+impl<S, H> EpochInflationHook<S> for &mut H
+where
+    H: EpochInflationHook<S> + ?Sized,
+{
+    fn on_epoch_change(
+        &mut self,
+        storage: &mut S,
+        token: &Address,
+        epoch: MaspEpoch,
+    ) -> Result<()> {
+        (**self).on_epoch_change(storage, token, epoch)
+    }
+}
+
+/// A registry of [`EpochInflationHook`]s, which are run in the order they
+/// were registered in.
+pub struct EpochInflationHooks<'a, S> {
+    hooks: Vec<Box<dyn EpochInflationHook<S> + 'a>>,
+}
+
+impl<S> Default for EpochInflationHooks<'_, S> {
+    fn default() -> Self {
+        Self { hooks: Vec::new() }
+    }
+}
+
+impl<'a, S> EpochInflationHooks<'a, S> {
+    /// Register a hook to run after the already registered ones
+    pub fn register(
+        &mut self,
+        hook: impl EpochInflationHook<S> + 'a,
+    ) -> &mut Self {
+        self.hooks.push(Box::new(hook));
+        self
+    }
+}
+
+impl<S> EpochInflationHook<S> for EpochInflationHooks<'_, S> {
+    fn on_epoch_change(
+        &mut self,
+        storage: &mut S,
+        token: &Address,
+        epoch: MaspEpoch,
+    ) -> Result<()> {
+        for hook in &mut self.hooks {
+            hook.on_epoch_change(storage, token, epoch)?;
+        }
+        Ok(())
+    }
+}
+
+/// The MASP rewards controller, as an [`EpochInflationHook`]. The rewards it
+/// computes for each token are retained until taken.
+pub struct MaspInflationHook<TransToken> {
+    masp_epochs_per_year: u64,
+    rewards: BTreeMap<Address, ((u128, u128), Denomination)>,
+    _trans_token: PhantomData<TransToken>,
+}
+
+impl<TransToken> MaspInflationHook<TransToken> {
+    /// Instantiate the MASP rewards controller hook
+    pub fn new(masp_epochs_per_year: u64) -> Self {
+        Self {
+            masp_epochs_per_year,
+            rewards: BTreeMap::new(),
+            _trans_token: PhantomData,
+        }
+    }
+
+    /// Take the rewards last computed for the given token, along with their
+    /// precision and the denomination of the token
+    pub fn take_rewards(
+        &mut self,
+        token: &Address,
+    ) -> Option<((u128, u128), Denomination)> {
+        self.rewards.remove(token)
+    }
+}
+
+impl<S, TransToken> EpochInflationHook<S> for MaspInflationHook<TransToken>
+where
+    S: StorageWrite + StorageRead,
+    TransToken: trans_token::Keys + trans_token::Read<S>,
+{
+    fn on_epoch_change(
+        &mut self,
+        storage: &mut S,
+        token: &Address,
+        epoch: MaspEpoch,
+    ) -> Result<()> {
+        let rewards = calculate_masp_rewards::<S, TransToken>(
+            storage,
+            token,
+            self.masp_epochs_per_year,
+        )?;
+        self.rewards.insert(token.clone(), rewards);
+
+        // Record the outputs of the controller in the inflation history
+        let inflation: Amount = storage
+            .read(&masp_last_inflation_key::<TransToken>(token))?
+            .unwrap_or_default();
+        let locked_amount: Amount = storage
+            .read(&masp_last_locked_amount_key::<TransToken>(token))?
+            .unwrap_or_default();
+        crate::write_inflation_sample::<S, TransToken>(
+            storage,
+            token,
+            epoch,
+            inflation,
+            locked_amount,
+        )
+    }
+}
+
+/// Read the tokens rewarded by the MASP, with the native token first
+fn masp_reward_tokens<S>(storage: &S) -> Result<Vec<Address>>
+where
+    S: StorageRead,
+{
+    use std::cmp::Ordering;
+
+    let token_map: namada_core::masp::TokenMap =
+        storage.read(&masp_token_map_key())?.unwrap_or_default();
+    let mut masp_reward_keys: Vec<_> = token_map.values().cloned().collect();
+    // Put the native rewards first because other inflation computations depend
+    // on it
+    let native_token = storage.get_native_token()?;
+    masp_reward_keys.sort_unstable_by(|x, y| {
+        if (*x == native_token) == (*y == native_token) {
+            Ordering::Equal
+        } else if *x == native_token {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        }
+    });
+    Ok(masp_reward_keys)
+}
+
 // This is only enabled when "wasm-runtime" is on, because we're using rayon
 #[cfg(not(any(feature = "multicore", test)))]
 /// Update the MASP's allowed conversions
@@ -241,6 +492,35 @@ where
     Ok(())
 }
 
+// This is only enabled when "wasm-runtime" is on, because we're using rayon
+#[cfg(not(any(feature = "multicore", test)))]
+/// Update the MASP's allowed conversions, running the given hooks for every
+/// rewarded token. The conversions themselves are left untouched, but the
+/// hooks are still run so that their effects don't depend on the build.
+pub fn update_allowed_conversions_with_hooks<S, Params, TransToken>(
+    storage: &mut S,
+    hooks: &mut impl EpochInflationHook<S>,
+) -> Result<()>
+where
+    S: StorageWrite + StorageRead + WithConversionState,
+    Params: parameters::Read<S>,
+    TransToken: trans_token::Keys,
+{
+    let masp_epoch_multiplier = Params::masp_epoch_multiplier(storage)?;
+    let masp_epoch = MaspEpoch::try_from_epoch(
+        storage.get_block_epoch()?,
+        masp_epoch_multiplier,
+    )
+    .map_err(Error::new_const)?;
+    if masp_epoch.prev().is_none() {
+        return Ok(());
+    }
+    for token in masp_reward_tokens(storage)? {
+        hooks.on_epoch_change(storage, &token, masp_epoch)?;
+    }
+    Ok(())
+}
+
 #[cfg(any(feature = "multicore", test))]
 /// Update the MASP's allowed conversions
 pub fn update_allowed_conversions<S, Params, TransToken>(
@@ -252,9 +532,25 @@ where
     TransToken:
         trans_token::Keys + trans_token::Read<S> + trans_token::Write<S>,
 {
-    use std::cmp::Ordering;
-    use std::collections::BTreeMap;
+    update_allowed_conversions_with_hooks::<S, Params, TransToken>(
+        storage,
+        &mut EpochInflationHooks::default(),
+    )
+}
 
+#[cfg(any(feature = "multicore", test))]
+/// Update the MASP's allowed conversions, running the given hooks for every
+/// rewarded token after the MASP rewards controller
+pub fn update_allowed_conversions_with_hooks<S, Params, TransToken>(
+    storage: &mut S,
+    hooks: &mut impl EpochInflationHook<S>,
+) -> Result<()>
+where
+    S: StorageWrite + StorageRead + WithConversionState,
+    Params: parameters::Read<S>,
+    TransToken:
+        trans_token::Keys + trans_token::Read<S> + trans_token::Write<S>,
+{
     use masp_primitives::bls12_381;
     use masp_primitives::convert::AllowedConversion;
     use masp_primitives::ff::PrimeField;
@@ -262,35 +558,21 @@ where
     use masp_primitives::sapling::Node;
     use masp_primitives::transaction::components::I128Sum as MaspAmount;
     use namada_core::arith::CheckedAdd;
-    use namada_core::masp::{encode_asset_type, MaspEpoch};
+    use namada_core::masp::encode_asset_type;
     use namada_core::token::{MaspDigitPos, NATIVE_MAX_DECIMAL_PLACES};
     use rayon::iter::{
         IndexedParallelIterator, IntoParallelIterator, ParallelIterator,
     };
     use rayon::prelude::ParallelSlice;
 
-    use crate::{mint_rewards, ConversionLeaf, Error, OptionExt, ResultExt};
+    use crate::{mint_rewards, ConversionLeaf, OptionExt};
 
     // The derived conversions will be placed in MASP address space
     let masp_addr = MASP;
 
-    let token_map_key = masp_token_map_key();
-    let token_map: namada_core::masp::TokenMap =
-        storage.read(&token_map_key)?.unwrap_or_default();
-    let mut masp_reward_keys: Vec<_> = token_map.values().cloned().collect();
+    let masp_reward_keys = masp_reward_tokens(storage)?;
     let mut masp_reward_denoms = BTreeMap::new();
-    // Put the native rewards first because other inflation computations depend
-    // on it
     let native_token = storage.get_native_token()?;
-    masp_reward_keys.sort_unstable_by(|x, y| {
-        if (*x == native_token) == (*y == native_token) {
-            Ordering::Equal
-        } else if *x == native_token {
-            Ordering::Less
-        } else {
-            Ordering::Greater
-        }
-    });
     // The total transparent value of the rewards being distributed
     let mut total_reward = Amount::zero();
 
@@ -354,13 +636,14 @@ where
     let epochs_per_year = Params::epochs_per_year(storage)?;
     let masp_epochs_per_year =
         checked!(epochs_per_year / masp_epoch_multiplier)?;
+    let mut masp_hook =
+        MaspInflationHook::<TransToken>::new(masp_epochs_per_year);
     for token in &masp_reward_keys {
-        let ((reward, precision), denom) =
-            calculate_masp_rewards::<S, TransToken>(
-                storage,
-                token,
-                masp_epochs_per_year,
-            )?;
+        masp_hook.on_epoch_change(storage, token, masp_epoch)?;
+        hooks.on_epoch_change(storage, token, masp_epoch)?;
+        let ((reward, precision), denom) = masp_hook
+            .take_rewards(token)
+            .ok_or_err_msg("Missing the MASP rewards of the token")?;
         masp_reward_denoms.insert(token.clone(), denom);
         // Dispense a transparent reward in parallel to the shielded rewards
         let addr_bal = TransToken::read_balance(storage, token, &masp_addr)?;
@@ -654,6 +937,7 @@ mod tests {
     use namada_core::collections::HashMap;
     use namada_core::dec::testing::arb_non_negative_dec;
     use namada_core::token::testing::arb_amount;
+    use namada_core::token::NATIVE_MAX_DECIMAL_PLACES;
     use namada_state::testing::TestStorage;
     use namada_trans_token::storage_key::{balance_key, minted_balance_key};
     use namada_trans_token::write_denom;
@@ -662,6 +946,7 @@ mod tests {
     use test_log::test;
 
     use super::*;
+    use crate::storage_key::masp_locked_amount_target_key;
     use crate::ShieldedParams;
 
     proptest! {
@@ -696,6 +981,7 @@ mod tests {
                 kp_gain_nom: Dec::from_str("0.1").unwrap(),
                 kd_gain_nom: Dec::from_str("0.1").unwrap(),
                 locked_amount_target: 10_000_u64,
+                inflation_history_window: 0,
             };
 
             for (token_addr, (alias, denom)) in tokens() {
@@ -744,6 +1030,406 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_compute_inflation_overflow_is_an_error() {
+        let denom = Uint::from(1_000_000);
+        let total_tokens = Uint::from(10_000_000_000_u64) * denom;
+        let locked_tokens = Uint::from(500_000) * denom;
+        // A misconfigured gain parameter
+        let p_gain_nom = Dec(namada_core::uint::I256::maximum());
+
+        let result = compute_inflation(
+            locked_tokens,
+            total_tokens,
+            Dec::from_str("0.01").unwrap(),
+            Uint::zero(),
+            p_gain_nom,
+            Dec::from_str("25000").unwrap(),
+            730,
+            Dec::try_from(locked_tokens).unwrap(),
+            Dec::try_from(locked_tokens).unwrap(),
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_compute_inflation_step() {
+        // With a single epoch per year and equal gains, each step adjusts the
+        // last inflation by `max_reward_rate * gain * (target - last_locked)`
+        let params = ShieldedParams {
+            max_reward_rate: Dec::from_str("0.1").unwrap(),
+            kd_gain_nom: Dec::one(),
+            kp_gain_nom: Dec::one(),
+            locked_amount_target: 1_000,
+            inflation_history_window: 0,
+        };
+        let large_gains = ShieldedParams {
+            kd_gain_nom: Dec::from(1_000_000),
+            kp_gain_nom: Dec::from(1_000_000),
+            ..params.clone()
+        };
+        let total_native_amount = Amount::from_u64(10_000_000);
+
+        // (params, last locked, locked, last inflation, rate, inflation)
+        let cases = [
+            // Below the target, inflation rises
+            (&params, 0, 0, 0, "0.00001", 100),
+            (&params, 500, 500, 100, "0.000015", 150),
+            // At the target, inflation holds steady
+            (&params, 1_000, 1_000, 150, "0.000015", 150),
+            // Above the target, inflation falls
+            (&params, 1_500, 1_500, 150, "0.00001", 100),
+            // Inflation never goes negative
+            (&params, 5_000, 5_000, 100, "0", 0),
+            // Inflation is clamped at the max reward rate
+            (&large_gains, 0, 0, 0, "0.1", 1_000_000),
+        ];
+        for (params, last_locked, locked, last_inflation, rate, inflation) in
+            cases
+        {
+            let (new_rate, new_inflation) = compute_inflation_step(
+                Amount::from_u64(last_locked),
+                Amount::from_u64(locked),
+                Amount::from_u64(last_inflation),
+                total_native_amount,
+                1,
+                params.max_reward_rate,
+                params.kp_gain_nom,
+                params.kd_gain_nom,
+                Amount::from_u64(params.locked_amount_target),
+                None,
+            )
+            .unwrap();
+            assert_eq!(new_rate, Dec::from_str(rate).unwrap());
+            assert_eq!(new_inflation, Amount::from_u64(inflation));
+            assert!(new_rate <= params.max_reward_rate);
+        }
+    }
+
+    #[test]
+    fn test_max_inflation() {
+        let max_reward_rate = Dec::from_str("0.1").unwrap();
+        let total_native_amount = Amount::from_u64(10_000_000);
+        // (epochs per year, max inflation)
+        let cases = [(1, 1_000_000), (4, 250_000), (3, 333_333), (0, 0)];
+        for (epochs_per_year, expected) in cases {
+            assert_eq!(
+                max_inflation(
+                    total_native_amount,
+                    max_reward_rate,
+                    epochs_per_year
+                )
+                .unwrap(),
+                Amount::from_u64(expected),
+            );
+        }
+        // A non-positive max reward rate never mints anything
+        for rate in ["0", "-0.1"] {
+            assert_eq!(
+                max_inflation(
+                    total_native_amount,
+                    Dec::from_str(rate).unwrap(),
+                    1
+                )
+                .unwrap(),
+                Amount::zero(),
+            );
+        }
+    }
+
+    #[test]
+    fn test_clamp_reward_rate() {
+        let max_reward_rate = Dec::from_str("0.1").unwrap();
+        // (raw rate, clamped rate)
+        let cases = [
+            ("0.05", "0.05"),
+            ("0.1", "0.1"),
+            // Overshooting the max is clamped to the max
+            ("0.100000000001", "0.1"),
+            ("25", "0.1"),
+            // Undershooting zero is floored at zero
+            ("0", "0"),
+            ("-0.000000000001", "0"),
+            ("-25", "0"),
+        ];
+        for (rate, clamped) in cases {
+            assert_eq!(
+                clamp_reward_rate(
+                    Dec::from_str(rate).unwrap(),
+                    max_reward_rate
+                ),
+                Dec::from_str(clamped).unwrap(),
+            );
+        }
+    }
+
+    /// Test that the reward rate and inflation of a controller step whose raw
+    /// output overshoots the max reward rate, or would go negative, are
+    /// clamped.
+    #[test]
+    fn test_compute_inflation_step_is_clamped() {
+        let params = ShieldedParams {
+            max_reward_rate: Dec::from_str("0.1").unwrap(),
+            kd_gain_nom: Dec::from(1_000_000),
+            kp_gain_nom: Dec::from(1_000_000),
+            locked_amount_target: 1_000,
+            inflation_history_window: 0,
+        };
+        let total_native_amount = Amount::from_u64(10_000_000);
+        let step = |last_locked, locked, last_inflation| {
+            compute_inflation_step(
+                Amount::from_u64(last_locked),
+                Amount::from_u64(locked),
+                Amount::from_u64(last_inflation),
+                total_native_amount,
+                1,
+                params.max_reward_rate,
+                params.kp_gain_nom,
+                params.kd_gain_nom,
+                Amount::from_u64(params.locked_amount_target),
+                None,
+            )
+            .unwrap()
+        };
+
+        // Far below the target, the raw output overshoots the max, and so
+        // does the minted inflation
+        let (rate, inflation) = step(0, 0, 0);
+        assert_eq!(rate, params.max_reward_rate);
+        assert_eq!(inflation, Amount::from_u64(1_000_000));
+        assert_eq!(
+            inflation,
+            max_inflation(total_native_amount, params.max_reward_rate, 1)
+                .unwrap()
+        );
+
+        // Starting from an inflation above the max, the minted inflation is
+        // brought back within the max
+        let (rate, inflation) = step(0, 0, 5_000_000);
+        assert_eq!(rate, params.max_reward_rate);
+        assert_eq!(inflation, Amount::from_u64(1_000_000));
+
+        // Far above the target, the raw output would go negative
+        let (rate, inflation) = step(1_000_000, 1_000_000, 1_000_000);
+        assert_eq!(rate, Dec::zero());
+        assert_eq!(inflation, Amount::zero());
+    }
+
+    #[test]
+    fn test_compute_inflation_step_is_capped_by_supply_headroom() {
+        let params = ShieldedParams {
+            max_reward_rate: Dec::from_str("0.1").unwrap(),
+            kd_gain_nom: Dec::one(),
+            kp_gain_nom: Dec::one(),
+            locked_amount_target: 1_000,
+            inflation_history_window: 0,
+        };
+        let step = |supply_headroom| {
+            compute_inflation_step(
+                Amount::zero(),
+                Amount::zero(),
+                Amount::zero(),
+                Amount::from_u64(10_000_000),
+                1,
+                params.max_reward_rate,
+                params.kp_gain_nom,
+                params.kd_gain_nom,
+                Amount::from_u64(params.locked_amount_target),
+                supply_headroom,
+            )
+            .unwrap()
+        };
+
+        // The uncapped inflation is 100
+        assert_eq!(step(None).1, Amount::from_u64(100));
+        assert_eq!(
+            step(Some(Amount::from_u64(1_000))).1,
+            Amount::from_u64(100)
+        );
+        // The rate is derived from the capped inflation
+        let (rate, inflation) = step(Some(Amount::from_u64(50)));
+        assert_eq!(inflation, Amount::from_u64(50));
+        assert_eq!(rate, Dec::from_str("0.000005").unwrap());
+        let (rate, inflation) = step(Some(Amount::zero()));
+        assert_eq!(inflation, Amount::zero());
+        assert_eq!(rate, Dec::zero());
+    }
+
+    #[test]
+    fn test_masp_inflation_is_capped_by_supply_cap() {
+        let mut s = TestStorage::default();
+        let token = address::testing::nam();
+        let params = ShieldedParams {
+            max_reward_rate: Dec::from_str("0.1").unwrap(),
+            kd_gain_nom: Dec::from(1_000_000),
+            kp_gain_nom: Dec::from(1_000_000),
+            locked_amount_target: 1_000,
+            inflation_history_window: 0,
+        };
+        crate::write_params::<_, namada_trans_token::Store<()>>(
+            &params,
+            &mut s,
+            &token,
+            &NATIVE_MAX_DECIMAL_PLACES.into(),
+        )
+        .unwrap();
+        s.write(&minted_balance_key(&token), Amount::from_u64(1_000_000))
+            .unwrap();
+        // Lock exactly the precision of the token in the MASP so that the
+        // inflation is handed out without any rounding
+        s.write(
+            &balance_key(&token, &address::MASP),
+            Amount::from_u64(1_000),
+        )
+        .unwrap();
+        // The uncapped inflation is at the max reward rate of 100_000
+        crate::write_supply_cap::<_, namada_trans_token::Store<()>>(
+            &mut s,
+            &token,
+            Amount::from_u64(1_000_500),
+        )
+        .unwrap();
+
+        let ((reward, precision), _denom) = calculate_masp_rewards::<
+            _,
+            namada_trans_token::Store<_>,
+        >(&mut s, &token, 1)
+        .unwrap();
+
+        assert_eq!((reward, precision), (500, 1_000));
+        let last_inflation: Amount = s
+            .read(&masp_last_inflation_key::<namada_trans_token::Store<()>>(
+                &token,
+            ))
+            .unwrap()
+            .unwrap();
+        assert_eq!(last_inflation, Amount::from_u64(500));
+    }
+
+    /// Test that the MASP rewards are steered towards the exact locked amount
+    /// target, even if it is not a whole number of tokens.
+    #[test]
+    fn test_masp_rewards_use_exact_locked_amount_target() {
+        type TransToken = namada_trans_token::Store<()>;
+
+        let mut s = TestStorage::default();
+        let token = address::testing::nam();
+        let params = ShieldedParams {
+            max_reward_rate: Dec::from_str("0.1").unwrap(),
+            kd_gain_nom: Dec::one(),
+            kp_gain_nom: Dec::one(),
+            locked_amount_target: 1,
+            inflation_history_window: 0,
+        };
+        crate::write_params::<_, TransToken>(
+            &params,
+            &mut s,
+            &token,
+            &NATIVE_MAX_DECIMAL_PLACES.into(),
+        )
+        .unwrap();
+        // A target of 1.500001 tokens, which is not a multiple of 10^6
+        let target = Amount::from_u64(1_500_001);
+        s.write(&masp_locked_amount_target_key::<TransToken>(&token), target)
+            .unwrap();
+        let total_native_amount = Amount::from_u64(1_000_000_000_000);
+        s.write(&minted_balance_key(&token), total_native_amount)
+            .unwrap();
+        // Lock exactly the precision of the token in the MASP so that the
+        // inflation is handed out without any rounding
+        let locked_amount = Amount::from_u64(1_000);
+        s.write(&balance_key(&token, &address::MASP), locked_amount)
+            .unwrap();
+
+        calculate_masp_rewards::<_, namada_trans_token::Store<_>>(
+            &mut s, &token, 1,
+        )
+        .unwrap();
+
+        let step = |target_locked_amount| {
+            compute_inflation_step(
+                Amount::zero(),
+                locked_amount,
+                Amount::zero(),
+                total_native_amount,
+                1,
+                params.max_reward_rate,
+                params.kp_gain_nom,
+                params.kd_gain_nom,
+                target_locked_amount,
+                None,
+            )
+            .unwrap()
+            .1
+        };
+        let last_inflation: Amount = s
+            .read(&masp_last_inflation_key::<TransToken>(&token))
+            .unwrap()
+            .unwrap();
+        assert_eq!(last_inflation, step(target));
+        // Flooring the target to whole tokens would have changed the outcome
+        assert_ne!(last_inflation, step(Amount::from_u64(1_000_000)));
+    }
+
+    /// An inflation hook that records its invocations
+    #[derive(Default)]
+    struct RecordingHook {
+        calls: Vec<(Address, MaspEpoch)>,
+    }
+
+    impl<S> EpochInflationHook<S> for RecordingHook {
+        fn on_epoch_change(
+            &mut self,
+            _storage: &mut S,
+            token: &Address,
+            epoch: MaspEpoch,
+        ) -> Result<()> {
+            self.calls.push((token.clone(), epoch));
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_epoch_inflation_hooks() {
+        type TransToken = namada_trans_token::Store<TestStorage>;
+
+        let mut s = TestStorage::default();
+        let token = address::testing::nam();
+        crate::write_params::<_, TransToken>(
+            &ShieldedParams::default(),
+            &mut s,
+            &token,
+            &NATIVE_MAX_DECIMAL_PLACES.into(),
+        )
+        .unwrap();
+        s.write(&minted_balance_key(&token), Amount::from_u64(1_000_000))
+            .unwrap();
+        s.write(
+            &balance_key(&token, &address::MASP),
+            Amount::from_u64(1_000),
+        )
+        .unwrap();
+
+        let mut masp_hook = MaspInflationHook::<TransToken>::new(1);
+        let mut recorder = RecordingHook::default();
+        let epoch_1 = MaspEpoch::zero().next().unwrap();
+        let epoch_2 = epoch_1.next().unwrap();
+        {
+            let mut hooks = EpochInflationHooks::default();
+            hooks.register(&mut masp_hook).register(&mut recorder);
+            hooks.on_epoch_change(&mut s, &token, epoch_1).unwrap();
+            hooks.on_epoch_change(&mut s, &token, epoch_2).unwrap();
+        }
+
+        assert_eq!(
+            recorder.calls,
+            vec![(token.clone(), epoch_1), (token.clone(), epoch_2)]
+        );
+        assert!(masp_hook.take_rewards(&token).is_some());
+        assert!(masp_hook.take_rewards(&token).is_none());
+    }
+
     pub fn tokens() -> HashMap<Address, (&'static str, Denomination)> {
         vec![
             (address::testing::nam(), ("nam", 6.into())),
@@ -792,7 +1478,8 @@ mod tests {
                 epochs_per_year,
                 Dec::try_from(locked_tokens_target).unwrap(),
                 Dec::try_from(locked_tokens_last).unwrap(),
-            );
+            )
+            .unwrap();
 
             let rate = Dec::try_from(inflation).unwrap()
                 * Dec::from(epochs_per_year)
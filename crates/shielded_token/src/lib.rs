@@ -38,6 +38,7 @@ pub use namada_state::{
 };
 use serde::{Deserialize, Serialize};
 pub use storage::*;
+use thiserror::Error;
 
 #[cfg(feature = "masp")]
 pub use crate::masp::shielded_wallet::ShieldedWallet;
@@ -67,6 +68,10 @@ pub struct ShieldedParams {
     /// Target amount for the given token that is locked in the shielded pool
     // TODO(namada#3255): use `Uint` here
     pub locked_amount_target: u64,
+    /// Number of MASP epochs whose inflation samples are retained for the
+    /// given token. A window of zero disables the inflation history.
+    #[serde(default)]
+    pub inflation_history_window: u64,
 }
 
 impl Default for ShieldedParams {
@@ -76,6 +81,130 @@ impl Default for ShieldedParams {
             kp_gain_nom: Dec::from_str("0.25").unwrap(),
             kd_gain_nom: Dec::from_str("0.25").unwrap(),
             locked_amount_target: 10_000_u64,
+            inflation_history_window: 0,
         }
     }
 }
+
+/// Errors of invalid [`ShieldedParams`]
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ParamsError {
+    /// A parameter that cannot be negative has a negative value
+    #[error(
+        "Shielded token parameter `{field}` must not be negative, got {value}"
+    )]
+    Negative {
+        /// The name of the offending parameter
+        field: &'static str,
+        /// The value of the offending parameter
+        value: Dec,
+    },
+    /// A parameter is outside of the bounds of values that can be stored
+    #[error(
+        "Shielded token parameter `{field}` is outside of the storage bounds"
+    )]
+    OutOfBounds {
+        /// The name of the offending parameter
+        field: &'static str,
+    },
+}
+
+impl ShieldedParams {
+    /// Validate the parameters and return them in their canonical form, such
+    /// that two logically equal parameter sets serialize to the same bytes.
+    ///
+    /// A [`Dec`] is a fixed-point number, so logically equal decimal values
+    /// (e.g. `0.1` and `0.10`) already share a single representation and
+    /// normalizing them amounts to checking their bounds.
+    pub fn normalize(&self) -> std::result::Result<Self, ParamsError> {
+        self.validate()?;
+        Ok(self.clone())
+    }
+
+    /// Check that the parameters are within their bounds, i.e. that the
+    /// maximum reward rate and the controller gains can be stored and are not
+    /// negative. Being fixed-point numbers, [`Dec`] values are always finite.
+    pub fn validate(&self) -> std::result::Result<(), ParamsError> {
+        let non_negative = |field: &'static str, value: Dec| {
+            if !value.is_within_storage_bounds() {
+                Err(ParamsError::OutOfBounds { field })
+            } else if value.is_negative() {
+                Err(ParamsError::Negative { field, value })
+            } else {
+                Ok(())
+            }
+        };
+        non_negative("max_reward_rate", self.max_reward_rate)?;
+        non_negative("kd_gain_nom", self.kd_gain_nom)?;
+        non_negative("kp_gain_nom", self.kp_gain_nom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use namada_core::borsh::BorshSerializeExt;
+
+    use super::*;
+
+    /// Test that logically equal parameters normalize to the same bytes.
+    #[test]
+    fn test_normalized_params_serialize_identically() {
+        let params_a = ShieldedParams {
+            max_reward_rate: Dec::from_str("0.10").expect("Test failed"),
+            kd_gain_nom: Dec::from_str("0.250000").expect("Test failed"),
+            kp_gain_nom: Dec::from_str("0.25").expect("Test failed"),
+            locked_amount_target: 10_000,
+            inflation_history_window: 0,
+        };
+        let params_b = ShieldedParams {
+            max_reward_rate: Dec::new(1, 1).expect("Test failed"),
+            kd_gain_nom: Dec::new(25, 2).expect("Test failed"),
+            kp_gain_nom: Dec::new(250, 3).expect("Test failed"),
+            locked_amount_target: 10_000,
+            inflation_history_window: 0,
+        };
+
+        let normalized_a = params_a.normalize().expect("Test failed");
+        let normalized_b = params_b.normalize().expect("Test failed");
+        assert_eq!(normalized_a, normalized_b);
+        assert_eq!(
+            normalized_a.serialize_to_vec(),
+            normalized_b.serialize_to_vec()
+        );
+    }
+
+    /// Test that negative parameters are rejected by normalization.
+    #[test]
+    fn test_normalize_rejects_negative_params() {
+        let value = Dec::from_str("-0.1").expect("Test failed");
+        let params = ShieldedParams {
+            kp_gain_nom: value,
+            ..Default::default()
+        };
+        assert_eq!(
+            params.normalize(),
+            Err(ParamsError::Negative {
+                field: "kp_gain_nom",
+                value
+            })
+        );
+    }
+
+    /// Test that parameters with a negative maximum reward rate fail
+    /// validation, naming the offending field.
+    #[test]
+    fn test_validate_params() {
+        ShieldedParams::default().validate().expect("Test failed");
+
+        let params = ShieldedParams {
+            max_reward_rate: Dec::from_str("-1").expect("Test failed"),
+            ..Default::default()
+        };
+        let err = params.validate().expect_err("Test failed");
+        assert_eq!(
+            err.to_string(),
+            "Shielded token parameter `max_reward_rate` must not be negative, \
+             got -1"
+        );
+    }
+}
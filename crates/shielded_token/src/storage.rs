@@ -1,13 +1,17 @@
 use namada_core::address::{self, Address};
 use namada_core::arith::checked;
-use namada_core::masp::TokenMap;
+use namada_core::borsh::{BorshDeserialize, BorshSerialize};
+use namada_core::masp::{MaspEpoch, TokenMap};
 use namada_core::token;
 use namada_core::token::Amount;
 use namada_core::uint::Uint;
 use namada_systems::trans_token;
 
 use crate::storage_key::*;
-use crate::{Result, ResultExt, ShieldedParams, StorageRead, StorageWrite};
+use crate::{
+    Dec, Error, OptionExt, Result, ResultExt, ShieldedParams, StorageRead,
+    StorageWrite,
+};
 
 /// Initialize parameters for the token in storage during the genesis block.
 pub fn write_params<S, TransToken>(
@@ -16,6 +20,22 @@ pub fn write_params<S, TransToken>(
     token: &Address,
     denom: &token::Denomination,
 ) -> Result<()>
+where
+    S: StorageRead + StorageWrite,
+    TransToken: trans_token::Keys,
+{
+    params.validate().into_storage_result()?;
+    write_validated_params::<S, TransToken>(params, storage, token, denom)
+}
+
+/// Write the parameters of the token to storage, assuming they have already
+/// been validated.
+fn write_validated_params<S, TransToken>(
+    params: &ShieldedParams,
+    storage: &mut S,
+    token: &Address,
+    denom: &token::Denomination,
+) -> Result<()>
 where
     S: StorageRead + StorageWrite,
     TransToken: trans_token::Keys,
@@ -25,6 +45,7 @@ where
         kd_gain_nom,
         kp_gain_nom,
         locked_amount_target,
+        inflation_history_window,
     } = params;
     storage.write(
         &masp_last_inflation_key::<TransToken>(token),
@@ -37,19 +58,345 @@ where
     storage.write(&masp_max_reward_rate_key::<TransToken>(token), max_rate)?;
     storage.write(&masp_kp_gain_key::<TransToken>(token), kp_gain_nom)?;
     storage.write(&masp_kd_gain_key::<TransToken>(token), kd_gain_nom)?;
+    storage.write(
+        &masp_inflation_history_window_key::<TransToken>(token),
+        inflation_history_window,
+    )?;
+    write_locked_amount_target::<S, TransToken>(
+        storage,
+        token,
+        denom,
+        *locked_amount_target,
+    )
+}
+
+/// Initialize the parameters of many tokens in storage during the genesis
+/// block. The parameters of all the tokens are validated before any of
+/// them gets written.
+///
+/// The parameters are written with [`StorageWrite::write`], exactly like
+/// [`write_params`] does. Whether their diffs are persisted is decided by the
+/// diff key filter of the storage, as for any other key, so that the Merkle
+/// tree doesn't depend on which of the two functions initialized a token.
+pub fn write_params_bulk<S, TransToken>(
+    storage: &mut S,
+    params_by_token: &[(Address, token::Denomination, ShieldedParams)],
+) -> Result<()>
+where
+    S: StorageRead + StorageWrite,
+    TransToken: trans_token::Keys,
+{
+    for (_, _, params) in params_by_token {
+        params.validate().into_storage_result()?;
+    }
+    for (token, denom, params) in params_by_token {
+        write_validated_params::<S, TransToken>(params, storage, token, denom)?;
+    }
+    Ok(())
+}
+
+/// Read back the parameters of the token written by [`write_params`].
+///
+/// The locked amount target is converted back to whole tokens, and an error
+/// is returned if the stored target is not a whole number of tokens that fits
+/// in a `u64`, e.g. after being overwritten by a governance proposal. Use
+/// [`read_locked_amount_target`] for the exact target.
+pub fn read_params<S, TransToken>(
+    storage: &S,
+    token: &Address,
+    denom: &token::Denomination,
+) -> Result<ShieldedParams>
+where
+    S: StorageRead,
+    TransToken: trans_token::Keys,
+{
+    let max_reward_rate = storage
+        .read(&masp_max_reward_rate_key::<TransToken>(token))?
+        .ok_or_err_msg("Missing MASP max reward rate of the token")?;
+    let kp_gain_nom = storage
+        .read(&masp_kp_gain_key::<TransToken>(token))?
+        .ok_or_err_msg("Missing MASP kp gain of the token")?;
+    let kd_gain_nom = storage
+        .read(&masp_kd_gain_key::<TransToken>(token))?
+        .ok_or_err_msg("Missing MASP kd gain of the token")?;
+    let raw_target =
+        read_locked_amount_target::<S, TransToken>(storage, token)?;
+    let (locked_amount_target, remainder) = raw_target
+        .raw_amount()
+        .div_mod(locked_amount_target_scale(denom));
+    if !remainder.is_zero() {
+        return Err(Error::new_const(
+            "MASP locked amount target of the token is not a whole number of \
+             tokens",
+        ));
+    }
+    let locked_amount_target = u64::try_from(locked_amount_target)
+        .ok()
+        .ok_or_err_msg("MASP locked amount target of the token overflows")?;
+    let inflation_history_window =
+        read_inflation_history_window::<S, TransToken>(storage, token)?;
+    Ok(ShieldedParams {
+        max_reward_rate,
+        kd_gain_nom,
+        kp_gain_nom,
+        locked_amount_target,
+        inflation_history_window,
+    })
+}
+
+/// Read the exact locked amount target of the token, in its smallest unit.
+/// This is the target that the MASP rewards controller steers towards.
+pub fn read_locked_amount_target<S, TransToken>(
+    storage: &S,
+    token: &Address,
+) -> Result<Amount>
+where
+    S: StorageRead,
+    TransToken: trans_token::Keys,
+{
+    storage
+        .read(&masp_locked_amount_target_key::<TransToken>(token))?
+        .ok_or_err_msg("Missing MASP locked amount target of the token")
+}
+
+/// Set a cap on the total supply of the token, beyond which no MASP inflation
+/// is minted.
+pub fn write_supply_cap<S, TransToken>(
+    storage: &mut S,
+    token: &Address,
+    supply_cap: Amount,
+) -> Result<()>
+where
+    S: StorageRead + StorageWrite,
+    TransToken: trans_token::Keys,
+{
+    storage.write(&masp_supply_cap_key::<TransToken>(token), supply_cap)
+}
+
+/// Read the cap on the total supply of the token, if any.
+pub fn read_supply_cap<S, TransToken>(
+    storage: &S,
+    token: &Address,
+) -> Result<Option<Amount>>
+where
+    S: StorageRead,
+    TransToken: trans_token::Keys,
+{
+    storage.read(&masp_supply_cap_key::<TransToken>(token))
+}
+
+/// Update the parameters of the token in storage after validating them. Only
+/// the parameters that differ from the ones currently in storage are written,
+/// and the last inflation and locked amount of the token are left untouched.
+pub fn update_params<S, TransToken>(
+    storage: &mut S,
+    token: &Address,
+    denom: &token::Denomination,
+    new: &ShieldedParams,
+) -> Result<()>
+where
+    S: StorageRead + StorageWrite,
+    TransToken: trans_token::Keys,
+{
+    new.validate().into_storage_result()?;
+    // NB: the current locked amount target is compared exactly, as it may
+    // not be a whole number of tokens
+    let current_target =
+        read_locked_amount_target::<S, TransToken>(storage, token)?;
+
+    for (key, new_value) in [
+        (
+            masp_max_reward_rate_key::<TransToken>(token),
+            new.max_reward_rate,
+        ),
+        (masp_kp_gain_key::<TransToken>(token), new.kp_gain_nom),
+        (masp_kd_gain_key::<TransToken>(token), new.kd_gain_nom),
+    ] {
+        if storage.read::<Dec>(&key)? != Some(new_value) {
+            storage.write(&key, new_value)?;
+        }
+    }
+    if read_inflation_history_window::<S, TransToken>(storage, token)?
+        != new.inflation_history_window
+    {
+        storage.write(
+            &masp_inflation_history_window_key::<TransToken>(token),
+            new.inflation_history_window,
+        )?;
+    }
+    let new_target =
+        scale_locked_amount_target(denom, new.locked_amount_target)?;
+    if new_target != current_target {
+        storage.write(
+            &masp_locked_amount_target_key::<TransToken>(token),
+            new_target,
+        )?;
+    }
+    Ok(())
+}
+
+/// Write the locked amount target of the token, scaled by its denomination.
+fn write_locked_amount_target<S, TransToken>(
+    storage: &mut S,
+    token: &Address,
+    denom: &token::Denomination,
+    locked_amount_target: u64,
+) -> Result<()>
+where
+    S: StorageWrite,
+    TransToken: trans_token::Keys,
+{
+    storage.write(
+        &masp_locked_amount_target_key::<TransToken>(token),
+        scale_locked_amount_target(denom, locked_amount_target)?,
+    )
+}
 
-    let locked_amount_target = Uint::from(*locked_amount_target);
+/// Scale a locked amount target in whole tokens to the smallest unit of the
+/// token.
+fn scale_locked_amount_target(
+    denom: &token::Denomination,
+    locked_amount_target: u64,
+) -> Result<Amount> {
     let raw_target = checked!(
-        locked_amount_target * (Uint::from(10) ^ Uint::from(denom.0))
+        Uint::from(locked_amount_target) * locked_amount_target_scale(denom)
     )?;
-    let raw_target = Amount::from_uint(raw_target, 0).into_storage_result()?;
+    Amount::from_uint(raw_target, 0).into_storage_result()
+}
+
+/// The factor by which the locked amount target of a token is scaled when
+/// written to storage.
+pub(crate) fn locked_amount_target_scale(denom: &token::Denomination) -> Uint {
+    Uint::from(10) ^ Uint::from(denom.0)
+}
+
+/// A sample of the outputs of the MASP rewards controller of a token, taken
+/// at some MASP epoch.
+#[derive(Clone, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct InflationSample {
+    /// The MASP epoch at which the sample was taken
+    pub epoch: MaspEpoch,
+    /// The inflation of the token computed at this epoch
+    pub inflation: Amount,
+    /// The amount of the token locked in the shielded pool at this epoch
+    pub locked_amount: Amount,
+}
+
+/// Read the inflation history window of the token from its parameters. The
+/// history is disabled for tokens whose parameters predate the window.
+fn read_inflation_history_window<S, TransToken>(
+    storage: &S,
+    token: &Address,
+) -> Result<u64>
+where
+    S: StorageRead,
+    TransToken: trans_token::Keys,
+{
+    Ok(storage
+        .read(&masp_inflation_history_window_key::<TransToken>(token))?
+        .unwrap_or_default())
+}
+
+/// Append an inflation sample to the history of the token, pruning the
+/// samples taken more than a history window before the given MASP epoch.
+/// Does nothing if the inflation history of the token is disabled.
+pub fn write_inflation_sample<S, TransToken>(
+    storage: &mut S,
+    token: &Address,
+    epoch: MaspEpoch,
+    inflation: Amount,
+    locked_amount: Amount,
+) -> Result<()>
+where
+    S: StorageRead + StorageWrite,
+    TransToken: trans_token::Keys,
+{
+    let window =
+        read_inflation_history_window::<S, TransToken>(storage, token)?;
+    if window == 0 {
+        return Ok(());
+    }
     storage.write(
-        &masp_locked_amount_target_key::<TransToken>(token),
-        raw_target,
+        &masp_inflation_sample_key::<TransToken>(token, epoch),
+        InflationSample {
+            epoch,
+            inflation,
+            locked_amount,
+        },
     )?;
+
+    // The window may have shrunk since the older samples were written, so
+    // prune every sample that fell out of it rather than just the oldest one
+    let prefix = masp_inflation_history_prefix::<TransToken>(token);
+    let expired =
+        namada_state::iter_prefix::<InflationSample>(storage, &prefix)?
+            .filter_map(|res| match res {
+                Ok((key, sample)) => {
+                    (!is_in_window(sample.epoch, epoch, window))
+                        .then_some(Ok(key))
+                }
+                Err(err) => Some(Err(err)),
+            })
+            .collect::<Result<Vec<_>>>()?;
+    for key in expired {
+        storage.delete(&key)?;
+    }
     Ok(())
 }
 
+/// Read the retained inflation samples of the token, sorted by epoch. Only
+/// the samples within the history window of the most recent sample are
+/// returned.
+pub fn read_inflation_samples<S, TransToken>(
+    storage: &S,
+    token: &Address,
+) -> Result<Vec<InflationSample>>
+where
+    S: StorageRead,
+    TransToken: trans_token::Keys,
+{
+    let window =
+        read_inflation_history_window::<S, TransToken>(storage, token)?;
+    let prefix = masp_inflation_history_prefix::<TransToken>(token);
+    let mut samples =
+        namada_state::iter_prefix::<InflationSample>(storage, &prefix)?
+            .map(|res| res.map(|(_, sample)| sample))
+            .collect::<Result<Vec<_>>>()?;
+    samples.sort_by_key(|sample| sample.epoch);
+    if let Some(latest) = samples.last().map(|sample| sample.epoch) {
+        samples.retain(|sample| is_in_window(sample.epoch, latest, window));
+    }
+    Ok(samples)
+}
+
+/// Check if a sample taken at `sampled` is one of the `window` most recent
+/// MASP epochs up to `latest`.
+fn is_in_window(sampled: MaspEpoch, latest: MaspEpoch, window: u64) -> bool {
+    latest
+        .epochs_since(sampled)
+        .is_some_and(|elapsed| elapsed < window)
+}
+
+/// Read the inflation of the token handed out at the given MASP epoch from
+/// its inflation history. Returns `None` for MASP epochs at which no
+/// inflation was recorded, including those that fell out of the history
+/// window, or all of them if the inflation history of the token is disabled.
+/// This distinguishes the epochs whose rewards are unknown from those that
+/// had no rewards.
+pub fn read_masp_rewards<S, TransToken>(
+    storage: &S,
+    token: &Address,
+    epoch: MaspEpoch,
+) -> Result<Option<Amount>>
+where
+    S: StorageRead,
+    TransToken: trans_token::Keys,
+{
+    let sample: Option<InflationSample> =
+        storage.read(&masp_inflation_sample_key::<TransToken>(token, epoch))?;
+    Ok(sample.map(|sample| sample.inflation))
+}
+
 /// Mint MASP rewards tokens and increment the stored total rewards.
 pub fn mint_rewards<S, TransToken>(
     storage: &mut S,
@@ -96,3 +443,395 @@ where
     let token_map_key = masp_token_map_key();
     storage.write(&token_map_key, token_map)
 }
+
+#[cfg(test)]
+#[allow(clippy::arithmetic_side_effects)]
+mod tests {
+    use std::str::FromStr;
+
+    use namada_core::address::testing::nam;
+    use namada_core::storage;
+    use namada_state::testing::{TestState, TestStorage};
+
+    use super::*;
+    use crate::Dec;
+
+    type TransToken = namada_trans_token::Store<()>;
+
+    /// Test that the parameters read back from storage are the ones that
+    /// were written.
+    #[test]
+    fn test_read_params_roundtrip() {
+        let mut storage = TestStorage::default();
+        let token = nam();
+        let denom = token::Denomination(6);
+        let params = ShieldedParams {
+            max_reward_rate: Dec::from_str("0.05").expect("Test failed"),
+            kd_gain_nom: Dec::from_str("0.3").expect("Test failed"),
+            kp_gain_nom: Dec::from_str("0.2").expect("Test failed"),
+            locked_amount_target: 12_345,
+            inflation_history_window: 8,
+        };
+        write_params::<_, TransToken>(&params, &mut storage, &token, &denom)
+            .expect("Test failed");
+
+        let read = read_params::<_, TransToken>(&storage, &token, &denom)
+            .expect("Test failed");
+        assert_eq!(read, params);
+    }
+
+    /// Test that reading the parameters of a token without any fails.
+    #[test]
+    fn test_read_missing_params() {
+        let storage = TestStorage::default();
+        let err = read_params::<_, TransToken>(
+            &storage,
+            &nam(),
+            &token::Denomination(6),
+        )
+        .expect_err("Test failed");
+        assert!(err.to_string().contains("Missing MASP max reward rate"));
+    }
+
+    /// Test that a locked amount target that is not a whole number of tokens
+    /// is read back exactly, rather than being rounded to whole tokens.
+    #[test]
+    fn test_read_fractional_locked_amount_target() {
+        let mut storage = TestStorage::default();
+        let token = nam();
+        let denom = token::Denomination(6);
+        write_params::<_, TransToken>(
+            &ShieldedParams::default(),
+            &mut storage,
+            &token,
+            &denom,
+        )
+        .expect("Test failed");
+        let target = Amount::from_u64(12_345_678);
+        storage
+            .write(&masp_locked_amount_target_key::<TransToken>(&token), target)
+            .expect("Test failed");
+
+        assert_eq!(
+            read_locked_amount_target::<_, TransToken>(&storage, &token)
+                .expect("Test failed"),
+            target
+        );
+        let err = read_params::<_, TransToken>(&storage, &token, &denom)
+            .expect_err("Test failed");
+        assert!(err.to_string().contains("not a whole number of tokens"));
+    }
+
+    /// Test that parameters that could not be read back are not written.
+    #[test]
+    fn test_write_params_rejects_out_of_bounds() {
+        use namada_core::uint::I256;
+
+        let mut storage = TestStorage::default();
+        let token = nam();
+        let denom = token::Denomination(6);
+        // The negative zero of the underlying integer
+        let out_of_bounds =
+            Dec(I256(Uint([0, 0, 0, 0x8000_0000_0000_0000_u64])));
+        assert!(!out_of_bounds.is_within_storage_bounds());
+        let params = ShieldedParams {
+            kp_gain_nom: out_of_bounds,
+            ..Default::default()
+        };
+
+        let err = write_params::<_, TransToken>(
+            &params,
+            &mut storage,
+            &token,
+            &denom,
+        )
+        .expect_err("Test failed");
+        assert!(err.to_string().contains("kp_gain_nom"));
+        read_params::<_, TransToken>(&storage, &token, &denom)
+            .expect_err("Test failed");
+    }
+
+    /// Test that the parameters of many tokens are written at once, and that
+    /// none are written if any of them is invalid.
+    #[test]
+    fn test_write_params_bulk() {
+        let mut storage = TestStorage::default();
+        let denom = token::Denomination(6);
+        let valid = ShieldedParams::default();
+        let invalid = ShieldedParams {
+            kd_gain_nom: Dec::from_str("-0.1").expect("Test failed"),
+            ..Default::default()
+        };
+        let token_1 = address::testing::established_address_1();
+        let token_2 = address::testing::established_address_2();
+
+        write_params_bulk::<_, TransToken>(
+            &mut storage,
+            &[
+                (token_1.clone(), denom, valid.clone()),
+                (token_2.clone(), denom, invalid),
+            ],
+        )
+        .expect_err("Test failed");
+        read_params::<_, TransToken>(&storage, &token_1, &denom)
+            .expect_err("Test failed");
+
+        write_params_bulk::<_, TransToken>(
+            &mut storage,
+            &[
+                (token_1.clone(), denom, valid.clone()),
+                (token_2.clone(), denom, valid.clone()),
+            ],
+        )
+        .expect("Test failed");
+        for token in [token_1, token_2] {
+            let read = read_params::<_, TransToken>(&storage, &token, &denom)
+                .expect("Test failed");
+            assert_eq!(read, valid);
+        }
+    }
+
+    /// Test that updating a single parameter of a token only writes that
+    /// parameter, and that invalid updates are rejected.
+    #[test]
+    fn test_update_params() {
+        let mut state = TestState::default();
+        let token = nam();
+        let denom = token::Denomination(6);
+        let params = ShieldedParams::default();
+        write_params::<_, TransToken>(&params, &mut state, &token, &denom)
+            .expect("Test failed");
+        // Advance the controller state of the token
+        state
+            .write(
+                &masp_last_inflation_key::<TransToken>(&token),
+                Amount::from_u64(100),
+            )
+            .expect("Test failed");
+        state
+            .write(
+                &masp_last_locked_amount_key::<TransToken>(&token),
+                Amount::from_u64(1_000),
+            )
+            .expect("Test failed");
+        state.commit_block().expect("Test failed");
+        let is_written = |state: &TestState, key: &storage::Key| {
+            state
+                .write_log()
+                .read(key)
+                .expect("Test failed")
+                .0
+                .is_some()
+        };
+
+        let invalid = ShieldedParams {
+            max_reward_rate: Dec::from_str("-0.1").expect("Test failed"),
+            ..params.clone()
+        };
+        update_params::<_, TransToken>(&mut state, &token, &denom, &invalid)
+            .expect_err("Test failed");
+        assert!(!is_written(
+            &state,
+            &masp_max_reward_rate_key::<TransToken>(&token)
+        ));
+
+        let new = ShieldedParams {
+            max_reward_rate: Dec::from_str("0.2").expect("Test failed"),
+            ..params
+        };
+        update_params::<_, TransToken>(&mut state, &token, &denom, &new)
+            .expect("Test failed");
+
+        let read = read_params::<_, TransToken>(&state, &token, &denom)
+            .expect("Test failed");
+        assert_eq!(read, new);
+        assert!(is_written(
+            &state,
+            &masp_max_reward_rate_key::<TransToken>(&token)
+        ));
+        for key in [
+            masp_last_inflation_key::<TransToken>(&token),
+            masp_last_locked_amount_key::<TransToken>(&token),
+            masp_kp_gain_key::<TransToken>(&token),
+            masp_kd_gain_key::<TransToken>(&token),
+            masp_locked_amount_target_key::<TransToken>(&token),
+        ] {
+            assert!(!is_written(&state, &key), "{key} must not be written");
+        }
+    }
+
+    /// Set the inflation history window of the token through its parameters
+    fn set_inflation_history_window(
+        storage: &mut TestStorage,
+        token: &Address,
+        window: u64,
+    ) {
+        let denom = token::Denomination(6);
+        let params = ShieldedParams {
+            inflation_history_window: window,
+            ..Default::default()
+        };
+        let is_initialized = storage
+            .has_key(&masp_locked_amount_target_key::<TransToken>(token))
+            .expect("Test failed");
+        let result = if is_initialized {
+            update_params::<_, TransToken>(storage, token, &denom, &params)
+        } else {
+            write_params::<_, TransToken>(&params, storage, token, &denom)
+        };
+        result.expect("Test failed");
+    }
+
+    /// Test that the inflation history only retains the samples of the most
+    /// recent epochs, and returns them in epoch order, also after the history
+    /// window changes.
+    #[test]
+    fn test_inflation_history_window() {
+        let mut storage = TestStorage::default();
+        let token = nam();
+
+        // without a window, no history is retained
+        write_inflation_sample::<_, TransToken>(
+            &mut storage,
+            &token,
+            MaspEpoch::new(0),
+            Amount::native_whole(1),
+            Amount::native_whole(1),
+        )
+        .expect("Test failed");
+        assert!(
+            read_inflation_samples::<_, TransToken>(&storage, &token)
+                .expect("Test failed")
+                .is_empty()
+        );
+
+        set_inflation_history_window(&mut storage, &token, 5);
+        let sample = |epoch| InflationSample {
+            epoch: MaspEpoch::new(epoch),
+            inflation: Amount::native_whole(epoch),
+            locked_amount: Amount::native_whole(2 * epoch),
+        };
+        for epoch in 1..=5 {
+            write_inflation_sample::<_, TransToken>(
+                &mut storage,
+                &token,
+                MaspEpoch::new(epoch),
+                Amount::native_whole(epoch),
+                Amount::native_whole(2 * epoch),
+            )
+            .expect("Test failed");
+        }
+        assert_eq!(
+            read_inflation_samples::<_, TransToken>(&storage, &token)
+                .expect("Test failed"),
+            (1..=5).map(sample).collect::<Vec<_>>()
+        );
+
+        // shrinking the window only retains the most recent samples, both
+        // before and after another sample is written
+        set_inflation_history_window(&mut storage, &token, 3);
+        assert_eq!(
+            read_inflation_samples::<_, TransToken>(&storage, &token)
+                .expect("Test failed"),
+            (3..=5).map(sample).collect::<Vec<_>>()
+        );
+        write_inflation_sample::<_, TransToken>(
+            &mut storage,
+            &token,
+            MaspEpoch::new(6),
+            Amount::native_whole(6),
+            Amount::native_whole(12),
+        )
+        .expect("Test failed");
+        assert_eq!(
+            read_inflation_samples::<_, TransToken>(&storage, &token)
+                .expect("Test failed"),
+            (4..=6).map(sample).collect::<Vec<_>>()
+        );
+        for epoch in 1..=3 {
+            assert!(
+                !storage
+                    .has_key(&masp_inflation_sample_key::<TransToken>(
+                        &token,
+                        MaspEpoch::new(epoch)
+                    ))
+                    .expect("Test failed")
+            );
+        }
+    }
+
+    /// Test that the inflation of a token is read back for each epoch of its
+    /// inflation history.
+    #[test]
+    fn test_read_masp_rewards() {
+        let mut storage = TestStorage::default();
+        let token = nam();
+        set_inflation_history_window(&mut storage, &token, 2);
+
+        for (epoch, inflation) in [(1, 10), (2, 25)] {
+            write_inflation_sample::<_, TransToken>(
+                &mut storage,
+                &token,
+                MaspEpoch::new(epoch),
+                Amount::native_whole(inflation),
+                Amount::zero(),
+            )
+            .expect("Test failed");
+        }
+
+        for (epoch, inflation) in
+            [(0, None), (1, Some(10)), (2, Some(25)), (3, None)]
+        {
+            assert_eq!(
+                read_masp_rewards::<_, TransToken>(
+                    &storage,
+                    &token,
+                    MaspEpoch::new(epoch)
+                )
+                .expect("Test failed"),
+                inflation.map(Amount::native_whole)
+            );
+        }
+
+        // The inflation of the epochs pruned from the history is no longer
+        // available
+        write_inflation_sample::<_, TransToken>(
+            &mut storage,
+            &token,
+            MaspEpoch::new(3),
+            Amount::native_whole(5),
+            Amount::zero(),
+        )
+        .expect("Test failed");
+        for (epoch, inflation) in [(1, None), (2, Some(25)), (3, Some(5))] {
+            assert_eq!(
+                read_masp_rewards::<_, TransToken>(
+                    &storage,
+                    &token,
+                    MaspEpoch::new(epoch)
+                )
+                .expect("Test failed"),
+                inflation.map(Amount::native_whole)
+            );
+        }
+
+        // Epochs without any rewards are told apart from unknown ones
+        write_inflation_sample::<_, TransToken>(
+            &mut storage,
+            &token,
+            MaspEpoch::new(4),
+            Amount::zero(),
+            Amount::zero(),
+        )
+        .expect("Test failed");
+        assert_eq!(
+            read_masp_rewards::<_, TransToken>(
+                &storage,
+                &token,
+                MaspEpoch::new(4)
+            )
+            .expect("Test failed"),
+            Some(Amount::zero())
+        );
+    }
+}
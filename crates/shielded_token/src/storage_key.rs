@@ -4,6 +4,7 @@ use masp_primitives::bls12_381::Scalar;
 use masp_primitives::sapling::Nullifier;
 use namada_core::address::{self, Address};
 use namada_core::hash::Hash;
+use namada_core::masp::MaspEpoch;
 use namada_core::storage::{self, DbKeySeg, KeySeg};
 use namada_systems::trans_token;
 
@@ -36,6 +37,12 @@ pub const MASP_LOCKED_AMOUNT_TARGET_KEY: &str = "locked_amount_target";
 pub const MASP_MAX_REWARD_RATE_KEY: &str = "max_reward_rate";
 /// The key for the total inflation rewards minted by MASP
 pub const MASP_TOTAL_REWARDS: &str = "max_total_rewards";
+/// Key segment prefix for the history of inflation samples of a given asset
+pub const MASP_INFLATION_HISTORY_KEY: &str = "inflation_history";
+/// The key for the inflation history window parameter of a given asset
+pub const MASP_INFLATION_HISTORY_WINDOW_KEY: &str = "inflation_history_window";
+/// The key for the cap on the total supply of a given asset
+pub const MASP_SUPPLY_CAP_KEY: &str = "supply_cap";
 
 /// Obtain the nominal proportional key for the given token
 pub fn masp_kp_gain_key<TransToken: trans_token::Keys>(
@@ -85,6 +92,42 @@ pub fn masp_last_inflation_key<TransToken: trans_token::Keys>(
         .with_segment(MASP_LAST_INFLATION_KEY.to_owned())
 }
 
+/// Obtain the storage key prefix for the history of inflation samples of a
+/// token
+pub fn masp_inflation_history_prefix<TransToken: trans_token::Keys>(
+    token_address: &Address,
+) -> storage::Key {
+    TransToken::parameter_prefix(token_address)
+        .with_segment(MASP_INFLATION_HISTORY_KEY.to_owned())
+}
+
+/// Obtain the storage key for the inflation sample of a token taken at the
+/// given MASP epoch
+pub fn masp_inflation_sample_key<TransToken: trans_token::Keys>(
+    token_address: &Address,
+    epoch: MaspEpoch,
+) -> storage::Key {
+    masp_inflation_history_prefix::<TransToken>(token_address)
+        .with_segment(epoch)
+}
+
+/// Obtain the storage key for the inflation history window parameter of a
+/// token
+pub fn masp_inflation_history_window_key<TransToken: trans_token::Keys>(
+    token_address: &Address,
+) -> storage::Key {
+    TransToken::parameter_prefix(token_address)
+        .with_segment(MASP_INFLATION_HISTORY_WINDOW_KEY.to_owned())
+}
+
+/// Obtain the storage key for the cap on the total supply of a token
+pub fn masp_supply_cap_key<TransToken: trans_token::Keys>(
+    token_address: &Address,
+) -> storage::Key {
+    TransToken::parameter_prefix(token_address)
+        .with_segment(MASP_SUPPLY_CAP_KEY.to_owned())
+}
+
 /// Check if the given storage key is MASP transparent balance key
 pub fn is_masp_balance_key(key: &storage::Key) -> bool {
     matches!(
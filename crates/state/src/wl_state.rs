@@ -795,19 +795,23 @@ where
     /// Check if we are at a given [`BlockHeight`] offset, `height_offset`,
     /// within the current epoch.
     pub fn is_deciding_offset_within_epoch(&self, height_offset: u64) -> bool {
-        let current_decision_height = self.get_current_decision_height();
+        self.get_offset_height_within_epoch(height_offset)
+            .map(|height| self.get_current_decision_height() == height)
+            .unwrap_or(false)
+    }
 
+    /// Get the [`BlockHeight`] at a given offset, `height_offset`, from the
+    /// start of the current epoch, if the start of the epoch is known.
+    pub fn get_offset_height_within_epoch(
+        &self,
+        height_offset: u64,
+    ) -> Option<BlockHeight> {
         let pred_epochs = &self.in_mem.block.pred_epochs;
         let fst_heights_of_each_epoch = pred_epochs.first_block_heights();
 
         fst_heights_of_each_epoch
             .last()
-            .and_then(|&h| {
-                let height_offset_within_epoch =
-                    h.checked_add(height_offset)?;
-                Some(current_decision_height == height_offset_within_epoch)
-            })
-            .unwrap_or(false)
+            .and_then(|&h| h.checked_add(height_offset))
     }
 
     /// Returns a value from the specified subspace at the given height (or the
@@ -468,3 +468,51 @@ pub mod testing {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::testing::TestStorage;
+    use super::*;
+
+    /// Test that iterating over a prefix with typed deserialization
+    /// yields the decoded values in key order, propagating decoding
+    /// errors of invalid values, and skipping keys outside the prefix.
+    #[test]
+    fn test_iter_prefix_typed() {
+        let mut storage = TestStorage::default();
+        let key = |key: &str| Key::parse(key).expect("Test failed");
+        let prefix = key("prefix");
+
+        storage.write(&key("prefix/a"), 1_u64).expect("Test failed");
+        storage
+            .write_bytes(&key("prefix/b"), [1_u8])
+            .expect("Test failed");
+        storage.write(&key("prefix/c"), 3_u64).expect("Test failed");
+        storage.write(&key("other/d"), 4_u64).expect("Test failed");
+
+        let items: Vec<_> = iter_prefix::<u64>(&storage, &prefix)
+            .expect("Test failed")
+            .collect();
+        assert_eq!(items.len(), 3);
+        assert_eq!(
+            items[0].as_ref().expect("Test failed"),
+            &(key("prefix/a"), 1)
+        );
+        assert!(items[1].is_err());
+        assert_eq!(
+            items[2].as_ref().expect("Test failed"),
+            &(key("prefix/c"), 3)
+        );
+
+        // the invalid value can be skipped with a filter
+        let invalid = key("prefix/b");
+        let items: Vec<_> =
+            iter_prefix_with_filter::<u64, _>(&storage, &prefix, move |k| {
+                k != &invalid
+            })
+            .expect("Test failed")
+            .collect::<Result<_>>()
+            .expect("Test failed");
+        assert_eq!(items, vec![(key("prefix/a"), 1), (key("prefix/c"), 3)]);
+    }
+}
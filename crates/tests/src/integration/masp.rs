@@ -86,6 +86,7 @@ fn init_null_rewards() -> Result<()> {
             kp_gain_nom: Dec::from_str("0").unwrap(),
             kd_gain_nom: Dec::from_str("0").unwrap(),
             locked_amount_target: 0,
+            inflation_history_window: 0,
         }),
         &mut node.shell.lock().unwrap().state,
         &test_token_addr,
@@ -187,6 +188,7 @@ fn init_null_rewards() -> Result<()> {
             kp_gain_nom: Dec::from_str("9999999999").unwrap(),
             kd_gain_nom: Dec::from_str("9999999999").unwrap(),
             locked_amount_target: 999999999u64,
+            inflation_history_window: 0,
         }),
         &mut node.shell.lock().unwrap().state,
         &test_token_addr,
@@ -619,6 +621,7 @@ fn values_spanning_multiple_masp_digits() -> Result<()> {
             kp_gain_nom: Dec::from_str("99999999999999999999").unwrap(),
             kd_gain_nom: Dec::from_str("99999999999999999999").unwrap(),
             locked_amount_target: u64::MAX,
+            inflation_history_window: 0,
         }),
         &mut node.shell.lock().unwrap().state,
         &test_token_addr,
@@ -1050,6 +1053,7 @@ fn enable_rewards_after_shielding() -> Result<()> {
             kp_gain_nom: Dec::from_str("9999999999").unwrap(),
             kd_gain_nom: Dec::from_str("9999999999").unwrap(),
             locked_amount_target: 999999999u64,
+            inflation_history_window: 0,
         }),
         &mut node.shell.lock().unwrap().state,
         &test_token_addr,
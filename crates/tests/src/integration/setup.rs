@@ -61,6 +61,7 @@ pub fn initialize_genesis(
             kp_gain_nom: Dec::from_str("0.1").unwrap(),
             kd_gain_nom: Dec::from_str("0.1").unwrap(),
             locked_amount_target: 1_000_000u64,
+            inflation_history_window: 0,
         });
     }
     let templates = update_genesis(templates);
@@ -109,18 +109,65 @@ where
     Ok(())
 }
 
+/// Initialize the parameters of many tokens in storage during the genesis
+/// block. The shielded parameters of all the tokens are validated before any
+/// of them gets written.
+pub fn write_params_bulk<S>(
+    storage: &mut S,
+    params_by_token: &[(Address, Denomination, Option<ShieldedParams>)],
+) -> Result<()>
+where
+    S: StorageRead + StorageWrite,
+{
+    let mut shielded_params = Vec::new();
+    for (address, denom, params) in params_by_token {
+        namada_trans_token::write_params(storage, address)?;
+        if let Some(params) = params {
+            shielded_params.push((address.clone(), *denom, params.clone()));
+        }
+    }
+    namada_shielded_token::write_params_bulk::<S, namada_trans_token::Store<()>>(
+        storage,
+        &shielded_params,
+    )
+}
+
 /// Apply token logic for finalizing block (i.e. shielded token rewards)
 pub fn finalize_block<S, Params>(
+    storage: &mut S,
+    events: &mut impl EmitEvents,
+    is_new_masp_epoch: bool,
+) -> Result<()>
+where
+    S: StorageWrite + StorageRead + WithConversionState,
+    Params: parameters::Read<S>,
+{
+    finalize_block_with_hooks::<S, Params>(
+        storage,
+        events,
+        is_new_masp_epoch,
+        &mut conversion::EpochInflationHooks::default(),
+    )
+}
+
+/// Apply token logic for finalizing block, running the given inflation hooks
+/// for every token rewarded by the MASP on a new MASP epoch
+pub fn finalize_block_with_hooks<S, Params>(
     storage: &mut S,
     _events: &mut impl EmitEvents,
     is_new_masp_epoch: bool,
+    hooks: &mut impl conversion::EpochInflationHook<S>,
 ) -> Result<()>
 where
     S: StorageWrite + StorageRead + WithConversionState,
     Params: parameters::Read<S>,
 {
+    use conversion::update_allowed_conversions_with_hooks;
+
     if is_new_masp_epoch {
-        conversion::update_allowed_conversions::<S, Params, Store<S>>(storage)?;
+        update_allowed_conversions_with_hooks::<S, Params, Store<S>>(
+            storage, hooks,
+        )?;
     }
     Ok(())
 }
@@ -418,6 +418,28 @@ impl BatchedTxResult {
     pub fn is_accepted(&self) -> bool {
         self.vps_result.rejected_vps.is_empty()
     }
+
+    /// Merge `other` into this [`BatchedTxResult`], e.g. to combine the
+    /// results of several protocol txs applied in the same block.
+    ///
+    /// The changed keys and events of both results are unioned. Since
+    /// these results only record which keys were touched, and not the
+    /// values written to them, a key changed by both results appears
+    /// once in the merged result, and its value in storage is the one
+    /// written last. The VP results are merged with
+    /// [`VpsResult::merge`].
+    pub fn merge(&mut self, other: BatchedTxResult) {
+        let BatchedTxResult {
+            changed_keys,
+            vps_result,
+            initialized_accounts,
+            events,
+        } = other;
+        self.changed_keys.extend(changed_keys);
+        self.vps_result.merge(vps_result);
+        self.initialized_accounts.extend(initialized_accounts);
+        self.events.extend(events);
+    }
 }
 
 bitflags! {
@@ -483,6 +505,26 @@ pub struct VpsResult {
     pub status_flags: VpStatusFlags,
 }
 
+impl VpsResult {
+    /// Merge `other` into this [`VpsResult`].
+    ///
+    /// The accepted and rejected VPs of both results are unioned, such
+    /// that a VP rejecting either result makes the merged result
+    /// rejected. Errors are concatenated, and status flags are combined.
+    pub fn merge(&mut self, other: VpsResult) {
+        let VpsResult {
+            accepted_vps,
+            rejected_vps,
+            errors,
+            status_flags,
+        } = other;
+        self.accepted_vps.extend(accepted_vps);
+        self.rejected_vps.extend(rejected_vps);
+        self.errors.extend(errors);
+        self.status_flags |= status_flags;
+    }
+}
+
 impl<T: Serialize> fmt::Display for TxResult<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if f.alternate() {
@@ -758,3 +800,99 @@ mod test_process_tx {
         assert_matches!(result, TxError::SigError(_));
     }
 }
+
+#[cfg(test)]
+mod test_tx_results {
+    use namada_core::address::testing::{
+        established_address_1, established_address_2,
+    };
+    use namada_events::EventLevel;
+
+    use super::*;
+    use crate::event::types::APPLIED;
+
+    fn key(segment: &str) -> storage::Key {
+        storage::Key::parse(segment).expect("Test failed")
+    }
+
+    /// Test that merging empty results yields an empty result.
+    #[test]
+    fn test_merge_empty_results() {
+        let mut result = BatchedTxResult::default();
+        result.merge(BatchedTxResult::default());
+
+        assert!(result.changed_keys.is_empty());
+        assert!(result.events.is_empty());
+        assert!(result.initialized_accounts.is_empty());
+        assert!(result.is_accepted());
+    }
+
+    /// Test that merging into an empty result yields the other result.
+    #[test]
+    fn test_merge_into_empty_result() {
+        let other = BatchedTxResult {
+            changed_keys: BTreeSet::from([key("a")]),
+            events: BTreeSet::from([Event::new(APPLIED, EventLevel::Tx)]),
+            ..Default::default()
+        };
+
+        let mut result = BatchedTxResult::default();
+        result.merge(other.clone());
+
+        assert_eq!(result.changed_keys, other.changed_keys);
+        assert_eq!(result.events, other.events);
+    }
+
+    /// Test that merging non-empty results unions their keys and events,
+    /// and keeps rejections.
+    #[test]
+    fn test_merge_non_empty_results() {
+        let mut result = BatchedTxResult {
+            changed_keys: BTreeSet::from([key("a"), key("b")]),
+            events: BTreeSet::from([Event::new(APPLIED, EventLevel::Tx)]),
+            vps_result: VpsResult {
+                accepted_vps: BTreeSet::from([established_address_1()]),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let other = BatchedTxResult {
+            changed_keys: BTreeSet::from([key("b"), key("c")]),
+            events: BTreeSet::from([Event::new(APPLIED, EventLevel::Block)]),
+            vps_result: VpsResult {
+                rejected_vps: BTreeSet::from([established_address_2()]),
+                errors: vec![(established_address_2(), "error".into())],
+                status_flags: VpStatusFlags::INVALID_SIGNATURE,
+                ..Default::default()
+            },
+            initialized_accounts: vec![established_address_2()],
+        };
+
+        result.merge(other);
+
+        assert_eq!(
+            result.changed_keys,
+            BTreeSet::from([key("a"), key("b"), key("c")])
+        );
+        assert_eq!(
+            result.events,
+            BTreeSet::from([
+                Event::new(APPLIED, EventLevel::Tx),
+                Event::new(APPLIED, EventLevel::Block),
+            ])
+        );
+        assert_eq!(result.initialized_accounts, vec![established_address_2()]);
+        assert!(!result.is_accepted());
+        assert_eq!(
+            result.vps_result.accepted_vps,
+            BTreeSet::from([established_address_1()])
+        );
+        assert_eq!(result.vps_result.errors.len(), 1);
+        assert!(
+            result
+                .vps_result
+                .status_flags
+                .contains(VpStatusFlags::INVALID_SIGNATURE)
+        );
+    }
+}
@@ -1,13 +1,14 @@
 //! Contains types necessary for processing validator set updates
 //! in vote extensions.
 use std::cmp::Ordering;
+use std::collections::BTreeMap;
 use std::ops::Deref;
 
 use namada_core::address::Address;
 use namada_core::borsh::{BorshDeserialize, BorshSchema, BorshSerialize};
 use namada_core::chain::Epoch;
 use namada_core::collections::HashMap;
-use namada_core::eth_abi::{AbiEncode, Encode, Token};
+use namada_core::eth_abi::{AbiEncode, Eip712Encode, Encode, Token};
 use namada_core::ethereum_events::EthAddress;
 use namada_core::keccak::KeccakHash;
 use namada_core::key::common::{self, Signature};
@@ -17,6 +18,7 @@ use namada_macros::BorshDeserializer;
 #[cfg(feature = "migrations")]
 use namada_migrations::*;
 use namada_tx::Signed;
+use thiserror::Error;
 
 // the contract versions and namespaces plugged into validator set hashes
 // TODO(namada#249): ideally, these values should not be hardcoded
@@ -61,6 +63,64 @@ impl VextDigest {
         }
     }
 
+    /// Build a [`VextDigest`] from multiple signed [`Vext`] instances.
+    ///
+    /// All the provided [`Vext`] instances must have been signed in
+    /// the same [`Epoch`], over the same [`VotingPowersMap`]. The
+    /// signing [`Epoch`] is returned alongside the digest.
+    pub fn from_signed_vexts(
+        exts: Vec<SignedVext>,
+    ) -> Result<(VextDigest, Epoch), VextDigestError> {
+        let mut exts = exts.into_iter();
+        let Some(SignedVext(first)) = exts.next() else {
+            return Err(VextDigestError::NoVexts);
+        };
+        let signing_epoch = first.data.signing_epoch;
+        let mut digest = VextDigest::singleton(SignedVext(first));
+
+        for SignedVext(ext) in exts {
+            if ext.data.signing_epoch != signing_epoch {
+                return Err(VextDigestError::SigningEpochMismatch {
+                    expected: signing_epoch,
+                    got: ext.data.signing_epoch,
+                });
+            }
+            if ext.data.voting_powers != digest.voting_powers {
+                return Err(VextDigestError::VotingPowersMismatch(
+                    ext.data.validator_addr,
+                ));
+            }
+            digest.signatures.insert(ext.data.validator_addr, ext.sig);
+        }
+
+        Ok((digest, signing_epoch))
+    }
+
+    /// Merge another partial [`VextDigest`] of the same [`Epoch`] into
+    /// this one, taking the union of their signatures.
+    ///
+    /// Both digests must have been signed over the same [`VotingPowersMap`],
+    /// and agree on the signature of any validator they have in common.
+    /// Neither digest is modified if merging fails.
+    pub fn merge(&mut self, other: VextDigest) -> Result<(), VextDigestError> {
+        if self.voting_powers != other.voting_powers {
+            return Err(VextDigestError::DigestVotingPowersMismatch);
+        }
+        if let Some((validator, _)) =
+            other.signatures.iter().find(|(validator, sig)| {
+                self.signatures
+                    .get(*validator)
+                    .is_some_and(|present| present != *sig)
+            })
+        {
+            return Err(VextDigestError::ConflictingSignatures(
+                validator.clone(),
+            ));
+        }
+        self.signatures.extend(other.signatures);
+        Ok(())
+    }
+
     /// Decompresses a set of signed [`Vext`] instances.
     pub fn decompress(self, signing_epoch: Epoch) -> Vec<SignedVext> {
         let VextDigest {
@@ -83,6 +143,42 @@ impl VextDigest {
     }
 }
 
+/// Errors that may occur while building a [`VextDigest`].
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum VextDigestError {
+    /// No vote extensions were provided.
+    #[error("No validator set update vote extensions were provided")]
+    NoVexts,
+    /// A vote extension was signed in a different epoch.
+    #[error(
+        "Validator set update vote extension signed at epoch {got}, but \
+         expected {expected}"
+    )]
+    SigningEpochMismatch {
+        /// The signing epoch of the first vote extension.
+        expected: Epoch,
+        /// The mismatching signing epoch.
+        got: Epoch,
+    },
+    /// A vote extension was signed over a different voting powers map.
+    #[error(
+        "The validator set update vote extension of {0} was signed over a \
+         different set of voting powers"
+    )]
+    VotingPowersMismatch(Address),
+    /// Two digests were signed over different sets of voting powers.
+    #[error(
+        "The validator set update digests were signed over different sets of \
+         voting powers"
+    )]
+    DigestVotingPowersMismatch,
+    /// Two digests hold different signatures from the same validator.
+    #[error(
+        "The validator set update digests hold conflicting signatures from {0}"
+    )]
+    ConflictingSignatures(Address),
+}
+
 /// Represents a [`Vext`] signed by some validator, with
 /// an Ethereum key.
 #[derive(
@@ -161,6 +257,25 @@ impl Vext {
     }
 }
 
+impl Eip712Encode for Vext {
+    const ENCODED_TYPE: &'static str =
+        "ValidatorSetUpdate(bytes32 bridgeValidatorSetHash,bytes32 \
+         governanceValidatorSetHash,uint256 epoch)";
+
+    fn encode_data(&self) -> Vec<Token> {
+        // NOTE: like with ABI encoded signatures, we sign
+        // against the next nonce (i.e. the new epoch)
+        let next_epoch = self.signing_epoch.next();
+        let (KeccakHash(bridge_hash), KeccakHash(gov_hash)) =
+            self.voting_powers.get_bridge_and_gov_hashes(next_epoch);
+        vec![
+            Token::FixedBytes(bridge_hash.to_vec()),
+            Token::FixedBytes(gov_hash.to_vec()),
+            epoch_to_token(next_epoch),
+        ]
+    }
+}
+
 /// Container type for both kinds of Ethereum bridge addresses:
 ///
 ///   - An address derived from a hot key.
@@ -192,7 +307,10 @@ pub type VotingPowersMap = HashMap<EthAddrBook, token::Amount>;
 /// with validator set update vote extensions logic.
 pub trait VotingPowersMapExt {
     /// Returns a [`Vec`] of pairs of validator addresses and voting powers,
-    /// sorted in descending order by voting power.
+    /// sorted in descending order by voting power. Validators with the same
+    /// voting power are sorted in ascending order by their Ethereum
+    /// addresses, such that the result is canonical, regardless of the
+    /// iteration order of the underlying map.
     fn get_sorted(&self) -> Vec<(&EthAddrBook, &token::Amount)>;
 
     /// Returns the list of Ethereum validator hot and cold addresses and their
@@ -247,6 +365,10 @@ pub trait VotingPowersMapExt {
             .unzip()
     }
 
+    /// Compute the changes from a `previous` [`VotingPowersMap`] (e.g. that
+    /// of the preceding epoch) to this one.
+    fn diff(&self, previous: &VotingPowersMap) -> VotingPowersDiff;
+
     /// Returns the bridge and governance keccak hashes of
     /// this [`VotingPowersMap`].
     #[inline]
@@ -286,6 +408,118 @@ pub fn valset_upd_toks_to_hashes(
     (bridge_hash, governance_hash)
 }
 
+/// The changes between two [`VotingPowersMap`] instances.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct VotingPowersDiff {
+    /// Validators present only in the new map, with their voting power.
+    pub added: BTreeMap<EthAddrBook, token::Amount>,
+    /// Validators present only in the previous map, with their last voting
+    /// power.
+    pub removed: BTreeMap<EthAddrBook, token::Amount>,
+    /// Validators whose voting power changed, mapped to their previous and
+    /// new voting powers (in this order).
+    pub changed: BTreeMap<EthAddrBook, (token::Amount, token::Amount)>,
+}
+
+impl VotingPowersDiff {
+    /// Check if the validator set did not change at all.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.removed.is_empty()
+            && self.changed.is_empty()
+    }
+}
+
+/// Canonical JSON (de)serialization of a [`VotingPowersMap`], to be used
+/// with `#[serde(with = "voting_powers_map_serde")]`.
+///
+/// The map is serialized as a sequence of entries of the form:
+///
+/// ```json
+/// {
+///   "eth_address": "0x...",
+///   "hot_key": "0x...",
+///   "cold_key": "0x...",
+///   "voting_power": "123"
+/// }
+/// ```
+///
+/// The `eth_address` of a validator is the address it is identified by in
+/// the bridge contract, i.e. its hot key address. Entries are sorted with
+/// [`VotingPowersMapExt::get_sorted`], such that the output is stable
+/// regardless of the iteration order of the map.
+pub mod voting_powers_map_serde {
+    use namada_core::ethereum_events::EthAddress;
+    use namada_core::token;
+    use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::{EthAddrBook, VotingPowersMap, VotingPowersMapExt};
+
+    /// A single entry of a serialized [`VotingPowersMap`].
+    #[derive(Serialize, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct Entry {
+        eth_address: EthAddress,
+        hot_key: EthAddress,
+        cold_key: EthAddress,
+        voting_power: token::Amount,
+    }
+
+    /// Serialize a [`VotingPowersMap`] in its canonical form.
+    pub fn serialize<S>(
+        voting_powers: &VotingPowersMap,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(voting_powers.get_sorted().into_iter().map(
+            |(addr_book, &voting_power)| Entry {
+                eth_address: addr_book.hot_key_addr,
+                hot_key: addr_book.hot_key_addr,
+                cold_key: addr_book.cold_key_addr,
+                voting_power,
+            },
+        ))
+    }
+
+    /// Deserialize a [`VotingPowersMap`] from its canonical form.
+    pub fn deserialize<'de, D>(
+        deserializer: D,
+    ) -> Result<VotingPowersMap, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let entries = Vec::<Entry>::deserialize(deserializer)?;
+        let mut voting_powers = VotingPowersMap::new();
+        for entry in entries {
+            if entry.eth_address != entry.hot_key {
+                return Err(de::Error::custom(format!(
+                    "The Ethereum address {} of a validator does not match \
+                     its hot key address {}",
+                    entry.eth_address, entry.hot_key
+                )));
+            }
+            let addr_book = EthAddrBook {
+                hot_key_addr: entry.hot_key,
+                cold_key_addr: entry.cold_key,
+            };
+            if voting_powers
+                .insert(addr_book, entry.voting_power)
+                .is_some()
+            {
+                return Err(de::Error::custom(format!(
+                    "Duplicate voting power entry for the validator with \
+                     Ethereum address {}",
+                    entry.eth_address
+                )));
+            }
+        }
+        Ok(voting_powers)
+    }
+}
+
 /// Compare two items of [`VotingPowersMap`]. This comparison operation must
 /// match the equivalent comparison operation in Ethereum bridge code.
 fn compare_voting_powers_map_items(
@@ -306,6 +540,27 @@ impl VotingPowersMapExt for VotingPowersMap {
         pairs.sort_by(compare_voting_powers_map_items);
         pairs
     }
+
+    fn diff(&self, previous: &VotingPowersMap) -> VotingPowersDiff {
+        let mut diff = VotingPowersDiff::default();
+        for (addr_book, &power) in self.iter() {
+            match previous.get(addr_book) {
+                None => {
+                    diff.added.insert(addr_book.clone(), power);
+                }
+                Some(&prev_power) if prev_power != power => {
+                    diff.changed.insert(addr_book.clone(), (prev_power, power));
+                }
+                Some(_) => {}
+            }
+        }
+        for (addr_book, &power) in previous.iter() {
+            if !self.contains_key(addr_book) {
+                diff.removed.insert(addr_book.clone(), power);
+            }
+        }
+        diff
+    }
 }
 
 /// Convert an [`Epoch`] to a [`Token`].
@@ -316,6 +571,11 @@ fn epoch_to_token(Epoch(e): Epoch) -> Token {
 
 /// Compute the keccak hash of a validator set update.
 ///
+/// The hash function is fixed by the Ethereum bridge smart contracts,
+/// which recompute this commitment with keccak256 when verifying the
+/// signatures of a validator set update. Hence, it must not be made
+/// configurable on the Namada side.
+///
 /// For more information, check the specs of the Ethereum bridge smart
 /// contracts.
 #[inline]
@@ -577,6 +837,284 @@ mod tests {
         assert_eq!(x, y);
     }
 
+    /// Checks that a [`VotingPowersMap`] built in different insertion orders
+    /// is sorted, ABI encoded, hashed and serialized into identical bytes.
+    #[test]
+    fn test_voting_powers_map_encoding_is_independent_of_insertion_order() {
+        use namada_core::address::testing::established_address_1;
+        use namada_core::borsh::BorshSerializeExt;
+
+        // several validators share the same voting power
+        let validators: Vec<_> = (0u8..16)
+            .zip([100u64, 200, 300, 400].into_iter().cycle())
+            .map(|(i, voting_power)| {
+                let addr_book = EthAddrBook {
+                    hot_key_addr: EthAddress([i; 20]),
+                    cold_key_addr: EthAddress([i.wrapping_add(100); 20]),
+                };
+                (addr_book, token::Amount::from(voting_power))
+            })
+            .collect();
+
+        let mut voting_powers_1 = VotingPowersMap::default();
+        for (addr_book, voting_power) in validators.iter() {
+            voting_powers_1.insert(addr_book.clone(), *voting_power);
+        }
+        let mut voting_powers_2 = VotingPowersMap::default();
+        for (addr_book, voting_power) in validators.iter().rev() {
+            voting_powers_2.insert(addr_book.clone(), *voting_power);
+        }
+
+        let sorted = voting_powers_1.get_sorted();
+        assert_eq!(sorted, voting_powers_2.get_sorted());
+        assert!(sorted.windows(2).all(|pair| {
+            compare_voting_powers_map_items(&pair[0], &pair[1])
+                == Ordering::Less
+        }));
+        assert_eq!(
+            voting_powers_1.get_abi_encoded(),
+            voting_powers_2.get_abi_encoded()
+        );
+        assert_eq!(
+            voting_powers_1.get_bridge_and_gov_hashes(1.into()),
+            voting_powers_2.get_bridge_and_gov_hashes(1.into())
+        );
+
+        let [ext_1, ext_2] =
+            [voting_powers_1, voting_powers_2].map(|voting_powers| Vext {
+                voting_powers,
+                validator_addr: established_address_1(),
+                signing_epoch: 0.into(),
+            });
+        assert_eq!(ext_1.serialize_to_vec(), ext_2.serialize_to_vec());
+    }
+
+    /// Test the EIP-712 hash of a [`Vext`], domain separated by some
+    /// [`Eip712Domain`].
+    #[test]
+    fn test_vext_eip712_hash() {
+        use namada_core::address::testing::established_address_1;
+        use namada_core::eth_abi::Eip712Domain;
+
+        // keccak256(abi.encodePacked(
+        //     "\x19\x01",
+        //     keccak256(abi.encode(
+        //         keccak256("EIP712Domain(...)"),
+        //         keccak256("Namada Bridge"),
+        //         keccak256("1"),
+        //         1,
+        //         0x0303030303030303030303030303030303030303
+        //     )),
+        //     keccak256(abi.encode(
+        //         keccak256("ValidatorSetUpdate(...)"),
+        //         keccak256(abi.encode(1, "bridge", [hot], 2)),
+        //         keccak256(abi.encode(1, "governance", [cold], 2)),
+        //         2
+        //     ))
+        // ))
+        const EXPECTED: &str =
+            "a21b8d88e66e4211da430fb837076f7d533cbd002d76675349f9ed3e6e9fb27b";
+
+        let ext = Vext {
+            voting_powers: VotingPowersMap::from([(
+                EthAddrBook {
+                    hot_key_addr: EthAddress([1; 20]),
+                    cold_key_addr: EthAddress([2; 20]),
+                },
+                100.into(),
+            )]),
+            validator_addr: established_address_1(),
+            signing_epoch: 1.into(),
+        };
+        let domain = Eip712Domain {
+            name: "Namada Bridge".into(),
+            version: "1".into(),
+            chain_id: 1,
+            verifying_contract: EthAddress([3; 20]),
+        };
+
+        let KeccakHash(got) = ext.eip712_hash(&domain);
+
+        assert_eq!(&HEXLOWER.encode(&got[..]), EXPECTED);
+    }
+
+    /// Test computing the changes between two [`VotingPowersMap`]
+    /// instances.
+    #[test]
+    fn test_voting_powers_map_diff() {
+        let validator_a = EthAddrBook {
+            hot_key_addr: EthAddress([0; 20]),
+            cold_key_addr: EthAddress([0; 20]),
+        };
+        let validator_b = EthAddrBook {
+            hot_key_addr: EthAddress([1; 20]),
+            cold_key_addr: EthAddress([1; 20]),
+        };
+        let validator_c = EthAddrBook {
+            hot_key_addr: EthAddress([2; 20]),
+            cold_key_addr: EthAddress([2; 20]),
+        };
+        let validator_d = EthAddrBook {
+            hot_key_addr: EthAddress([3; 20]),
+            cold_key_addr: EthAddress([3; 20]),
+        };
+
+        let previous = VotingPowersMap::from([
+            (validator_a.clone(), 100.into()),
+            (validator_b.clone(), 200.into()),
+            (validator_c.clone(), 300.into()),
+        ]);
+        let current = VotingPowersMap::from([
+            (validator_a.clone(), 100.into()),
+            (validator_b.clone(), 250.into()),
+            (validator_d.clone(), 400.into()),
+        ]);
+
+        let diff = current.diff(&previous);
+        assert_eq!(
+            diff,
+            VotingPowersDiff {
+                added: BTreeMap::from([(validator_d, 400.into())]),
+                removed: BTreeMap::from([(validator_c, 300.into())]),
+                changed: BTreeMap::from([(
+                    validator_b,
+                    (200.into(), 250.into())
+                )]),
+            }
+        );
+        assert!(!diff.is_empty());
+        assert!(current.diff(&current).is_empty());
+    }
+
+    /// Test that building a [`VextDigest`] from vote extensions
+    /// signed at different epochs fails.
+    #[test]
+    fn test_from_signed_vexts_rejects_epoch_mismatch() {
+        use namada_core::address::testing::{
+            established_address_1, established_address_2,
+        };
+        use namada_core::key::secp256k1;
+        use namada_core::key::testing::gen_keypair;
+
+        let sk =
+            common::SecretKey::Secp256k1(gen_keypair::<secp256k1::SigScheme>());
+        let ext_1 = Vext {
+            voting_powers: VotingPowersMap::new(),
+            validator_addr: established_address_1(),
+            signing_epoch: 1.into(),
+        }
+        .sign(&sk);
+        let ext_2 = Vext {
+            voting_powers: VotingPowersMap::new(),
+            validator_addr: established_address_2(),
+            signing_epoch: 2.into(),
+        }
+        .sign(&sk);
+
+        assert_eq!(
+            VextDigest::from_signed_vexts(vec![ext_1.clone(), ext_2]),
+            Err(VextDigestError::SigningEpochMismatch {
+                expected: 1.into(),
+                got: 2.into(),
+            })
+        );
+        assert_eq!(
+            VextDigest::from_signed_vexts(vec![]),
+            Err(VextDigestError::NoVexts)
+        );
+
+        let (digest, signing_epoch) =
+            VextDigest::from_signed_vexts(vec![ext_1.clone()])
+                .expect("Test failed");
+        assert_eq!(signing_epoch, 1.into());
+        assert_eq!(digest, VextDigest::singleton(ext_1));
+    }
+
+    /// Test merging partial [`VextDigest`] instances.
+    #[test]
+    fn test_vext_digest_merge() {
+        use namada_core::address::testing::{
+            established_address_1, established_address_2, established_address_3,
+        };
+        use namada_core::key::secp256k1;
+        use namada_core::key::testing::gen_keypair;
+
+        let sk =
+            common::SecretKey::Secp256k1(gen_keypair::<secp256k1::SigScheme>());
+        let sign = |validator_addr, voting_powers| {
+            Vext {
+                voting_powers,
+                validator_addr,
+                signing_epoch: 1.into(),
+            }
+            .sign(&sk)
+        };
+
+        // disjoint signer sets are unioned
+        let (mut digest, _) = VextDigest::from_signed_vexts(vec![
+            sign(established_address_1(), VotingPowersMap::new()),
+            sign(established_address_2(), VotingPowersMap::new()),
+        ])
+        .expect("Test failed");
+        let other = VextDigest::singleton(sign(
+            established_address_3(),
+            VotingPowersMap::new(),
+        ));
+        digest.merge(other).expect("Test failed");
+        assert_eq!(digest.signatures.len(), 3);
+        for validator in [
+            established_address_1(),
+            established_address_2(),
+            established_address_3(),
+        ] {
+            assert!(digest.signatures.contains_key(&validator));
+        }
+
+        // merging a signer already present is a no-op
+        let expected = digest.clone();
+        digest
+            .merge(VextDigest::singleton(sign(
+                established_address_1(),
+                VotingPowersMap::new(),
+            )))
+            .expect("Test failed");
+        assert_eq!(digest, expected);
+
+        // conflicting voting powers are rejected
+        let conflicting = VextDigest::singleton(sign(
+            established_address_3(),
+            VotingPowersMap::from([(
+                EthAddrBook {
+                    hot_key_addr: EthAddress([1; 20]),
+                    cold_key_addr: EthAddress([2; 20]),
+                },
+                token::Amount::from(100u64),
+            )]),
+        ));
+        assert_eq!(
+            digest.merge(conflicting),
+            Err(VextDigestError::DigestVotingPowersMismatch)
+        );
+        assert_eq!(digest, expected);
+
+        // conflicting signatures are rejected
+        let mut conflicting = VextDigest::singleton(sign(
+            established_address_1(),
+            VotingPowersMap::new(),
+        ));
+        let other_sig = expected.signatures[&established_address_2()].clone();
+        conflicting
+            .signatures
+            .insert(established_address_1(), other_sig);
+        assert_eq!(
+            digest.merge(conflicting),
+            Err(VextDigestError::ConflictingSignatures(
+                established_address_1()
+            ))
+        );
+        assert_eq!(digest, expected);
+    }
+
     #[test]
     fn test_abi_encode_valset_args() {
         let valset_update = ValidatorSetArgs {
@@ -599,4 +1137,57 @@ mod tests {
                         6b58b";
         assert_eq!(expected, encoded);
     }
+
+    /// Test that the canonical JSON encoding of a [`VotingPowersMap`] is
+    /// stable, and that it round trips.
+    #[test]
+    fn test_voting_powers_map_json_is_canonical() {
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Wrapper(
+            #[serde(with = "voting_powers_map_serde")] VotingPowersMap,
+        );
+
+        const EXPECTED: &str = concat!(
+            r#"[{"eth_address":"0x0202020202020202020202020202020202020202","#,
+            r#""hot_key":"0x0202020202020202020202020202020202020202","#,
+            r#""cold_key":"0x0303030303030303030303030303030303030303","#,
+            r#""voting_power":"200"},"#,
+            r#"{"eth_address":"0x0000000000000000000000000000000000000000","#,
+            r#""hot_key":"0x0000000000000000000000000000000000000000","#,
+            r#""cold_key":"0x0101010101010101010101010101010101010101","#,
+            r#""voting_power":"100"}]"#,
+        );
+
+        let entries = [
+            (
+                EthAddrBook {
+                    hot_key_addr: EthAddress([0; 20]),
+                    cold_key_addr: EthAddress([1; 20]),
+                },
+                token::Amount::from(100u64),
+            ),
+            (
+                EthAddrBook {
+                    hot_key_addr: EthAddress([2; 20]),
+                    cold_key_addr: EthAddress([3; 20]),
+                },
+                token::Amount::from(200u64),
+            ),
+        ];
+        let voting_powers_1: VotingPowersMap =
+            entries.iter().cloned().collect();
+        let voting_powers_2: VotingPowersMap =
+            entries.iter().rev().cloned().collect();
+
+        let json_1 = serde_json::to_string(&Wrapper(voting_powers_1.clone()))
+            .expect("Test failed");
+        let json_2 = serde_json::to_string(&Wrapper(voting_powers_2))
+            .expect("Test failed");
+        assert_eq!(json_1, EXPECTED);
+        assert_eq!(json_2, EXPECTED);
+
+        let Wrapper(decoded) =
+            serde_json::from_str(&json_1).expect("Test failed");
+        assert_eq!(decoded, voting_powers_1);
+    }
 }
@@ -78,7 +78,8 @@ pub fn is_proposal_accepted(ctx: &Ctx, proposal_id: u64) -> VpEnvResult<bool> {
     let proposal_execution_key =
         gov_storage::keys::get_proposal_execution_key(proposal_id);
 
-    ctx.has_key_pre(&proposal_execution_key).into_vp_error()
+    ctx.has_key_pre(&proposal_execution_key)
+        .into_storage_read_error(&proposal_execution_key)
 }
 
 /// Verify section signatures
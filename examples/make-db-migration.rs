@@ -3,11 +3,17 @@ use std::collections::BTreeMap;
 use borsh::{BorshDeserialize, BorshSerialize};
 use namada_macros::BorshDeserializer;
 use namada_sdk::address::Address;
+use namada_sdk::governance::parameters::GovernanceParameters;
+use namada_sdk::governance::storage::keys::{
+    get_max_code_gas_key, get_max_proposal_code_retries_key,
+    get_max_proposal_execution_gas_key, get_min_proposal_quorum_key,
+    get_rejection_refund_fraction_key,
+};
 use namada_sdk::masp_primitives::asset_type::AssetType;
 use namada_sdk::masp_primitives::merkle_tree::FrozenCommitmentTree;
 use namada_sdk::masp_primitives::sapling;
 use namada_sdk::migrations;
-use namada_sdk::storage::DbColFam;
+use namada_sdk::storage::{DbColFam, Epoch};
 use namada_shielded_token::{ConversionLeaf, ConversionState};
 use namada_trans_token::storage_key::{balance_key, minted_balance_key};
 use namada_trans_token::Amount;
@@ -72,6 +78,61 @@ fn example() {
         .unwrap();
 }
 
+/// Add the governance parameters introduced after genesis to a chain
+/// initialized without them, set to their default values. The limit on the
+/// gas of the code of proposals is in effect from the genesis epoch onward.
+fn governance_params_migration() {
+    let GovernanceParameters {
+        max_proposal_execution_gas,
+        rejection_refund_fraction,
+        min_proposal_quorum,
+        max_proposal_code_retries,
+        ..
+    } = GovernanceParameters::default();
+
+    let updates = [
+        migrations::DbUpdateType::Add {
+            key: get_max_proposal_execution_gas_key(),
+            cf: DbColFam::SUBSPACE,
+            value: max_proposal_execution_gas.into(),
+            force: false,
+        },
+        migrations::DbUpdateType::Add {
+            key: get_max_code_gas_key(Epoch::default()),
+            cf: DbColFam::SUBSPACE,
+            value: max_proposal_execution_gas.into(),
+            force: false,
+        },
+        migrations::DbUpdateType::Add {
+            key: get_rejection_refund_fraction_key(),
+            cf: DbColFam::SUBSPACE,
+            value: rejection_refund_fraction.into(),
+            force: false,
+        },
+        migrations::DbUpdateType::Add {
+            key: get_min_proposal_quorum_key(),
+            cf: DbColFam::SUBSPACE,
+            value: min_proposal_quorum.into(),
+            force: false,
+        },
+        migrations::DbUpdateType::Add {
+            key: get_max_proposal_code_retries_key(),
+            cf: DbColFam::SUBSPACE,
+            value: max_proposal_code_retries.into(),
+            force: false,
+        },
+    ];
+    let changes = migrations::DbChanges {
+        changes: updates.into_iter().collect(),
+    };
+    std::fs::write(
+        "governance_params_migration.json",
+        serde_json::to_string(&changes).unwrap(),
+    )
+    .unwrap();
+}
+
 fn main() {
-    example()
+    example();
+    governance_params_migration();
 }
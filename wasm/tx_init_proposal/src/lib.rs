@@ -8,7 +8,10 @@ use namada_tx_prelude::*;
 fn apply_tx(ctx: &mut Ctx, tx_data: BatchedTx) -> TxResult {
     let data = ctx.get_tx_data(&tx_data)?;
     let BatchedTx { tx, cmt: _ } = tx_data;
-    let tx_data = governance::InitProposalData::try_from_slice(&data[..])
+    let governance::InitProposalDataV1 {
+        data: tx_data,
+        funds_recipient,
+    } = governance::InitProposalDataV1::try_from_versioned_slice(&data[..])
         .wrap_err("Failed to decode InitProposalData value")?;
 
     // The tx must be authorized by the author address
@@ -55,6 +58,14 @@ fn apply_tx(ctx: &mut Ctx, tx_data: BatchedTx) -> TxResult {
         ctx, &tx_data, content, code,
     )
     .wrap_err("Failed to initialize new governance proposal")?;
+    if let Some(funds_recipient) = &funds_recipient {
+        governance::write_proposal_funds_recipient(
+            ctx,
+            proposal_id,
+            funds_recipient,
+        )
+        .wrap_err("Failed to set the funds recipient of the proposal")?;
+    }
 
     ctx.emit_event(GovernanceEvent::new_proposal(proposal_id, tx_data.r#type))
 }
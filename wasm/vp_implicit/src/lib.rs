@@ -124,7 +124,7 @@ fn validate_tx(
             KeyType::Pk(owner) => {
                 if owner == &addr {
                     let key_was_not_already_revealed =
-                        !ctx.has_key_pre(key).into_vp_error()?;
+                        !ctx.has_key_pre(key).into_storage_read_error(key)?;
                     key_was_not_already_revealed.ok_or_else(|| {
                         VpError::Erased(format!(
                             "Public key of {addr} has already been revealed"
@@ -132,7 +132,7 @@ fn validate_tx(
                     })?;
 
                     let pubkey_in_storage =
-                        ctx.read_post(key).into_vp_error()?;
+                        ctx.read_post(key).into_storage_read_error(key)?;
                     pubkey_in_storage.map_or_else(
                         || {
                             Err(VpError::Erased(
@@ -162,10 +162,14 @@ fn validate_tx(
             }
             KeyType::TokenBalance { owner, .. } => {
                 if owner == &addr {
-                    let pre: token::Amount =
-                        ctx.read_pre(key).into_vp_error()?.unwrap_or_default();
-                    let post: token::Amount =
-                        ctx.read_post(key).into_vp_error()?.unwrap_or_default();
+                    let pre: token::Amount = ctx
+                        .read_pre(key)
+                        .into_storage_read_error(key)?
+                        .unwrap_or_default();
+                    let post: token::Amount = ctx
+                        .read_post(key)
+                        .into_storage_read_error(key)?
+                        .unwrap_or_default();
                     let change =
                         post.change().checked_sub(pre.change()).unwrap();
                     gadget.verify_signatures_when(
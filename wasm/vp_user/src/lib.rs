@@ -122,10 +122,14 @@ fn validate_tx(
         let mut validate_change = || match key_type {
             KeyType::TokenBalance { owner, .. } => {
                 if owner == &addr {
-                    let pre: token::Amount =
-                        ctx.read_pre(key).into_vp_error()?.unwrap_or_default();
-                    let post: token::Amount =
-                        ctx.read_post(key).into_vp_error()?.unwrap_or_default();
+                    let pre: token::Amount = ctx
+                        .read_pre(key)
+                        .into_storage_read_error(key)?
+                        .unwrap_or_default();
+                    let post: token::Amount = ctx
+                        .read_post(key)
+                        .into_storage_read_error(key)?
+                        .unwrap_or_default();
                     let change =
                         post.change().checked_sub(pre.change()).unwrap();
                     gadget.verify_signatures_when(
@@ -168,7 +172,7 @@ fn validate_tx(
             ),
             KeyType::Vp(owner) => {
                 let vp_overwritten: bool =
-                    ctx.has_key_post(key).into_vp_error()?;
+                    ctx.has_key_post(key).into_storage_read_error(key)?;
                 gadget.verify_signatures_when(
                     || owner == &addr && vp_overwritten,
                     ctx,